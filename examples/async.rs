@@ -166,6 +166,10 @@ fn main() -> Result<(), Box<dyn Error>> {
         println!("STEP {i}");
         println!("{value:#}");
 
+        for frame in deflect::async_backtrace(&task)? {
+            println!("{frame}");
+        }
+
         if res.is_ready() {
             break;
         }
@@ -3,6 +3,7 @@ use deflect::Reflect;
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     struct Struct;
 
+    #[allow(dead_code)]
     trait Trait {
         fn foo(&self) {}
         fn bar(&self) {}
@@ -17,5 +18,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let value: deflect::value::Struct<_> = value.try_into()?;
 
     println!("{value:#}");
+
+    let boxed: Box<dyn Trait> = Box::new(Struct);
+    let erased: &dyn Reflect = &boxed;
+    let value: deflect::value::BoxedDyn = erased.reflect(&context)?.try_into()?;
+    println!("{:#}", value.vtable()?);
     Ok(())
 }
@@ -7,7 +7,7 @@ fn foo(val: u8) -> u32 {
 struct Wrapper<F>(F);
 
 fn run() -> Result<(), Box<dyn std::error::Error>> {
-    let erased: &dyn Reflect = &Wrapper(foo);
+    let erased: &dyn Reflect = &Wrapper(foo as fn(u8) -> u32);
     let context = deflect::default_provider()?;
     let value = erased.reflect(&context)?;
     println!("{value:#}");
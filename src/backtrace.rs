@@ -0,0 +1,164 @@
+//! Backtraces of suspended `async` tasks.
+
+use std::fmt;
+
+/// Produces the async backtrace of `future`; e.g., of a `Pin<Box<dyn Future>>`.
+///
+/// Each returned [`Frame`] is a generator reachable from `future` without
+/// traversing references or raw pointers, along with the frames it is
+/// awaiting. Combinators like `join` may produce several root frames.
+///
+/// Debug info is read with the [default provider][crate::default_provider];
+/// see [`async_backtrace_with`] to supply another.
+pub fn async_backtrace(future: &dyn crate::Reflect) -> Result<Vec<Frame>, crate::Error> {
+    async_backtrace_with(future, &crate::default_provider()?)
+}
+
+/// Produces the async backtrace of `future`, reading debug info with
+/// `provider`.
+///
+/// See [`async_backtrace`].
+pub fn async_backtrace_with<P>(
+    future: &dyn crate::Reflect,
+    provider: &P,
+) -> Result<Vec<Frame>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    let value = future.reflect(provider)?;
+    frames_of(value)
+}
+
+/// Produces the async backtrace frames reachable from `value`.
+///
+/// This is [`async_backtrace`] for an already-reflected value. Fails if any
+/// field along the way cannot be reflected, rather than produce a backtrace
+/// that silently omits its frames.
+pub fn frames_of<'value, 'dwarf, P>(
+    value: crate::Value<'value, 'dwarf, P>,
+) -> Result<Vec<Frame>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    let mut frames = vec![];
+    collect(value, &mut frames)?;
+    Ok(frames)
+}
+
+fn collect<'value, 'dwarf, P>(
+    value: crate::Value<'value, 'dwarf, P>,
+    frames: &mut Vec<Frame>,
+) -> Result<(), crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    match value {
+        crate::Value::Generator(value) => {
+            let mut awaiting = vec![];
+            if let Some(awaitee) = value.awaitee()? {
+                collect(awaitee, &mut awaiting)?;
+            }
+            let state = value.state()?;
+            let state = state.schema().name()?.to_string_lossy()?.into_owned();
+            frames.push(Frame {
+                function: value.schema().function()?,
+                state,
+                location: value.location()?,
+                awaiting,
+            });
+        }
+        crate::Value::Struct(value) => collect_fields(value.fields()?, frames)?,
+        crate::Value::Enum(value) => collect_fields(value.variant()?.fields()?, frames)?,
+        crate::Value::Box(value) => collect(value.deref()?, frames)?,
+        crate::Value::BoxedDyn(value) => collect(value.deref()?, frames)?,
+        crate::Value::Array(value) => {
+            for elt in value.iter()? {
+                collect(elt?, frames)?;
+            }
+        }
+        crate::Value::BoxedSlice(value) => {
+            for elt in value.iter()? {
+                collect(elt?, frames)?;
+            }
+        }
+        crate::Value::Typedef(value) => collect(value.value()?, frames)?,
+        crate::Value::Qualified(value) => collect(value.value()?, frames)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+fn collect_fields<'value, 'dwarf, P>(
+    mut fields: crate::value::Fields<'value, 'dwarf, P>,
+    frames: &mut Vec<Frame>,
+) -> Result<(), crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    let mut fields = fields.iter()?;
+    while let Some(field) = fields.try_next()? {
+        collect(field.value()?, frames)?;
+    }
+    Ok(())
+}
+
+/// A frame of an async backtrace.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    function: String,
+    state: String,
+    location: Option<(String, u64)>,
+    awaiting: Vec<Frame>,
+}
+
+impl Frame {
+    /// The fully-qualified path of the `async` function of this frame.
+    pub fn function(&self) -> &str {
+        &self.function
+    }
+
+    /// The state of this frame's generator; e.g., `Suspend0`.
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// The source location (file and line) this frame is suspended at, if
+    /// any.
+    pub fn location(&self) -> Option<(&str, u64)> {
+        self.location
+            .as_ref()
+            .map(|(file, line)| (file.as_str(), *line))
+    }
+
+    /// The frames this frame is awaiting.
+    pub fn awaiting(&self) -> &[Frame] {
+        &self.awaiting
+    }
+
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>, prefix: &str) -> fmt::Result {
+        f.write_str(&self.function)?;
+        if let Some((file, line)) = self.location() {
+            write!(f, " at {file}:{line}")?;
+        } else {
+            write!(f, " ({})", self.state)?;
+        }
+        let mut awaiting = self.awaiting.iter().peekable();
+        while let Some(frame) = awaiting.next() {
+            let (branch, indent) = if awaiting.peek().is_some() {
+                ("├╼ ", "│  ")
+            } else {
+                ("└╼ ", "   ")
+            };
+            write!(f, "\n{prefix}{branch}")?;
+            frame.fmt_tree(f, &format!("{prefix}{indent}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("╼ ")?;
+        self.fmt_tree(f, "  ")
+    }
+}
@@ -49,6 +49,10 @@ pub(crate) fn enum_destructure() -> crate::Error {
     anyhow!("could not destructure enum into variant")
 }
 
+pub(crate) fn unsupported(method: &'static str) -> crate::Error {
+    Unsupported { method }.into()
+}
+
 /// Could not downcast the value into the given type.
 #[derive(thiserror::Error, Debug)]
 #[error("Could not downcast into {src}, received {dst}")]
@@ -64,3 +68,57 @@ impl DowncastErr {
         Self { src, dst }
     }
 }
+
+/// A pointer was null, misaligned, or did not point into readable memory.
+///
+/// Only reported while pointer validation is enabled; see
+/// [`set_pointer_validation`][crate::set_pointer_validation].
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("invalid pointer 0x{address:x}")]
+pub struct InvalidPointer {
+    address: usize,
+}
+
+impl InvalidPointer {
+    pub(crate) fn new(address: usize) -> Self {
+        Self { address }
+    }
+
+    /// The address of the invalid pointer.
+    pub fn address(&self) -> usize {
+        self.address
+    }
+}
+
+/// A debug info provider did not implement the method needed for an
+/// operation.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("this debug info provider does not implement `{method}`")]
+pub struct Unsupported {
+    method: &'static str,
+}
+
+impl Unsupported {
+    /// The name of the unimplemented method; e.g., `vtable_info`.
+    pub fn method(&self) -> &'static str {
+        self.method
+    }
+}
+
+/// There was no vtable at an address.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("could not find a vtable at the static address 0x{address:x}")]
+pub struct VtableNotFound {
+    address: u64,
+}
+
+impl VtableNotFound {
+    pub(crate) fn new(address: u64) -> Self {
+        Self { address }
+    }
+
+    /// The static address at which no vtable was found.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+}
@@ -18,7 +18,7 @@
 //!
 //! // initialize the debuginfo provider
 //! let context = deflect::default_provider()?;
-//! 
+//!
 //! // create some type-erased data
 //! let erased: Box<dyn Any> = Box::new(Foo { a: 42 });
 //!
@@ -30,27 +30,27 @@
 //!
 //! // pretty-print the reflected value
 //! assert_eq!(value.to_string(), "box Foo { a: 42 }");
-//! 
+//!
 //! // downcast into a `BoxedDyn` value
 //! let value: deflect::value::BoxedDyn = value.try_into()?;
 //!
 //! // dereference the boxed value
 //! let value: deflect::Value = value.deref()?;
-//! 
+//!
 //! // downcast into a `Struct` value
 //! let value: deflect::value::Struct = value.try_into()?;
-//! 
+//!
 //! // get the field `a` by name
 //! let Some(field) = value.field("a")? else {
 //!     panic!("no field named `a`!")
 //! };
-//! 
+//!
 //! // get the value of the field
 //! let value = field.value()?;
-//! 
+//!
 //! // downcast into a `u8`
 //! let value: u8 = value.try_into()?;
-//! 
+//!
 //! // check that it's equal to `42`!
 //! assert_eq!(value, 42);
 //! # Ok::<_, Box<dyn std::error::Error>>(())
@@ -83,6 +83,7 @@ pub use addr2line::{self, gimli, object};
 
 use dashmap::DashMap;
 use gimli::{AttributeValue, EndianReader, RunTimeEndian, UnitOffset};
+use itertools::Itertools;
 use once_cell::sync::Lazy;
 use std::{
     borrow::{Borrow, Cow},
@@ -91,13 +92,22 @@ use std::{
     fmt,
     mem::{self, MaybeUninit},
     path::Path,
-    ptr::slice_from_raw_parts,
+    ptr::{slice_from_raw_parts, slice_from_raw_parts_mut},
     rc::Rc,
 };
 
 mod debug;
 mod error;
-pub use error::DowncastErr;
+pub use error::{DowncastErr, InvalidPointer, Unsupported, VtableNotFound};
+
+mod safety;
+pub use safety::{pointer_validation, set_pointer_validation};
+
+mod symbol;
+pub use symbol::Symbol;
+
+pub mod backtrace;
+pub use backtrace::{async_backtrace, async_backtrace_with};
 
 pub mod schema;
 pub mod value;
@@ -148,14 +158,127 @@ pub unsafe trait DebugInfoProvider: Clone {
 
     /// Produces debug info for a given function.
     fn info_for(&self, fn_addr: u64) -> Result<DebugInfo<'_, Self::Reader>, crate::Error>;
+
+    /// Produces the debug info context covering the given code address, along
+    /// with that address's static (i.e., unrelocated) counterpart.
+    ///
+    /// By default, this is unsupported and fails.
+    fn context_for(
+        &self,
+        addr: u64,
+    ) -> Result<(&addr2line::Context<Self::Reader>, u64), crate::Error> {
+        let _ = addr;
+        Err(error::unsupported("context_for"))
+    }
+
+    /// Produces debug info for the vtable at the given address; i.e., the
+    /// `DW_TAG_variable` named `<T as Trait>::{vtable}`.
+    ///
+    /// By default, this is unsupported and fails.
+    fn vtable_info(&self, vtable_addr: u64) -> Result<DebugInfo<'_, Self::Reader>, crate::Error> {
+        let _ = vtable_addr;
+        Err(error::unsupported("vtable_info"))
+    }
+
+    /// Produces the vtables of the type whose DIE is at `offset` of `unit` (in
+    /// the binary described by `dwarf`), along with the paths of the traits
+    /// they implement; e.g., `core::fmt::Debug`.
+    ///
+    /// By default, this is unsupported and fails.
+    fn vtables_of(
+        &self,
+        dwarf: &gimli::Dwarf<Self::Reader>,
+        unit: &gimli::Unit<Self::Reader>,
+        offset: UnitOffset,
+    ) -> Result<Vec<(String, u64)>, crate::Error> {
+        let _ = (dwarf, unit, offset);
+        Err(error::unsupported("vtables_of"))
+    }
+
+    /// Produces the vtables of the types whose fully-qualified path is
+    /// `self_type` (e.g., `my_crate::Foo`) in the binary described by `dwarf`,
+    /// along with the paths of the traits they implement.
+    ///
+    /// Unlike [`vtables_of`][Self::vtables_of], this also produces the
+    /// vtables of each of the DIEs a type may have (e.g., one per codegen
+    /// unit), and of any distinct types that share its path.
+    ///
+    /// By default, this is unsupported and fails.
+    fn vtables_named(
+        &self,
+        dwarf: &gimli::Dwarf<Self::Reader>,
+        self_type: &str,
+    ) -> Result<Vec<(String, u64)>, crate::Error> {
+        let _ = (dwarf, self_type);
+        Err(error::unsupported("vtables_named"))
+    }
+
+    /// Translates `static_addr`, an address recorded in `dwarf` (e.g., the
+    /// `DW_AT_location` of a static variable), to its address in this
+    /// process.
+    ///
+    /// By default, this is unsupported and fails.
+    fn dynamic_address(
+        &self,
+        dwarf: &gimli::Dwarf<Self::Reader>,
+        static_addr: u64,
+    ) -> Result<u64, crate::Error> {
+        let _ = (dwarf, static_addr);
+        Err(error::unsupported("dynamic_address"))
+    }
+
+    /// Produces debug info for the type whose fully-qualified name is `name`
+    /// (e.g., `my_crate::Foo`), if that type exists.
+    ///
+    /// By default, this is unsupported and fails.
+    fn type_info(&self, name: &str) -> Result<Option<DebugInfo<'_, Self::Reader>>, crate::Error> {
+        let _ = name;
+        Err(error::unsupported("type_info"))
+    }
+
+    /// Produces the reflected `Type` whose fully-qualified name is `name`
+    /// (e.g., `my_crate::Foo`), if that type exists.
+    fn type_named(
+        &self,
+        name: &str,
+    ) -> Result<Option<schema::Type<'_, Self::Reader>>, crate::Error> {
+        let Some(DebugInfo {
+            context,
+            unit,
+            entry,
+        }) = self.type_info(name)?
+        else {
+            return Ok(None);
+        };
+        let entry = unit.entry(entry)?;
+        schema::Type::from_die(context.dwarf(), unit, entry).map(Some)
+    }
+
+    /// Produces a reflected `Value` of the value of the type named `name`
+    /// (e.g., `my_crate::Foo`) at `ptr`.
+    ///
+    /// ## Safety
+    /// `ptr` must point to a valid, initialized value of the type named
+    /// `name`, which must live (and not be mutated) for `'value`.
+    unsafe fn reflect_raw<'value>(
+        &self,
+        ptr: *const std::ffi::c_void,
+        name: &str,
+    ) -> Result<Value<'value, '_, Self>, crate::Error> {
+        let Some(r#type) = self.type_named(name)? else {
+            bail!("could not find a type named `{name}`");
+        };
+        r#type.reflect_at(ptr, self)
+    }
 }
 
 mod dbginfo_provider {
     use super::*;
+    use std::collections::hash_map::DefaultHasher;
 
     struct Map {
         path: std::path::PathBuf,
-        static_addr: usize,
+        file_offset: usize,
     }
 
     fn map_of(dynamic_addr: usize) -> Result<Map, crate::Error> {
@@ -166,7 +289,7 @@ mod dbginfo_provider {
             if (map.base..=map.ceiling).contains(&dynamic_addr) {
                 if let procmaps::Path::MappedFile(file) = &map.pathname {
                     return Ok(Map {
-                        static_addr: (dynamic_addr - map.base) + map.offset,
+                        file_offset: (dynamic_addr - map.base) + map.offset,
                         path: file.into(),
                     });
                 }
@@ -175,13 +298,435 @@ mod dbginfo_provider {
         bail!("could not map the dynamic address 0x{dynamic_addr:x} to a static address in the binary");
     }
 
+    /// Maps an offset into the file of `object` to its static virtual address.
+    fn static_addr_of(
+        object: &object::File<'static, &'static [u8]>,
+        file_offset: usize,
+    ) -> Result<usize, crate::Error> {
+        use object::{Object, ObjectSegment};
+        let file_offset = file_offset as u64;
+        for segment in object.segments() {
+            let (start, size) = segment.file_range();
+            if (start..start + size).contains(&file_offset) {
+                return Ok((file_offset - start + segment.address()) as usize);
+            }
+        }
+        bail!("could not map the file offset 0x{file_offset:x} to a static address in the binary");
+    }
+
     pub fn context_of(dynamic_addr: usize) -> Result<(&'static Context, usize), crate::Error> {
-        let Map { path, static_addr } = map_of(dynamic_addr)?;
+        let Map { path, file_offset } = map_of(dynamic_addr)?;
+        let static_addr = static_addr_of(read_object(path.as_path())?, file_offset)?;
         let context = read_context(path)?;
         Ok((context, static_addr))
     }
 
-    pub fn read_context<P>(path: P) -> Result<&'static Context, crate::Error>
+    /// Maps the static virtual address `static_addr` in the binary at `path` to
+    /// its dynamic address in this process.
+    pub fn dynamic_addr_of(path: &Path, static_addr: usize) -> Result<usize, crate::Error> {
+        use object::{Object, ObjectSegment};
+        let object = read_object(path)?;
+        // the segments of a binary may share pages of its file, so a file
+        // offset may be mapped more than once; instead, offset `static_addr`
+        // by the distance the binary was loaded from its static addresses
+        let Some(first) = object
+            .segments()
+            .find(|segment| segment.file_range().0 == 0)
+        else {
+            bail!(
+                "could not find the segment at the start of {}",
+                path.display()
+            );
+        };
+
+        let pid = std::process::id();
+        let mappings = procmaps::Mappings::from_pid(pid as _)?;
+        for map in mappings.iter() {
+            let procmaps::Path::MappedFile(file) = &map.pathname else {
+                continue;
+            };
+            if Path::new(file) != path || map.offset != 0 {
+                continue;
+            }
+            let bias = map.base.wrapping_sub(first.address() as usize);
+            return Ok(static_addr.wrapping_add(bias));
+        }
+        bail!("could not find where {} is mapped", path.display());
+    }
+
+    /// Produces the unit and entry of the vtable variable at `static_addr`.
+    pub fn vtable_of(
+        context: &'static Context,
+        static_addr: usize,
+    ) -> Result<(&'static gimli::Unit<Addr2LineReader>, UnitOffset), crate::Error> {
+        let index = vtable_index(context)?;
+        let Some(&(unit, entry)) = index.by_addr.get(&(static_addr as u64)) else {
+            return Err(crate::error::VtableNotFound::new(static_addr as u64).into());
+        };
+        Ok((unit_at(context, unit)?, entry))
+    }
+
+    /// Produces the dynamic addresses of the vtables of the type whose DIE is
+    /// at `offset` of `unit`, along with the paths of the traits they
+    /// implement.
+    pub fn vtables_of(
+        dwarf: &gimli::Dwarf<Addr2LineReader>,
+        unit: &gimli::Unit<Addr2LineReader>,
+        offset: UnitOffset,
+    ) -> Result<Vec<(String, usize)>, crate::Error> {
+        let (path, context) = binary_of(dwarf)?;
+        let index = vtable_index(context)?;
+        let Some(unit) = unit.header.offset().as_debug_info_offset() else {
+            return Ok(vec![]);
+        };
+        let Some(vtables) = index.by_type.get(&(unit, offset)) else {
+            return Ok(vec![]);
+        };
+        dynamic_vtables(&path, vtables)
+    }
+
+    /// Produces the dynamic addresses of the vtables of the types whose path is
+    /// `self_type`, along with the paths of the traits they implement.
+    pub fn vtables_named(
+        dwarf: &gimli::Dwarf<Addr2LineReader>,
+        self_type: &str,
+    ) -> Result<Vec<(String, usize)>, crate::Error> {
+        let (path, context) = binary_of(dwarf)?;
+        let index = vtable_index(context)?;
+        let Some(vtables) = index.by_self_type.get(self_type) else {
+            return Ok(vec![]);
+        };
+        dynamic_vtables(&path, vtables)
+    }
+
+    /// Translates the static addresses of `vtables` of the binary at `path`
+    /// to dynamic addresses.
+    fn dynamic_vtables(
+        path: &Path,
+        vtables: &[(String, u64)],
+    ) -> Result<Vec<(String, usize)>, crate::Error> {
+        vtables
+            .iter()
+            .map(|(r#trait, static_addr)| {
+                let addr = dynamic_addr_of(path, *static_addr as usize)?;
+                Ok((r#trait.clone(), addr))
+            })
+            .collect()
+    }
+
+    /// Produces the path and context of the binary whose debug info is `dwarf`.
+    pub fn binary_of(
+        dwarf: &gimli::Dwarf<Addr2LineReader>,
+    ) -> Result<(std::path::PathBuf, &'static Context), crate::Error> {
+        let binary = CONTEXT_CACHE.with(|context_cache| {
+            context_cache
+                .borrow()
+                .iter()
+                .find(|(_, context)| std::ptr::eq(context.dwarf(), dwarf))
+                .map(|(path, context)| (path.clone(), *context))
+        });
+        binary.ok_or_else(|| {
+            anyhow!("could not find the binary corresponding to the given debug info")
+        })
+    }
+
+    /// Produces the unit at `offset` of `context`.
+    ///
+    /// Units covering code are parsed by `context` already, and are reused;
+    /// others are parsed (once per thread) on demand.
+    fn unit_at(
+        context: &'static Context,
+        offset: gimli::DebugInfoOffset,
+    ) -> Result<&'static gimli::Unit<Addr2LineReader>, crate::Error> {
+        thread_local! {
+            static UNIT_CACHE: RefCell<HashMap<(usize, usize), &'static gimli::Unit<Addr2LineReader>>> =
+                RefCell::new(HashMap::new());
+        }
+
+        let key = (context as *const Context as usize, offset.0);
+        if let Some(unit) = UNIT_CACHE.with(|unit_cache| unit_cache.borrow().get(&key).copied()) {
+            return Ok(unit);
+        }
+        let dwarf = context.dwarf();
+        let unit = dwarf.unit(dwarf.debug_info.header_from_offset(offset)?)?;
+        let probe = dwarf
+            .unit_ranges(&unit)
+            .and_then(|mut ranges| ranges.next())
+            .ok()
+            .flatten()
+            .map(|range| range.begin);
+        let parsed = probe
+            .and_then(|probe| context.find_dwarf_unit(probe))
+            .filter(|parsed| parsed.header.offset() == offset.into());
+        let unit = match parsed {
+            Some(parsed) => parsed,
+            None => Box::leak(Box::new(unit)),
+        };
+        UNIT_CACHE.with(|unit_cache| unit_cache.borrow_mut().insert(key, unit));
+        Ok(unit)
+    }
+
+    /// Produces debug info for the type named `name` in the binary containing
+    /// this crate, if any.
+    ///
+    /// Fails if distinct types share this name; e.g., C structs of the same
+    /// name, but with different members, defined in different files.
+    pub fn type_named(
+        name: &str,
+    ) -> Result<Option<DebugInfo<'static, Addr2LineReader>>, crate::Error> {
+        let (context, _) = context_of(crate::default_provider as *const () as usize)?;
+        let index = type_index(context)?;
+        let Some(types) = index.get(name.as_bytes()) else {
+            return Ok(None);
+        };
+        let [(_, (unit, entry))] = types[..] else {
+            bail!(
+                "`{name}` is ambiguous; it names {} distinct types",
+                types.len()
+            )
+        };
+        Ok(Some(DebugInfo {
+            context,
+            unit: unit_at(context, unit)?,
+            entry,
+        }))
+    }
+
+    /// Identifies a DIE of a binary by the offset of its unit, and its offset
+    /// within that unit.
+    type DieKey = (gimli::DebugInfoOffset, UnitOffset);
+
+    /// The type definitions of a binary, by fully-qualified name. Types may
+    /// be defined once per unit, so only the first definition of each
+    /// distinct [`shape`] is indexed.
+    type TypeIndex = HashMap<Vec<u8>, Vec<(u64, DieKey)>>;
+
+    /// Produces the (cached) type index of the binary of `context`.
+    fn type_index(context: &'static Context) -> Result<&'static TypeIndex, crate::Error> {
+        static TYPE_CACHE: Lazy<DashMap<std::path::PathBuf, &'static TypeIndex>> =
+            Lazy::new(DashMap::new);
+
+        let (path, _) = binary_of(context.dwarf())?;
+        let index = TYPE_CACHE.entry(path).or_try_insert_with(|| {
+            let index: &'static _ = Box::leak(Box::new(index_types(context)?));
+            Ok::<_, crate::Error>(index)
+        })?;
+        Ok(*index)
+    }
+
+    /// Indexes the type definitions of `context` by their fully-qualified
+    /// names (e.g., `my_crate::Foo`).
+    fn index_types(context: &'static Context) -> Result<TypeIndex, crate::Error> {
+        use std::hash::Hasher;
+
+        let dwarf = context.dwarf();
+        let mut index = TypeIndex::new();
+        let mut headers = dwarf.units();
+        while let Some(header) = headers.next()? {
+            let Some(offset) = header.offset().as_debug_info_offset() else {
+                continue;
+            };
+            let unit = dwarf.unit(header)?;
+            let mut path: Vec<Option<Vec<u8>>> = vec![];
+            // the types whose shapes are being hashed, by depth
+            let mut open: Vec<(isize, Vec<u8>, UnitOffset, DefaultHasher)> = vec![];
+            let mut depth: isize = 0;
+            let mut entries = unit.entries();
+            while let Some((delta, entry)) = entries.next_dfs()? {
+                depth += delta;
+                path.truncate(depth as usize);
+                while open
+                    .last()
+                    .map_or(false, |(open_depth, ..)| *open_depth >= depth)
+                {
+                    let Some((_, name, entry, hasher)) = open.pop() else {
+                        break;
+                    };
+                    insert_type(&mut index, name, (offset, entry), hasher.finish());
+                }
+                if let Some((open_depth, _, _, hasher)) = open.last_mut() {
+                    if *open_depth == depth - 1 {
+                        shape(hasher, dwarf, &unit, entry)?;
+                    }
+                }
+                let name = match entry.attr_value(gimli::DW_AT_name)? {
+                    Some(name) => {
+                        let name = dwarf.attr_string(&unit, name)?;
+                        Some(gimli::Reader::to_slice(&name)?.into_owned())
+                    }
+                    None => None,
+                };
+                let is_declaration = matches!(
+                    entry.attr_value(gimli::DW_AT_declaration)?,
+                    Some(AttributeValue::Flag(true))
+                );
+                let (is_scope, is_type) = match entry.tag() {
+                    gimli::DW_TAG_namespace => (true, false),
+                    gimli::DW_TAG_structure_type
+                    | gimli::DW_TAG_class_type
+                    | gimli::DW_TAG_union_type
+                    | gimli::DW_TAG_enumeration_type => (true, true),
+                    gimli::DW_TAG_base_type | gimli::DW_TAG_typedef => (false, true),
+                    _ => (false, false),
+                };
+                if let (Some(name), true, false) = (&name, is_type, is_declaration) {
+                    let qualified = path
+                        .iter()
+                        .flatten()
+                        .chain(std::iter::once(name))
+                        .map(Vec::as_slice)
+                        .collect::<Vec<_>>()
+                        .join(&b"::"[..]);
+                    let mut hasher = DefaultHasher::new();
+                    shape(&mut hasher, dwarf, &unit, entry)?;
+                    open.push((depth, qualified, entry.offset(), hasher));
+                }
+                path.push(if is_scope { name } else { None });
+            }
+            for (_, name, entry, hasher) in open {
+                insert_type(&mut index, name, (offset, entry), hasher.finish());
+            }
+        }
+        Ok(index)
+    }
+
+    /// Indexes the type at `die` by `name`, unless a type of the same `shape`
+    /// has been indexed by that name already.
+    fn insert_type(index: &mut TypeIndex, name: Vec<u8>, die: DieKey, shape: u64) {
+        let types = index.entry(name).or_default();
+        if types.iter().all(|&(indexed, _)| indexed != shape) {
+            types.push((shape, die));
+        }
+    }
+
+    /// Hashes the shape of `entry` (i.e., its tag, size, encoding, location,
+    /// and the name of its type) into `hasher`. The shape of a type comprises
+    /// its own, and those of its children.
+    fn shape(
+        hasher: &mut DefaultHasher,
+        dwarf: &gimli::Dwarf<Addr2LineReader>,
+        unit: &gimli::Unit<Addr2LineReader>,
+        entry: &gimli::DebuggingInformationEntry<Addr2LineReader>,
+    ) -> Result<(), crate::Error> {
+        use std::hash::Hash;
+
+        let name = |entry: &gimli::DebuggingInformationEntry<Addr2LineReader>| {
+            let Some(name) = entry.attr_value(gimli::DW_AT_name)? else {
+                return Ok::<_, crate::Error>(None);
+            };
+            let name = dwarf.attr_string(unit, name)?;
+            Ok(Some(gimli::Reader::to_slice(&name)?.into_owned()))
+        };
+        let udata = |attr| {
+            let value = entry.attr_value(attr)?;
+            Ok::<_, crate::Error>(value.and_then(|value| value.udata_value()))
+        };
+        entry.tag().0.hash(hasher);
+        name(entry)?.hash(hasher);
+        udata(gimli::DW_AT_byte_size)?.hash(hasher);
+        udata(gimli::DW_AT_bit_size)?.hash(hasher);
+        udata(gimli::DW_AT_data_member_location)?.hash(hasher);
+        udata(gimli::DW_AT_data_bit_offset)?.hash(hasher);
+        udata(gimli::DW_AT_discr_value)?.hash(hasher);
+        if let Some(AttributeValue::Encoding(encoding)) = entry.attr_value(gimli::DW_AT_encoding)? {
+            encoding.0.hash(hasher);
+        }
+        if let Some(r#type) = get_attr_ref(entry, gimli::DW_AT_type)? {
+            let r#type = unit.entry(r#type)?;
+            r#type.tag().0.hash(hasher);
+            name(&r#type)?.hash(hasher);
+        }
+        Ok(())
+    }
+
+    /// The `<T as Trait>::{vtable}` variables of a binary.
+    struct VtableIndex {
+        /// The vtables, by static address.
+        by_addr: HashMap<u64, DieKey>,
+        /// The traits and static addresses of the vtables of each type, by the
+        /// DIE of that type.
+        by_type: HashMap<DieKey, Vec<(String, u64)>>,
+        /// The traits and static addresses of the vtables, by the path of the
+        /// type they are for.
+        by_self_type: HashMap<String, Vec<(String, u64)>>,
+    }
+
+    /// Produces the (cached) vtable index of the binary of `context`.
+    fn vtable_index(context: &'static Context) -> Result<&'static VtableIndex, crate::Error> {
+        static VTABLE_CACHE: Lazy<DashMap<std::path::PathBuf, &'static VtableIndex>> =
+            Lazy::new(DashMap::new);
+
+        let (path, _) = binary_of(context.dwarf())?;
+        let index = VTABLE_CACHE.entry(path).or_try_insert_with(|| {
+            let index: &'static _ = Box::leak(Box::new(index_vtables(context)?));
+            Ok::<_, crate::Error>(index)
+        })?;
+        Ok(*index)
+    }
+
+    /// Indexes the `<T as Trait>::{vtable}` variables of `context` by their
+    /// static addresses, by the DIEs of their types (i.e., the
+    /// `DW_AT_containing_type` of their `{vtable_type}`), and by the paths of
+    /// their types.
+    fn index_vtables(context: &'static Context) -> Result<VtableIndex, crate::Error> {
+        let dwarf = context.dwarf();
+        let mut index = VtableIndex {
+            by_addr: HashMap::new(),
+            by_type: HashMap::new(),
+            by_self_type: HashMap::new(),
+        };
+        let mut headers = dwarf.units();
+        while let Some(header) = headers.next()? {
+            let Some(offset) = header.offset().as_debug_info_offset() else {
+                continue;
+            };
+            let unit = dwarf.unit(header)?;
+            let mut entries = unit.entries();
+            while let Some((_, entry)) = entries.next_dfs()? {
+                if entry.tag() != gimli::DW_TAG_variable {
+                    continue;
+                }
+                let Some(name) = entry.attr_value(gimli::DW_AT_name)? else {
+                    continue;
+                };
+                let name = dwarf.attr_string(&unit, name)?;
+                let name = gimli::Reader::to_slice(&name)?;
+                if !name.ends_with(b"::{vtable}") {
+                    continue;
+                }
+                let Some(address) = get_static_address(dwarf, &unit, entry)? else {
+                    continue;
+                };
+                index.by_addr.insert(address, (offset, entry.offset()));
+                let name = String::from_utf8_lossy(&name);
+                let Some((self_type, r#trait)) = schema::split_qualified_self(&name) else {
+                    continue;
+                };
+                index
+                    .by_self_type
+                    .entry(self_type.to_owned())
+                    .or_default()
+                    .push((r#trait.to_owned(), address));
+                let Some(vtable_type) = get_attr_ref(entry, gimli::DW_AT_type)? else {
+                    continue;
+                };
+                let vtable_type = unit.entry(vtable_type)?;
+                let Some(r#type) = get_attr_ref(&vtable_type, gimli::DW_AT_containing_type)? else {
+                    continue;
+                };
+                index
+                    .by_type
+                    .entry((offset, r#type))
+                    .or_default()
+                    .push((r#trait.to_owned(), address));
+            }
+        }
+        Ok(index)
+    }
+
+    fn read_object<P>(
+        path: P,
+    ) -> Result<&'static object::File<'static, &'static [u8]>, crate::Error>
     where
         P: Borrow<Path>,
     {
@@ -201,17 +746,27 @@ mod dbginfo_provider {
             Ok::<_, crate::Error>(object)
         })?;
 
-        thread_local! {
-            pub static CONTEXT_CACHE: RefCell<HashMap<std::path::PathBuf, &'static Context>> =
-                RefCell::new(HashMap::new());
-        }
+        Ok(*object)
+    }
+
+    thread_local! {
+        static CONTEXT_CACHE: RefCell<HashMap<std::path::PathBuf, &'static Context>> =
+            RefCell::new(HashMap::new());
+    }
+
+    pub fn read_context<P>(path: P) -> Result<&'static Context, crate::Error>
+    where
+        P: Borrow<Path>,
+    {
+        let path = path.borrow().to_owned();
+        let object = read_object(path.as_path())?;
 
         CONTEXT_CACHE.with(move |context_cache| {
             let mut context_cache = context_cache.borrow_mut();
             if let Some(context) = context_cache.get(&path) {
                 Ok(*context)
             } else {
-                let context = addr2line::Context::new(*object)?;
+                let context = addr2line::Context::new(object)?;
                 let context: &'static _ = Box::leak(Box::new(context));
                 context_cache.insert(path, context);
                 Ok(context)
@@ -227,22 +782,82 @@ pub(crate) mod private {
 
 pub(crate) use private::DefaultProvider;
 
-/// The default provider of DWARF debug info.
-pub fn default_provider() -> Result<DefaultProvider, crate::Error> {
-    unsafe impl DebugInfoProvider for DefaultProvider {
-        type Reader = Addr2LineReader;
-
-        fn info_for(&self, fn_addr: u64) -> Result<DebugInfo<'static, Self::Reader>, crate::Error> {
-            let (context, static_addr) = crate::dbginfo_provider::context_of(fn_addr as _)?;
-            let (unit, entry) = crate::dw_unit_and_die_of_addr(context, static_addr)?;
-            Ok(DebugInfo {
-                context,
-                unit,
-                entry,
-            })
-        }
+unsafe impl DebugInfoProvider for DefaultProvider {
+    type Reader = Addr2LineReader;
+
+    fn info_for(&self, fn_addr: u64) -> Result<DebugInfo<'static, Self::Reader>, crate::Error> {
+        let (context, static_addr) = crate::dbginfo_provider::context_of(fn_addr as _)?;
+        let (unit, entry) = crate::dw_unit_and_die_of_addr(context, static_addr)?;
+        Ok(DebugInfo {
+            context,
+            unit,
+            entry,
+        })
+    }
+
+    fn context_for(&self, addr: u64) -> Result<(&'static Context, u64), crate::Error> {
+        let (context, static_addr) = crate::dbginfo_provider::context_of(addr as _)?;
+        Ok((context, static_addr as _))
+    }
+
+    fn vtable_info(
+        &self,
+        vtable_addr: u64,
+    ) -> Result<DebugInfo<'static, Self::Reader>, crate::Error> {
+        let (context, static_addr) = crate::dbginfo_provider::context_of(vtable_addr as _)?;
+        let (unit, entry) = crate::dbginfo_provider::vtable_of(context, static_addr)?;
+        Ok(DebugInfo {
+            context,
+            unit,
+            entry,
+        })
+    }
+
+    fn vtables_of(
+        &self,
+        dwarf: &gimli::Dwarf<Self::Reader>,
+        unit: &gimli::Unit<Self::Reader>,
+        offset: UnitOffset,
+    ) -> Result<Vec<(String, u64)>, crate::Error> {
+        let vtables = crate::dbginfo_provider::vtables_of(dwarf, unit, offset)?;
+        Ok(vtables
+            .into_iter()
+            .map(|(r#trait, addr)| (r#trait, addr as _))
+            .collect())
     }
 
+    fn vtables_named(
+        &self,
+        dwarf: &gimli::Dwarf<Self::Reader>,
+        self_type: &str,
+    ) -> Result<Vec<(String, u64)>, crate::Error> {
+        let vtables = crate::dbginfo_provider::vtables_named(dwarf, self_type)?;
+        Ok(vtables
+            .into_iter()
+            .map(|(r#trait, addr)| (r#trait, addr as _))
+            .collect())
+    }
+
+    fn dynamic_address(
+        &self,
+        dwarf: &gimli::Dwarf<Self::Reader>,
+        static_addr: u64,
+    ) -> Result<u64, crate::Error> {
+        let (path, _) = crate::dbginfo_provider::binary_of(dwarf)?;
+        let addr = crate::dbginfo_provider::dynamic_addr_of(&path, static_addr as _)?;
+        Ok(addr as _)
+    }
+
+    fn type_info(
+        &self,
+        name: &str,
+    ) -> Result<Option<DebugInfo<'static, Self::Reader>>, crate::Error> {
+        crate::dbginfo_provider::type_named(name)
+    }
+}
+
+/// The default provider of DWARF debug info.
+pub fn default_provider() -> Result<DefaultProvider, crate::Error> {
     Ok(DefaultProvider {})
 }
 
@@ -252,7 +867,7 @@ pub trait Reflect {
     /// unit.
     #[inline(never)]
     fn local_type_id(&self) -> usize {
-        <Self as Reflect>::local_type_id as usize
+        <Self as Reflect>::local_type_id as *const () as usize
     }
 }
 
@@ -264,17 +879,49 @@ impl dyn Reflect + '_ {
         &'value self,
         provider: &'dwarf P,
     ) -> Result<Value<'value, 'dwarf, P>, crate::Error> {
-        let DebugInfo {
-            context,
-            unit,
-            entry,
-        } = provider.info_for(self.local_type_id() as _)?;
-        let entry = unit.entry(entry)?;
-        let r#type = schema::Type::from_die(context.dwarf(), unit, entry)?;
+        let r#type = self.reflect_type(provider)?;
         let value =
             slice_from_raw_parts(self as *const Self as *const Byte, mem::size_of_val(self));
         unsafe { value::Value::with_type(r#type, &*value, provider) }
     }
+
+    /// Produces a reflected, mutable `ValueMut` of `&mut self`.
+    ///
+    /// Only plain values may be [assigned][value::ValueMut::assign] safely;
+    /// the other writes of a `ValueMut` are `unsafe`, since they may break the
+    /// invariants of `self`'s type.
+    pub fn reflect_mut<'value, 'dwarf, P: DebugInfoProvider>(
+        &'value mut self,
+        provider: &'dwarf P,
+    ) -> Result<value::ValueMut<'value, 'dwarf, P>, crate::Error> {
+        let r#type = self.reflect_type(provider)?;
+        let value =
+            slice_from_raw_parts_mut(self as *mut Self as *mut Byte, mem::size_of_val(self));
+        unsafe { value::ValueMut::new(r#type, &mut *value, provider) }
+    }
+
+    /// Produces the reflected `Type` of `self`.
+    pub(crate) fn reflect_type<'dwarf, P: DebugInfoProvider>(
+        &self,
+        provider: &'dwarf P,
+    ) -> Result<schema::Type<'dwarf, P::Reader>, crate::Error> {
+        type_of_local_id(provider, self.local_type_id())
+    }
+}
+
+/// Produces the reflected `Type` identified by `local_type_id`; see
+/// [`Reflect::local_type_id`].
+pub(crate) fn type_of_local_id<P: DebugInfoProvider>(
+    provider: &P,
+    local_type_id: usize,
+) -> Result<schema::Type<'_, P::Reader>, crate::Error> {
+    let DebugInfo {
+        context,
+        unit,
+        entry,
+    } = provider.info_for(local_type_id as _)?;
+    let entry = unit.entry(entry)?;
+    schema::Type::from_die(context.dwarf(), unit, entry)
 }
 
 /// Produces the DWARF unit and entry offset for the DIE of `T`.
@@ -285,11 +932,9 @@ fn dw_unit_and_die_of_addr<'ctx, R>(
 where
     R: crate::gimli::Reader<Offset = usize>,
 {
-    let Some(frame) = ctx
-        .find_frames(static_addr as u64)?
-        .next()? else {
-            bail!("could not find a DWARF frame for the address 0x{static_addr:x}")
-        };
+    let Some(frame) = ctx.find_frames(static_addr as u64)?.next()? else {
+        bail!("could not find a DWARF frame for the address 0x{static_addr:x}")
+    };
 
     let Some(dw_die_offset) = frame.dw_die_offset else {
         bail!("could not find the DWARF unit offset corresponding to the DIE of the function at static address 0x{static_addr:x}")
@@ -311,7 +956,9 @@ where
     }
 
     let Some(ty) = ty else {
-        return Err(error::missing_child(crate::gimli::DW_TAG_template_type_parameter))
+        return Err(error::missing_child(
+            crate::gimli::DW_TAG_template_type_parameter,
+        ));
     };
 
     Ok((unit, ty))
@@ -454,8 +1101,18 @@ macro_rules! generate_type_and_value {
             P: crate::DebugInfoProvider,
         {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                value::referents::display(self, f, false)
+            }
+        }
+
+        impl<'value, 'dwarf, P> Value<'value, 'dwarf, P>
+        where
+            P: crate::DebugInfoProvider,
+        {
+            /// Displays this value, without regard to its shared referents.
+            pub(crate) fn display_plain(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 match self {
-                    $(Self::$t(v) => v.fmt(f),)*
+                    $(Self::$t(v) => fmt::Display::fmt(v, f),)*
                 }
             }
         }
@@ -605,9 +1262,30 @@ generate_type_and_value! {
     /// A reflected enum.
     Enum,
 
+    /// A reflected generator; e.g., an `async fn`'s state machine.
+    Generator,
+
     /// A reflected function.
     Function,
 
+    /// A reflected bitfield member.
+    Bitfield,
+
+    /// A reflected type alias (i.e., a C `typedef`).
+    Typedef,
+
+    /// A reflected `const`, `volatile` or `restrict`-qualified type.
+    Qualified,
+
+    /// A reflected type of unspecified layout.
+    Unspecified,
+
+    /// A reflected unsized `[T]` tail of a dynamically sized struct.
+    UnsizedSlice,
+
+    /// A reflected SIMD vector.
+    Vector,
+
     /// A reflected shared reference.
     SharedRef,
 
@@ -722,12 +1400,18 @@ fn get_file<'a, R: crate::gimli::Reader<Offset = usize> + 'a>(
     unit: &'a crate::gimli::Unit<R, usize>,
     entry: &crate::gimli::DebuggingInformationEntry<R>,
 ) -> Result<Option<Cow<'a, str>>, crate::Error> {
-    let file = get(entry, crate::gimli::DW_AT_decl_file)?;
+    let Some(file) = get_opt(entry, crate::gimli::DW_AT_decl_file)? else {
+        return Ok(None);
+    };
     let AttributeValue::FileIndex(index) = file else {
         return Ok(None); // error?
     };
-    let Some(prog) = &unit.line_program else { return Ok(None) };
-    let Some(file) = prog.header().file(index) else { return Ok(None) };
+    let Some(prog) = &unit.line_program else {
+        return Ok(None);
+    };
+    let Some(file) = prog.header().file(index) else {
+        return Ok(None);
+    };
     let filename = dwarf.attr_string(unit, file.path_name())?;
     let filename = filename.to_string_lossy()?;
     if let Some(dir) = file.directory(prog.header()) {
@@ -741,6 +1425,52 @@ fn get_file<'a, R: crate::gimli::Reader<Offset = usize> + 'a>(
     Ok(Some(filename.into_owned().into()))
 }
 
+fn get_line<R: crate::gimli::Reader<Offset = usize>>(
+    entry: &crate::gimli::DebuggingInformationEntry<R>,
+) -> Result<Option<u64>, crate::Error> {
+    if let Some(line) = get_opt(entry, crate::gimli::DW_AT_decl_line)? {
+        let line = line
+            .udata_value()
+            .ok_or_else(|| crate::error::invalid_attr(crate::gimli::DW_AT_decl_line))?;
+        Ok(Some(line))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Produces the `::`-delimited path of the DIE at `offset`, including the names
+/// of its enclosing namespaces and types.
+fn get_qualified_name<R: crate::gimli::Reader<Offset = usize>>(
+    dwarf: &crate::gimli::Dwarf<R>,
+    unit: &crate::gimli::Unit<R>,
+    offset: UnitOffset,
+) -> Result<String, crate::Error> {
+    let mut path: Vec<Option<String>> = vec![];
+    let mut depth: isize = 0;
+    let mut entries = unit.entries();
+    while let Some((delta, entry)) = entries.next_dfs()? {
+        depth += delta;
+        path.truncate(depth as usize);
+        let name = match entry.tag() {
+            crate::gimli::DW_TAG_namespace
+            | crate::gimli::DW_TAG_structure_type
+            | crate::gimli::DW_TAG_union_type
+            | crate::gimli::DW_TAG_enumeration_type => {
+                schema::Name::from_die_opt(dwarf, unit, entry)?
+                    .map(|name| name.to_string_lossy().map(Cow::into_owned))
+                    .transpose()?
+            }
+            _ => None,
+        };
+        if entry.offset() == offset {
+            path.push(name);
+            return Ok(path.into_iter().flatten().join("::"));
+        }
+        path.push(name);
+    }
+    bail!("could not find the DIE at offset {offset:x?} in its unit")
+}
+
 fn get_attr_ref<R: crate::gimli::Reader<Offset = usize>>(
     entry: &crate::gimli::DebuggingInformationEntry<R>,
     name: crate::gimli::DwAt,
@@ -753,6 +1483,25 @@ fn get_attr_ref<R: crate::gimli::Reader<Offset = usize>>(
     Ok(None)
 }
 
+/// The static address of the variable described by `entry`; i.e., its
+/// `DW_AT_location`, if that is a plain address.
+fn get_static_address<R: crate::gimli::Reader<Offset = usize>>(
+    dwarf: &crate::gimli::Dwarf<R>,
+    unit: &crate::gimli::Unit<R>,
+    entry: &crate::gimli::DebuggingInformationEntry<R>,
+) -> Result<Option<u64>, crate::Error> {
+    let Some(AttributeValue::Exprloc(location)) = entry.attr_value(crate::gimli::DW_AT_location)?
+    else {
+        return Ok(None);
+    };
+    let mut operations = location.operations(unit.encoding());
+    Ok(match operations.next()? {
+        Some(crate::gimli::Operation::Address { address }) => Some(address),
+        Some(crate::gimli::Operation::AddressIndex { index }) => Some(dwarf.address(unit, index)?),
+        _ => None,
+    })
+}
+
 fn fi_to_string<'a, R: crate::gimli::Reader<Offset = usize> + 'a>(
     dwarf: &'a crate::gimli::Dwarf<R>,
     unit: &'a crate::gimli::Unit<R>,
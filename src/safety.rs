@@ -0,0 +1,138 @@
+//! Opt-in validation of pointers before they are dereferenced.
+//!
+//! Reflected values may contain pointers that are dangling, misaligned or
+//! otherwise corrupt (e.g., when reflecting raw memory). By default, such
+//! pointers are dereferenced as-is. With pointer validation enabled, each
+//! pointer is first checked to be non-null, aligned, and to point into
+//! readable memory of this process; pointers that fail these checks are
+//! reported as [`InvalidPointer`] errors, and are displayed as
+//! `<invalid pointer 0x…>`.
+
+use once_cell::sync::Lazy;
+use std::fmt;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use crate::error::InvalidPointer;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables pointer validation for this process.
+///
+/// While enabled, pointers are checked to be non-null, aligned, and to point
+/// into readable memory of this process before they are dereferenced. Invalid
+/// pointers are reported as [`InvalidPointer`] errors, and displayed as
+/// `<invalid pointer 0x…>`, rather than crashing the process.
+pub fn set_pointer_validation(enabled: bool) {
+    ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+/// Whether pointer validation is enabled for this process.
+pub fn pointer_validation() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+/// The readable mappings of this process, sorted by address, as of when they
+/// were last read from procfs.
+static READABLE: Lazy<Mutex<Vec<Range<usize>>>> = Lazy::new(Default::default);
+
+/// Checks that `size` bytes at `address`, aligned to `align`, may be read, if
+/// pointer validation is enabled.
+pub(crate) fn check(address: usize, size: usize, align: usize) -> Result<(), crate::Error> {
+    if !pointer_validation() {
+        return Ok(());
+    }
+    let invalid = || crate::Error::from(InvalidPointer::new(address));
+    if address == 0 || address % align.max(1) != 0 {
+        return Err(invalid());
+    }
+    if size == 0 {
+        return Ok(());
+    }
+    let end = address.checked_add(size).ok_or_else(invalid)?;
+    let mut readable = READABLE.lock().unwrap_or_else(PoisonError::into_inner);
+    if is_readable(&readable, address..end) {
+        return Ok(());
+    }
+    // the mappings of this process may have changed since they were read
+    *readable = readable_mappings()?;
+    if is_readable(&readable, address..end) {
+        Ok(())
+    } else {
+        Err(invalid())
+    }
+}
+
+/// Checks that a value of `r#type` at `address` may be read, if pointer
+/// validation is enabled.
+pub(crate) fn check_type<R>(
+    address: usize,
+    r#type: &crate::schema::Type<'_, R>,
+) -> Result<(), crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    if !pointer_validation() {
+        return Ok(());
+    }
+    let size = r#type.size()?.try_into()?;
+    // types of unknown alignment are only checked to be non-null
+    let align = crate::value::align_of(r#type).map_or(Ok(1), usize::try_from)?;
+    check(address, size, align)
+}
+
+/// Reads the readable mappings of this process.
+fn readable_mappings() -> Result<Vec<Range<usize>>, crate::Error> {
+    let pid = std::process::id();
+    let mappings = procmaps::Mappings::from_pid(pid as _)?;
+    let mut readable: Vec<_> = mappings
+        .iter()
+        .filter(|map| map.perms.readable)
+        .map(|map| map.base..map.ceiling)
+        .collect();
+    readable.sort_by_key(|map| map.start);
+    Ok(readable)
+}
+
+/// Whether `range` is covered by the sorted, disjoint `mappings`.
+fn is_readable(mappings: &[Range<usize>], range: Range<usize>) -> bool {
+    let first = mappings.partition_point(|map| map.end <= range.start);
+    let mut covered = range.start;
+    for map in &mappings[first..] {
+        if map.start > covered {
+            return false;
+        }
+        covered = map.end;
+        if covered >= range.end {
+            return true;
+        }
+    }
+    false
+}
+
+/// Displays an invalid pointer as `<invalid pointer 0x…>`.
+pub(crate) struct Invalid(InvalidPointer);
+
+impl Invalid {
+    /// Recovers the invalid pointer of `err`, failing if `err` does not
+    /// concern an invalid pointer.
+    pub(crate) fn from_err(err: crate::Error) -> Result<Self, fmt::Error> {
+        match err.downcast::<InvalidPointer>() {
+            Ok(invalid) => Ok(Self(invalid)),
+            Err(err) => Err(crate::fmt_err(err)),
+        }
+    }
+}
+
+/// Displays `err` as `<invalid pointer 0x…>`, failing if `err` does not
+/// concern an invalid pointer.
+pub(crate) fn display_invalid(err: crate::Error, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(&Invalid::from_err(err)?, f)
+}
+
+impl fmt::Display for Invalid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}>", self.0)
+    }
+}
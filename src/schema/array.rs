@@ -1,6 +1,12 @@
 use std::fmt;
 
 /// A schema for [`[T; N]`][prim@array].
+///
+/// Multi-dimensional (C) arrays, like `int m[2][3]`, are described by a single
+/// [`DW_TAG_array_type`][crate::gimli::DW_TAG_array_type] with a
+/// [`DW_TAG_subrange_type`][crate::gimli::DW_TAG_subrange_type] per dimension;
+/// their elements are the arrays of their remaining dimensions (e.g., the rows
+/// `int[3]`).
 #[derive(Clone)]
 #[allow(non_camel_case_types)]
 pub struct Array<'dwarf, R: crate::gimli::Reader<Offset = usize>>
@@ -10,6 +16,8 @@ where
     dwarf: &'dwarf crate::gimli::Dwarf<R>,
     unit: &'dwarf crate::gimli::Unit<R, usize>,
     entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    /// The index of the outermost subrange this array spans.
+    dimension: usize,
 }
 
 impl<'dwarf, R> Array<'dwarf, R>
@@ -24,7 +32,12 @@ where
         entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
     ) -> Result<Self, crate::Error> {
         crate::check_tag(&entry, crate::gimli::DW_TAG_array_type)?;
-        Ok(Self { dwarf, unit, entry })
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            dimension: 0,
+        })
     }
 
     /// The [DWARF](crate::gimli::Dwarf) sections that this `Function`'s
@@ -50,7 +63,16 @@ where
     }
 
     /// The element type, `T`, of this [`[T; N]`][prim@array] array.
+    ///
+    /// The elements of multi-dimensional arrays are the arrays of their
+    /// remaining dimensions.
     pub fn elt_type(&self) -> Result<super::Type<'dwarf, R>, crate::Error> {
+        if self.dimension + 1 < self.subranges()?.len() {
+            return Ok(super::Type::Array(Self {
+                dimension: self.dimension + 1,
+                ..self.clone()
+            }));
+        }
         super::Type::from_die(
             self.dwarf,
             self.unit,
@@ -58,21 +80,57 @@ where
         )
     }
 
+    /// The lengths of the dimensions of this array, outermost first; e.g.,
+    /// `[Some(2), Some(3)]` for `int m[2][3]`.
+    ///
+    /// The length of a dimension is `None` if it is not statically known, as
+    /// for C's flexible array members (e.g., `int data[]`) and variable-length
+    /// arrays.
+    pub fn dimensions(&self) -> Result<Vec<Option<u64>>, crate::Error> {
+        self.subranges()?
+            .iter()
+            .skip(self.dimension)
+            .map(|subrange| subrange_len(self.unit, subrange))
+            .collect()
+    }
+
     /// The length, `N`, of this [`[T; N]`][prim@array] array.
+    ///
+    /// Arrays of unknown length (e.g., C's flexible array members) occupy no
+    /// storage, and so have a length of `0`.
     pub fn len(&self) -> Result<u64, crate::Error> {
-        let mut tree = self.unit.entries_tree(Some(self.entry.offset()))?;
-        let root = tree.root()?;
-        let mut children = root.children();
-        let dw_tag_subrange_type = children
-            .next()?
+        let dimensions = self.dimensions()?;
+        let len = dimensions
+            .first()
             .ok_or_else(|| crate::error::missing_child(crate::gimli::DW_TAG_subrange_type))?;
-        let dw_tag_subrange_type = dw_tag_subrange_type.entry();
-        crate::check_tag(dw_tag_subrange_type, crate::gimli::DW_TAG_subrange_type)?;
-        let dw_at_count = crate::get(dw_tag_subrange_type, crate::gimli::DW_AT_count)?;
-        let count = dw_at_count
-            .udata_value()
-            .ok_or_else(|| crate::error::invalid_attr(crate::gimli::DW_AT_count))?;
-        Ok(count)
+        Ok(len.unwrap_or(0))
+    }
+
+    /// Whether the length of this array is not statically known; e.g., C's
+    /// `int data[]`.
+    pub fn is_unbounded(&self) -> Result<bool, crate::Error> {
+        Ok(matches!(self.dimensions()?.first(), Some(None)))
+    }
+
+    /// The [`DW_TAG_subrange_type`][crate::gimli::DW_TAG_subrange_type]s of
+    /// this array, outermost first.
+    fn subranges(
+        &self,
+    ) -> Result<Vec<crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>>, crate::Error> {
+        let mut subranges = vec![];
+        let mut tree = self.unit.entries_tree(Some(self.entry.offset()))?;
+        let mut children = tree.root()?.children();
+        while let Some(child) = children.next()? {
+            if child.entry().tag() == crate::gimli::DW_TAG_subrange_type {
+                subranges.push(child.entry().clone());
+            }
+        }
+        if subranges.is_empty() {
+            return Err(crate::error::missing_child(
+                crate::gimli::DW_TAG_subrange_type,
+            ));
+        }
+        Ok(subranges)
     }
 
     /// The size of this array, in bytes.
@@ -105,8 +163,107 @@ where
     R: crate::gimli::Reader<Offset = usize>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        super::Name::from_die(self.dwarf(), self.unit(), self.entry())
-            .map_err(crate::fmt_err)?
-            .fmt(f)
+        if self.dimension == 0 {
+            let name = super::Name::from_die_opt(self.dwarf(), self.unit(), self.entry())
+                .map_err(crate::fmt_err)?;
+            if let Some(name) = name {
+                return name.fmt(f);
+            }
+        }
+        // C arrays (and the rows of multi-dimensional arrays) are unnamed
+        let elt_type = self.elt_type().map_err(crate::fmt_err)?;
+        match self.dimensions().map_err(crate::fmt_err)?.first() {
+            Some(Some(len)) => write!(f, "[{elt_type}; {len}]"),
+            _ => write!(f, "[{elt_type}]"),
+        }
+    }
+}
+
+/// The length of the dimension described by `subrange`, if it is statically
+/// known.
+fn subrange_len<'dwarf, R>(
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    subrange: &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+) -> Result<Option<u64>, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    if let Some(count) = crate::get_opt(subrange, crate::gimli::DW_AT_count)? {
+        // non-constant bounds (e.g., of variable-length arrays) are
+        // expressions or references, rather than data
+        return Ok(count.udata_value());
+    }
+    // C arrays record their inclusive upper bound, rather than their count
+    let signed = is_signed_index(unit, subrange)?;
+    let Some(upper_bound) = bound(subrange, crate::gimli::DW_AT_upper_bound, signed)? else {
+        return Ok(None);
+    };
+    let lower_bound = bound(subrange, crate::gimli::DW_AT_lower_bound, signed)?.unwrap_or(0);
+    // older GCCs record the upper bound of flexible array members as `-1`
+    if upper_bound < lower_bound {
+        return Ok(None);
+    }
+    Ok(u64::try_from(upper_bound - lower_bound + 1).ok())
+}
+
+/// The constant bound `attr` of `subrange`, interpreted as signed if its index
+/// type is.
+///
+/// The signedness of the fixed-size data forms is given by their context, so a
+/// `DW_FORM_data4` of `0xFFFFFFFF` is `-1` for an `int` index.
+fn bound<R>(
+    subrange: &crate::gimli::DebuggingInformationEntry<'_, '_, R>,
+    attr: crate::gimli::DwAt,
+    signed: bool,
+) -> Result<Option<i128>, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    use crate::gimli::AttributeValue;
+    let Some(value) = crate::get_opt(subrange, attr)? else {
+        return Ok(None);
+    };
+    Ok(match value {
+        AttributeValue::Sdata(value) => Some(value.into()),
+        AttributeValue::Udata(value) => Some(value.into()),
+        AttributeValue::Data1(value) if signed => Some((value as i8).into()),
+        AttributeValue::Data2(value) if signed => Some((value as i16).into()),
+        AttributeValue::Data4(value) if signed => Some((value as i32).into()),
+        AttributeValue::Data8(value) if signed => Some((value as i64).into()),
+        AttributeValue::Data1(value) => Some(value.into()),
+        AttributeValue::Data2(value) => Some(value.into()),
+        AttributeValue::Data4(value) => Some(value.into()),
+        AttributeValue::Data8(value) => Some(value.into()),
+        // e.g., the expressions of variable-length arrays
+        _ => None,
+    })
+}
+
+/// Whether the index type of `subrange` is signed.
+fn is_signed_index<'dwarf, R>(
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    subrange: &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+) -> Result<bool, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let Some(r#type) = crate::get_attr_ref(subrange, crate::gimli::DW_AT_type)? else {
+        return Ok(false);
+    };
+    let mut entry = unit.entry(r#type)?;
+    // look through typedefs and qualifiers, e.g. `const ssize_t`
+    while matches!(
+        entry.tag(),
+        crate::gimli::DW_TAG_typedef
+            | crate::gimli::DW_TAG_const_type
+            | crate::gimli::DW_TAG_volatile_type
+    ) {
+        entry = crate::get_type_res(unit, &entry)?;
     }
+    Ok(matches!(
+        crate::get_opt(&entry, crate::gimli::DW_AT_encoding)?,
+        Some(crate::gimli::AttributeValue::Encoding(
+            crate::gimli::DW_ATE_signed | crate::gimli::DW_ATE_signed_char
+        ))
+    ))
 }
@@ -0,0 +1,158 @@
+use super::{Name, Type};
+use std::fmt;
+
+/// A bitfield member; i.e., a [`DW_TAG_member`][crate::gimli::DW_TAG_member]
+/// with a [`DW_AT_bit_size`][crate::gimli::DW_AT_bit_size].
+///
+/// Bitfields occupy `bit_size` bits starting `bit_offset` bits into the first
+/// byte of their storage, and so need not begin or end on a byte boundary.
+#[derive(Clone)]
+pub struct Bitfield<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    bit_offset: u8,
+    bit_size: u8,
+}
+
+impl<'dwarf, R> Bitfield<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    /// Construct a new `Bitfield` from a
+    /// [`DW_TAG_member`][crate::gimli::DW_TAG_member] that has a
+    /// [`DW_AT_bit_size`][crate::gimli::DW_AT_bit_size].
+    pub(crate) fn from_dw_tag_member(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        crate::check_tag(&entry, crate::gimli::DW_TAG_member)?;
+        let bit_size = crate::get(&entry, crate::gimli::DW_AT_bit_size)?
+            .udata_value()
+            .ok_or_else(|| crate::error::invalid_attr(crate::gimli::DW_AT_bit_size))?;
+        if !(1..=64).contains(&bit_size) {
+            bail!("unsupported bitfield width of {bit_size} bits");
+        }
+        let bit_address = super::Offset::from_die(unit, &entry)?.bit_address(0)?;
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            bit_offset: (bit_address % 8) as u8,
+            bit_size: bit_size as u8,
+        })
+    }
+
+    /// The [DWARF](crate::gimli::Dwarf) sections that this `Bitfield`'s
+    /// debuginfo belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+        self.dwarf
+    }
+
+    /// The DWARF [unit][crate::gimli::Unit] that this `Bitfield`'s debuginfo
+    /// belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, usize> {
+        self.unit
+    }
+
+    /// The [debugging information
+    /// entry][crate::gimli::DebuggingInformationEntry] this `Bitfield`
+    /// abstracts over.
+    #[allow(dead_code)]
+    pub(crate) fn entry(&self) -> &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R> {
+        &self.entry
+    }
+
+    /// The declared type of this bitfield; e.g., `u32` for `u32: 3`.
+    pub fn r#type(&self) -> Result<Type<'dwarf, R>, crate::Error> {
+        let r#type = crate::get_type_res(self.unit, &self.entry)?;
+        Type::from_die(self.dwarf, self.unit, r#type)
+    }
+
+    /// The width of this bitfield, in bits.
+    pub fn bit_size(&self) -> u8 {
+        self.bit_size
+    }
+
+    /// The offset of this bitfield's first bit within its first byte.
+    pub fn bit_offset(&self) -> u8 {
+        self.bit_offset
+    }
+
+    /// The number of bytes this bitfield spans.
+    pub fn size(&self) -> u64 {
+        (u64::from(self.bit_offset) + u64::from(self.bit_size) + 7) / 8
+    }
+
+    /// The [`DW_AT_encoding`][crate::gimli::DW_AT_encoding] of this
+    /// bitfield's declared type, if it is a base type.
+    pub fn encoding(&self) -> Result<Option<crate::gimli::DwAte>, crate::Error> {
+        let mut entry = crate::get_type_res(self.unit, &self.entry)?;
+        // look through typedefs and qualifiers, e.g. `const uint8_t`
+        while matches!(
+            entry.tag(),
+            crate::gimli::DW_TAG_typedef
+                | crate::gimli::DW_TAG_const_type
+                | crate::gimli::DW_TAG_volatile_type
+        ) {
+            entry = crate::get_type_res(self.unit, &entry)?;
+        }
+        if entry.tag() != crate::gimli::DW_TAG_base_type {
+            return Ok(None);
+        }
+        match crate::get_opt(&entry, crate::gimli::DW_AT_encoding)? {
+            Some(crate::gimli::AttributeValue::Encoding(encoding)) => Ok(Some(encoding)),
+            Some(_) => Err(crate::error::invalid_attr(crate::gimli::DW_AT_encoding)),
+            None => Ok(None),
+        }
+    }
+
+    /// Whether this bitfield holds a two's-complement signed integer.
+    pub fn is_signed(&self) -> Result<bool, crate::Error> {
+        Ok(matches!(
+            self.encoding()?,
+            Some(crate::gimli::DW_ATE_signed | crate::gimli::DW_ATE_signed_char)
+        ))
+    }
+
+    /// The name of this bitfield's declared type, if it has one.
+    fn type_name(&self) -> Result<Option<Name<R>>, crate::Error> {
+        let r#type = crate::get_type_res(self.unit, &self.entry)?;
+        Name::from_die_opt(self.dwarf, self.unit, &r#type)
+    }
+}
+
+impl<'dwarf, R> fmt::Debug for Bitfield<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple("deflect::schema::Bitfield");
+        debug_tuple.field(&crate::debug::DebugEntry::new(
+            self.dwarf(),
+            self.unit(),
+            self.entry(),
+        ));
+        debug_tuple.finish()
+    }
+}
+
+impl<'dwarf, R> fmt::Display for Bitfield<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.type_name().map_err(crate::fmt_err)? {
+            name.fmt(f)?;
+        } else {
+            self.r#type().map_err(crate::fmt_err)?.fmt(f)?;
+        }
+        write!(f, ": {}", self.bit_size)
+    }
+}
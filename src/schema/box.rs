@@ -11,6 +11,7 @@ where
     entry: crate::gimli::UnitOffset,
     name: Option<super::Name<R>>,
     target: crate::gimli::UnitOffset,
+    wide: Option<super::pointer::Wide>,
 }
 
 impl<'dwarf, R> Box<'dwarf, R>
@@ -31,9 +32,48 @@ where
             entry,
             name,
             target,
+            wide: None,
         }
     }
 
+    /// Construct a new `Box` of a dynamically sized struct from the
+    /// [`DW_TAG_structure_type`][crate::gimli::DW_TAG_structure_type] at
+    /// `entry`.
+    pub(super) fn new_wide(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::UnitOffset,
+        name: Option<super::Name<R>>,
+        target: crate::gimli::UnitOffset,
+        wide: super::pointer::Wide,
+    ) -> Self {
+        Self {
+            wide: Some(wide),
+            ..Self::new(dwarf, unit, entry, name, target)
+        }
+    }
+
+    /// The [DWARF](crate::gimli::Dwarf) sections that this type's debuginfo
+    /// belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+        self.dwarf
+    }
+
+    /// The DWARF [unit][crate::gimli::Unit] that this type's debuginfo belongs
+    /// to.
+    #[allow(dead_code)]
+    pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, usize> {
+        self.unit
+    }
+
+    /// The offset of the [debugging information
+    /// entry][crate::gimli::DebuggingInformationEntry] this type abstracts over.
+    #[allow(dead_code)]
+    pub(crate) fn offset(&self) -> crate::gimli::UnitOffset {
+        self.entry
+    }
+
     /// The name of this reference type.
     pub fn name(&self) -> Option<&super::Name<R>> {
         self.name.as_ref()
@@ -41,7 +81,21 @@ where
 
     /// The size of this type, in bytes.
     pub fn size(&self) -> u64 {
-        core::mem::size_of::<usize>() as _
+        match self.wide {
+            Some(_) => 2 * core::mem::size_of::<usize>() as u64,
+            None => core::mem::size_of::<usize>() as _,
+        }
+    }
+
+    /// Whether this is a wide pointer to a dynamically sized struct; e.g.,
+    /// `Box<Packet>` for `struct Packet { header: u32, body: [u8] }`.
+    pub fn is_wide(&self) -> bool {
+        self.wide.is_some()
+    }
+
+    /// The layout of this wide pointer, if it is one.
+    pub(crate) fn wide(&self) -> Option<super::pointer::Wide> {
+        self.wide
     }
 
     /// The type of the referent.
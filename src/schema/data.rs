@@ -1,6 +1,6 @@
 /// A static value (e.g., enum discriminant).
 #[allow(non_camel_case_types)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Data {
     /// A byte of data.
     u8(u8),
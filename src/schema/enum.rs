@@ -114,6 +114,22 @@ where
         &self.name
     }
 
+    /// The generic arguments of this enum; e.g., `T = u32` for `Option<u32>`.
+    pub fn generics(&self) -> Result<Vec<super::Generic<'dwarf, R>>, crate::Error> {
+        super::generics::generics_of(self.dwarf, self.unit, &self.entry)
+    }
+
+    /// Whether this enum is a `core::option::Option<T>`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn is_option(&self) -> Result<bool, crate::Error> {
+        // check the name first, as computing the path is comparatively costly
+        if !self.name.to_slice()?.starts_with(b"Option<") {
+            return Ok(false);
+        }
+        let path = crate::get_qualified_name(self.dwarf, self.unit, self.entry.offset())?;
+        Ok(path.starts_with("core::option::Option<"))
+    }
+
     /// The discriminant of this type.
     pub fn discriminant_type(&self) -> Result<super::Type<'dwarf, R>, crate::Error> {
         let entry = self.unit.entry(self.discr_type_offset)?;
@@ -121,7 +137,7 @@ where
     }
 
     /// The discriminant of this type.
-    pub fn discriminant_location(&self) -> &super::Offset<R> {
+    pub fn discriminant_location(&self) -> &super::Offset<'dwarf, R> {
         &self.location
     }
 
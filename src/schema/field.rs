@@ -10,6 +10,7 @@ where
     dwarf: &'dwarf crate::gimli::Dwarf<R>,
     unit: &'dwarf crate::gimli::Unit<R, usize>,
     entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    slice_tail: bool,
 }
 
 impl<'dwarf, R> Field<'dwarf, R>
@@ -26,7 +27,56 @@ where
         let _tree = unit.entries_tree(Some(entry.offset()))?;
         //crate::debug::inspect_tree(&mut tree, dwarf, unit);
         crate::check_tag(&entry, crate::gimli::DW_TAG_member)?;
-        Ok(Self { dwarf, unit, entry })
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            slice_tail: false,
+        })
+    }
+
+    /// Construct a new `Field` for a base-class subobject from a (C++)
+    /// [`DW_TAG_inheritance`][crate::gimli::DW_TAG_inheritance].
+    pub(crate) fn from_dw_tag_inheritance(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        crate::check_tag(&entry, crate::gimli::DW_TAG_inheritance)?;
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            slice_tail: false,
+        })
+    }
+
+    /// Construct a new `Field` for a (C++) static member from a
+    /// [`DW_TAG_member`][crate::gimli::DW_TAG_member] or
+    /// [`DW_TAG_variable`][crate::gimli::DW_TAG_variable] declaration.
+    pub(crate) fn from_dw_tag_static(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        if !is_static(&entry)? {
+            return Err(crate::error::missing_attr(crate::gimli::DW_AT_declaration));
+        }
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            slice_tail: false,
+        })
+    }
+
+    /// Marks this field as the unsized `[T]` tail of a dynamically sized
+    /// struct.
+    pub(crate) fn into_slice_tail(self) -> Self {
+        Self {
+            slice_tail: true,
+            ..self
+        }
     }
 
     /// The [DWARF](crate::gimli::Dwarf) sections that this `Field`'s debuginfo
@@ -51,11 +101,64 @@ where
         &self.entry
     }
 
-    /// The name of this primitive type.
+    /// The name of this field.
+    ///
+    /// Base-class subobjects are named after their class.
     pub fn name(&self) -> Result<Name<R>, crate::Error> {
+        if self.is_base() {
+            let r#type = crate::get_type_res(self.unit, &self.entry)?;
+            return Name::from_die(self.dwarf(), self.unit(), &r#type);
+        }
         Name::from_die(self.dwarf(), self.unit(), self.entry())
     }
 
+    /// Whether this field is a (C++) base-class subobject.
+    pub fn is_base(&self) -> bool {
+        self.entry.tag() == crate::gimli::DW_TAG_inheritance
+    }
+
+    /// Whether this field is a (C++) static member.
+    pub fn is_static(&self) -> Result<bool, crate::Error> {
+        is_static(&self.entry)
+    }
+
+    /// The static address of the storage of this (C++) static member; i.e.,
+    /// the `DW_AT_location` of its definition in the same unit, if it has one.
+    ///
+    /// Static members initialized in their class (e.g., `static const int
+    /// limit = 8;`), and those discarded by the linker, have no storage.
+    pub fn static_address(&self) -> Result<Option<u64>, crate::Error> {
+        if !self.is_static()? {
+            return Ok(None);
+        }
+        let declaration = self.entry.offset();
+        let mut entries = self.unit.entries();
+        while let Some((_, entry)) = entries.next_dfs()? {
+            if entry.tag() != crate::gimli::DW_TAG_variable {
+                continue;
+            }
+            if crate::get_attr_ref(entry, crate::gimli::DW_AT_specification)? == Some(declaration) {
+                let address = crate::get_static_address(self.dwarf, self.unit, entry)?;
+                // linkers may discard unused statics, leaving a tombstone
+                // address in their stead
+                return Ok(address.filter(|&address| address != 0 && address != u64::MAX));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Whether this field is a (C++) vtable pointer; e.g., `_vptr.Base`.
+    pub fn is_vtable_pointer(&self) -> Result<bool, crate::Error> {
+        if self.is_base() {
+            return Ok(false);
+        }
+        let Some(name) = Name::from_die_opt(self.dwarf(), self.unit(), self.entry())? else {
+            return Ok(false);
+        };
+        // GCC names these `_vptr.Class`, and Clang `_vptr$Class`
+        Ok(name.to_slice()?.starts_with(b"_vptr"))
+    }
+
     /// The size of this field, in bytes.
     pub fn size(&self) -> Result<Option<u64>, crate::Error> {
         crate::get_size_opt(self.entry())
@@ -67,12 +170,55 @@ where
     }
 
     /// The offset at which this field occurs.
+    ///
+    /// Fails for (C++) static members, which are stored apart from their
+    /// struct; see [`static_address`][Self::static_address].
     pub fn offset(&'dwarf self) -> Result<Offset<'dwarf, R>, crate::Error> {
+        if self.is_static()? {
+            bail!("static member `{}` has no offset", self.name()?);
+        }
         Offset::from_die(self.unit(), self.entry())
     }
 
+    /// The width of this field, in bits, if it is a bitfield.
+    pub fn bit_size(&self) -> Result<Option<u64>, crate::Error> {
+        let Some(bit_size) = crate::get_opt(self.entry(), crate::gimli::DW_AT_bit_size)? else {
+            return Ok(None);
+        };
+        bit_size
+            .udata_value()
+            .map(Some)
+            .ok_or_else(|| crate::error::invalid_attr(crate::gimli::DW_AT_bit_size))
+    }
+
+    /// Whether this field is the unsized tail of a dynamically sized struct;
+    /// e.g., the `body` of `struct Packet { header: u32, body: [u8] }`.
+    pub fn is_unsized(&self) -> Result<bool, crate::Error> {
+        if self.slice_tail {
+            return Ok(true);
+        }
+        match self.r#type()? {
+            Type::Struct(r#type) => r#type.is_unsized(),
+            _ => Ok(false),
+        }
+    }
+
     /// The type of the field.
+    ///
+    /// Bitfield members are reflected as a [`Bitfield`][super::Bitfield] of
+    /// their declared type, and unsized `[T]` tails as an
+    /// [`UnsizedSlice`][super::UnsizedSlice].
     pub fn r#type(&self) -> Result<Type<'dwarf, R>, crate::Error> {
+        if self.slice_tail {
+            let entry = self.entry.clone();
+            return super::UnsizedSlice::from_dw_tag_member(self.dwarf, self.unit, entry)
+                .map(Type::UnsizedSlice);
+        }
+        if self.bit_size()?.is_some() {
+            let entry = self.entry.clone();
+            return super::Bitfield::from_dw_tag_member(self.dwarf, self.unit, entry)
+                .map(Type::Bitfield);
+        }
         let r#type = crate::get_type_res(self.unit, &self.entry)?;
         super::Type::from_die(self.dwarf, self.unit, r#type)
     }
@@ -86,3 +232,18 @@ where
         self.name().map_err(crate::fmt_err)?.fmt(f)
     }
 }
+
+/// Whether `entry` declares a (C++) static member; i.e., is a
+/// [`DW_TAG_member`][crate::gimli::DW_TAG_member] (DWARF 4) or
+/// [`DW_TAG_variable`][crate::gimli::DW_TAG_variable] (DWARF 5) declaration.
+pub(crate) fn is_static<R>(
+    entry: &crate::gimli::DebuggingInformationEntry<'_, '_, R>,
+) -> Result<bool, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    Ok(matches!(
+        entry.tag(),
+        crate::gimli::DW_TAG_member | crate::gimli::DW_TAG_variable
+    ) && crate::get_opt(entry, crate::gimli::DW_AT_declaration)?.is_some())
+}
@@ -24,10 +24,13 @@ where
 
     /// Produces an iterator over fields.
     pub fn iter(&mut self) -> Result<FieldsIter<'dwarf, '_, R>, crate::Error> {
+        let root = self.tree.root()?;
+        let parent_size = crate::get_size_opt(root.entry())?;
         Ok(FieldsIter {
             dwarf: self.dwarf,
             unit: self.unit,
-            iter: self.tree.root()?.children(),
+            parent_size,
+            iter: root.children(),
         })
     }
 }
@@ -39,6 +42,7 @@ where
 {
     dwarf: &'dwarf crate::gimli::Dwarf<R>,
     unit: &'dwarf crate::gimli::Unit<R, usize>,
+    parent_size: Option<u64>,
     iter: crate::gimli::EntriesTreeIter<'dwarf, 'dwarf, 'tree, R>,
 }
 
@@ -49,16 +53,49 @@ where
     /// Produces the next field, if any.
     pub fn try_next(&mut self) -> Result<Option<super::Field<'dwarf, R>>, crate::Error> {
         loop {
-            let Some(next) = self.iter.next()? else { return Ok(None) };
+            let Some(next) = self.iter.next()? else {
+                return Ok(None);
+            };
             let entry = next.entry();
-            if entry.tag() != crate::gimli::DW_TAG_member {
-                continue;
+            match entry.tag() {
+                crate::gimli::DW_TAG_inheritance => {
+                    return Ok(Some(super::Field::from_dw_tag_inheritance(
+                        self.dwarf,
+                        self.unit,
+                        entry.clone(),
+                    )?));
+                }
+                crate::gimli::DW_TAG_member if !super::field::is_static(entry)? => {
+                    let field =
+                        super::Field::from_dw_tag_member(self.dwarf, self.unit, entry.clone())?;
+                    if self.is_slice_tail(&field)? {
+                        return Ok(Some(field.into_slice_tail()));
+                    }
+                    return Ok(Some(field));
+                }
+                _ => continue,
             }
-            return Ok(Some(super::Field::from_dw_tag_member(
-                self.dwarf,
-                self.unit,
-                entry.clone(),
-            )?));
+        }
+    }
+
+    /// Whether `field` is an unsized `[T]` tail, which rustc describes as a
+    /// member of type `T` that begins at or past the end of its struct.
+    fn is_slice_tail(&self, field: &super::Field<'dwarf, R>) -> Result<bool, crate::Error> {
+        let Some(parent_size) = self.parent_size else {
+            return Ok(false);
+        };
+        if field.bit_size()?.is_some() {
+            return Ok(false);
+        }
+        let offset = super::Offset::from_die(self.unit, field.entry())?.address(0)?;
+        if offset < parent_size {
+            return Ok(false);
+        }
+        match field.r#type()? {
+            // a nested dynamically sized struct, e.g. the `Mutex<dyn Trait>` of
+            // `ArcInner<Mutex<dyn Trait>>`, is not itself a slice
+            super::Type::Struct(r#type) if r#type.is_unsized()? => Ok(false),
+            r#type => Ok(r#type.size()? > 0),
         }
     }
 }
@@ -10,6 +10,7 @@ where
     dwarf: &'dwarf crate::gimli::Dwarf<R>,
     unit: &'dwarf crate::gimli::Unit<R, usize>,
     entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    pointer: Option<crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>>,
 }
 
 impl<'dwarf, R> Function<'dwarf, R>
@@ -24,7 +25,33 @@ where
         entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
     ) -> Result<Self, crate::Error> {
         crate::check_tag(&entry, crate::gimli::DW_TAG_subroutine_type)?;
-        Ok(Self { dwarf, unit, entry })
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            pointer: None,
+        })
+    }
+
+    /// Construct a new `Function` from a
+    /// [`DW_TAG_pointer_type`][crate::gimli::DW_TAG_pointer_type] (i.e., a
+    /// function pointer) and the
+    /// [`DW_TAG_subroutine_type`][crate::gimli::DW_TAG_subroutine_type] it
+    /// points to.
+    pub(crate) fn from_dw_tag_pointer_type(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        pointer: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        crate::check_tag(&pointer, crate::gimli::DW_TAG_pointer_type)?;
+        crate::check_tag(&entry, crate::gimli::DW_TAG_subroutine_type)?;
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            pointer: Some(pointer),
+        })
     }
 
     /// The [DWARF](crate::gimli::Dwarf) sections that this `Function`'s
@@ -49,13 +76,154 @@ where
         &self.entry
     }
 
-    /// The name of this type.
+    /// The name of this type; e.g., `fn(u8) -> u32`.
     pub fn name(&self) -> Result<Name<R>, crate::Error> {
-        println!(
-            "{:?}",
-            crate::debug::DebugEntry::new(self.dwarf, self.unit, &self.entry)
-        );
-        Name::from_die(self.dwarf(), self.unit(), self.entry())
+        if let Some(pointer) = &self.pointer {
+            Name::from_die(self.dwarf(), self.unit(), pointer)
+        } else {
+            Name::from_die(self.dwarf(), self.unit(), self.entry())
+        }
+    }
+
+    /// The types of this function's parameters.
+    pub fn params(&self) -> Result<Vec<super::Type<'dwarf, R>>, crate::Error> {
+        let mut params = vec![];
+        for offset in self.param_offsets()? {
+            let entry = self.unit.entry(offset)?;
+            params.push(super::Type::from_die(self.dwarf, self.unit, entry)?);
+        }
+        Ok(params)
+    }
+
+    /// The return type of this function, or `None` if it returns `()`.
+    pub fn return_type(&self) -> Result<Option<super::Type<'dwarf, R>>, crate::Error> {
+        let Some(offset) = crate::get_attr_ref(&self.entry, crate::gimli::DW_AT_type)? else {
+            return Ok(None);
+        };
+        let entry = self.unit.entry(offset)?;
+        super::Type::from_die(self.dwarf, self.unit, entry).map(Some)
+    }
+
+    /// Whether this function takes a variable number of arguments; e.g.,
+    /// `unsafe extern "C" fn(i32, ...)`.
+    pub fn is_variadic(&self) -> Result<bool, crate::Error> {
+        let mut tree = self.unit.entries_tree(Some(self.entry.offset()))?;
+        let mut children = tree.root()?.children();
+        while let Some(child) = children.next()? {
+            if child.entry().tag() == crate::gimli::DW_TAG_unspecified_parameters {
+                return Ok(true);
+            }
+        }
+        // rustc does not emit `DW_TAG_unspecified_parameters`, but records
+        // variadics in the name of the function pointer type.
+        Ok(self.pointer_name()?.map_or(false, |name| {
+            name.ends_with("...)") || name.contains("...) ->")
+        }))
+    }
+
+    /// Whether this function is `unsafe` to call.
+    pub fn is_unsafe(&self) -> Result<bool, crate::Error> {
+        Ok(self
+            .pointer_name()?
+            .map_or(false, |name| name.starts_with("unsafe ")))
+    }
+
+    /// The ABI of this function, if it is not the Rust ABI; e.g., `C`.
+    pub fn abi(&self) -> Result<Option<String>, crate::Error> {
+        if let Some(crate::gimli::AttributeValue::CallingConvention(cc)) =
+            crate::get_opt(&self.entry, crate::gimli::DW_AT_calling_convention)?
+        {
+            // the vendor-specific calling conventions of LLVM
+            let abi = match cc.0 {
+                0xb1 => Some("stdcall"),
+                0xb3 => Some("fastcall"),
+                0xb5 => Some("thiscall"),
+                0xc0 => Some("vectorcall"),
+                0xc1 => Some("win64"),
+                0xc2 => Some("sysv64"),
+                0xc3 | 0xc4 => Some("aapcs"),
+                _ => None,
+            };
+            if let Some(abi) = abi {
+                return Ok(Some(abi.to_owned()));
+            }
+        }
+        if !self.is_rust()? {
+            return Ok(Some("C".to_owned()));
+        }
+        // rustc does not emit `DW_AT_calling_convention` for the ABIs it
+        // shares with C, but records them in the name of the function pointer
+        // type.
+        let Some(name) = self.pointer_name()? else {
+            return Ok(None);
+        };
+        let name = name.strip_prefix("unsafe ").unwrap_or(&name);
+        let Some(abi) = name.strip_prefix("extern \"") else {
+            return Ok(None);
+        };
+        Ok(abi.split_once('"').map(|(abi, _)| abi.to_owned()))
+    }
+
+    /// Whether this function was described by rustc.
+    fn is_rust(&self) -> Result<bool, crate::Error> {
+        let mut entries = self.unit.entries();
+        let Some((_, root)) = entries.next_dfs()? else {
+            return Ok(true);
+        };
+        Ok(!matches!(
+            crate::get_opt(root, crate::gimli::DW_AT_language)?,
+            Some(crate::gimli::AttributeValue::Language(lang))
+                if lang != crate::gimli::DW_LANG_Rust
+        ))
+    }
+
+    /// The offsets of the types of this function's parameters.
+    fn param_offsets(&self) -> Result<Vec<crate::gimli::UnitOffset>, crate::Error> {
+        let mut tree = self.unit.entries_tree(Some(self.entry.offset()))?;
+        let mut children = tree.root()?.children();
+        let mut offsets = vec![];
+        while let Some(child) = children.next()? {
+            let entry = child.entry();
+            if entry.tag() == crate::gimli::DW_TAG_formal_parameter {
+                offsets.push(crate::get_type_ref(entry)?);
+            }
+        }
+        Ok(offsets)
+    }
+
+    /// The name of the function pointer type, if any.
+    fn pointer_name(&self) -> Result<Option<String>, crate::Error> {
+        let Some(pointer) = &self.pointer else {
+            return Ok(None);
+        };
+        let Some(name) = Name::from_die_opt(self.dwarf, self.unit, pointer)? else {
+            return Ok(None);
+        };
+        Ok(Some(name.to_string_lossy()?.into_owned()))
+    }
+
+    /// The name of the type at `offset`, for rendering signatures.
+    fn type_name(&self, offset: crate::gimli::UnitOffset) -> Result<String, crate::Error> {
+        let entry = self.unit.entry(offset)?;
+        if let Some(name) = Name::from_die_opt(self.dwarf, self.unit, &entry)? {
+            return Ok(name.to_string_lossy()?.into_owned());
+        }
+        Ok(super::Type::from_die(self.dwarf, self.unit, entry)?.to_string())
+    }
+
+    /// Whether this type is a function pointer (as opposed to the bare type
+    /// of a function).
+    pub fn is_pointer(&self) -> bool {
+        self.pointer.is_some()
+    }
+
+    /// The size of this type, in bytes.
+    pub fn size(&self) -> u64 {
+        if self.is_pointer() {
+            core::mem::size_of::<usize>() as _
+        } else {
+            0
+        }
     }
 }
 
@@ -79,6 +247,29 @@ where
     R: crate::gimli::Reader<Offset = usize>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("fn()")
+        if self.is_unsafe().map_err(crate::fmt_err)? {
+            f.write_str("unsafe ")?;
+        }
+        if let Some(abi) = self.abi().map_err(crate::fmt_err)? {
+            write!(f, "extern {abi:?} ")?;
+        }
+        f.write_str("fn(")?;
+        let params = self.param_offsets().map_err(crate::fmt_err)?;
+        for (i, param) in params.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(&self.type_name(*param).map_err(crate::fmt_err)?)?;
+        }
+        if self.is_variadic().map_err(crate::fmt_err)? {
+            f.write_str(if params.is_empty() { "..." } else { ", ..." })?;
+        }
+        f.write_str(")")?;
+        if let Some(ret) =
+            crate::get_attr_ref(&self.entry, crate::gimli::DW_AT_type).map_err(crate::fmt_err)?
+        {
+            write!(f, " -> {}", self.type_name(ret).map_err(crate::fmt_err)?)?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,145 @@
+use std::fmt;
+
+/// A schema for a generator; e.g., the state machine of an `async fn`.
+///
+/// Generators are encoded in DWARF as enums whose variants are the states of
+/// the generator: `Unresumed`, `Returned`, `Panicked`, and a `SuspendN` state
+/// for each yield point (i.e., each `.await`).
+#[derive(Clone)]
+pub struct Generator<'dwarf, R: crate::gimli::Reader<Offset = usize>>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    schema: super::Enum<'dwarf, R>,
+    /// The fully-qualified path of this type, once computed.
+    path: once_cell::unsync::OnceCell<String>,
+}
+
+impl<'dwarf, R> Generator<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    /// Construct a new `Generator` from a
+    /// [`DW_TAG_structure_type`][crate::gimli::DW_TAG_structure_type].
+    pub(crate) fn from_dw_tag_structure_type(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        let name = super::Name::from_die(dwarf, unit, &entry)?;
+        if !is_generator_name(&name.to_slice()?) {
+            let actual = name.to_string_lossy()?.to_string();
+            Err(crate::error::name_mismatch("{async_fn_env#N}", actual))?;
+        }
+        let schema = super::Enum::from_dw_tag_structure_type(dwarf, unit, entry)?;
+        Ok(Self {
+            schema,
+            path: Default::default(),
+        })
+    }
+
+    /// The [DWARF](crate::gimli::Dwarf) sections that this `Generator`'s
+    /// debuginfo belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+        self.schema.dwarf()
+    }
+
+    /// The DWARF [unit][crate::gimli::Unit] that this `Generator`'s debuginfo
+    /// belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, usize> {
+        self.schema.unit()
+    }
+
+    /// The [debugging information
+    /// entry][crate::gimli::DebuggingInformationEntry] this `Generator`
+    /// abstracts over.
+    #[allow(dead_code)]
+    pub(crate) fn entry(&self) -> &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R> {
+        self.schema.entry()
+    }
+
+    /// This generator, interpreted as an enum of its states.
+    pub fn as_enum(&self) -> &super::Enum<'dwarf, R> {
+        &self.schema
+    }
+
+    /// The name of this type; e.g., `{async_fn_env#0}`.
+    pub fn name(&self) -> &super::Name<R> {
+        self.schema.name()
+    }
+
+    /// The fully-qualified path of this type; e.g.,
+    /// `my_crate::handler::{async_fn_env#0}`.
+    pub fn path(&self) -> Result<String, crate::Error> {
+        // computing the path requires a traversal of the whole unit
+        self.path
+            .get_or_try_init(|| {
+                crate::get_qualified_name(self.dwarf(), self.unit(), self.entry().offset())
+            })
+            .cloned()
+    }
+
+    /// The fully-qualified path of the function that defines this generator;
+    /// e.g., `my_crate::handler`.
+    pub fn function(&self) -> Result<String, crate::Error> {
+        let mut path = self.path()?;
+        if let Some(index) = path.rfind("::{") {
+            path.truncate(index);
+        }
+        Ok(path)
+    }
+
+    /// The states of this generator.
+    pub fn states(&self) -> Result<super::Variants<'dwarf, R>, crate::Error> {
+        self.schema.variants()
+    }
+
+    /// The size of this type, in bytes.
+    pub fn size(&self) -> Result<u64, crate::Error> {
+        self.schema.size()
+    }
+
+    /// The alignment of this type, in bytes.
+    pub fn align(&self) -> Result<Option<u64>, crate::Error> {
+        self.schema.align()
+    }
+}
+
+/// Whether `name` is the name rustc gives to generator types.
+pub(crate) fn is_generator_name(name: &[u8]) -> bool {
+    [
+        &b"{async_fn_env#"[..],
+        b"{async_block_env#",
+        b"{async_closure_env#",
+        b"{coroutine_env#",
+        b"{generator_env#",
+    ]
+    .iter()
+    .any(|prefix| name.starts_with(prefix))
+}
+
+impl<'dwarf, R> fmt::Debug for Generator<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple("deflect::schema::Generator");
+        debug_tuple.field(&crate::debug::DebugEntry::new(
+            self.dwarf(),
+            self.unit(),
+            self.entry(),
+        ));
+        debug_tuple.finish()
+    }
+}
+
+impl<'dwarf, R> fmt::Display for Generator<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.path().map_err(crate::fmt_err)?.fmt(f)
+    }
+}
@@ -0,0 +1,276 @@
+use super::{Name, Type};
+use std::fmt;
+
+/// A generic argument of a [struct][super::Struct] or [enum][super::Enum].
+#[derive(Debug, Clone)]
+pub enum Generic<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    /// A type argument; e.g., the `u32` of `Vec<u32>`.
+    Type {
+        /// The name of the parameter; e.g., `T`.
+        name: Name<R>,
+        /// The argument.
+        r#type: Type<'dwarf, R>,
+    },
+    /// A const argument; e.g., the `3` of `[u8; 3]`.
+    Const {
+        /// The name of the parameter (e.g., `N`), if recorded.
+        name: Option<Name<R>>,
+        /// The argument.
+        value: ConstValue,
+    },
+}
+
+impl<'dwarf, R> Generic<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    /// The name of the parameter, if recorded.
+    pub fn name(&self) -> Option<&Name<R>> {
+        match self {
+            Self::Type { name, .. } => Some(name),
+            Self::Const { name, .. } => name.as_ref(),
+        }
+    }
+
+    /// The type argument, if this is a type argument.
+    pub fn as_type(&self) -> Option<&Type<'dwarf, R>> {
+        match self {
+            Self::Type { r#type, .. } => Some(r#type),
+            Self::Const { .. } => None,
+        }
+    }
+
+    /// The const argument, if this is a const argument.
+    pub fn as_const(&self) -> Option<&ConstValue> {
+        match self {
+            Self::Type { .. } => None,
+            Self::Const { value, .. } => Some(value),
+        }
+    }
+}
+
+impl<'dwarf, R> fmt::Display for Generic<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Type { name, r#type } => write!(f, "{name} = {type}"),
+            Self::Const {
+                name: Some(name),
+                value,
+            } => write!(f, "const {name} = {value}"),
+            Self::Const { name: None, value } => value.fmt(f),
+        }
+    }
+}
+
+/// The value of a const generic argument.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConstValue {
+    /// A [`bool`][prim@bool].
+    Bool(bool),
+    /// A [`char`][prim@char].
+    Char(char),
+    /// A signed integer.
+    Int(i128),
+    /// An unsigned integer.
+    UInt(u128),
+    /// A value that the debuginfo only identifies by an opaque hash; e.g.,
+    /// `{CONST#d04753e636d39563}`.
+    Opaque(String),
+}
+
+impl fmt::Display for ConstValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => value.fmt(f),
+            Self::Char(value) => write!(f, "{value:?}"),
+            Self::Int(value) => value.fmt(f),
+            Self::UInt(value) => value.fmt(f),
+            Self::Opaque(value) => value.fmt(f),
+        }
+    }
+}
+
+impl ConstValue {
+    /// Parses a const argument, as rustc renders it in type names.
+    fn parse(arg: &str) -> Option<Self> {
+        match arg {
+            "true" => Some(Self::Bool(true)),
+            "false" => Some(Self::Bool(false)),
+            _ if arg.starts_with("{CONST#") => Some(Self::Opaque(arg.to_owned())),
+            _ if arg.starts_with('-') => arg.parse().ok().map(Self::Int),
+            _ => arg.parse().ok().map(Self::UInt),
+        }
+    }
+}
+
+/// Produces the generic arguments of the type described by `entry`.
+///
+/// rustc records type parameters as
+/// [`DW_TAG_template_type_parameter`][crate::gimli::DW_TAG_template_type_parameter]s,
+/// but does not (yet) emit
+/// [`DW_TAG_template_value_parameter`][crate::gimli::DW_TAG_template_value_parameter]s
+/// for const parameters; those are recovered from the type's name.
+pub(crate) fn generics_of<'dwarf, R>(
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+) -> Result<Vec<Generic<'dwarf, R>>, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let (mut generics, mut has_values) = template_params(dwarf, unit, entry.offset())?;
+    if generics.is_empty() {
+        // rustc attaches the template parameters of enums to the structs of
+        // their variants, rather than to the enum itself
+        let mut tree = unit.entries_tree(Some(entry.offset()))?;
+        let mut children = tree.root()?.children();
+        while let Some(child) = children.next()? {
+            if child.entry().tag() == crate::gimli::DW_TAG_structure_type {
+                (generics, has_values) = template_params(dwarf, unit, child.entry().offset())?;
+                break;
+            }
+        }
+    }
+    if has_values {
+        return Ok(generics);
+    }
+    let Some(name) = Name::from_die_opt(dwarf, unit, entry)? else {
+        return Ok(generics);
+    };
+    let name = name.to_string_lossy()?;
+    let Some(args) = generic_args(&name) else {
+        return Ok(generics);
+    };
+    // interleave the const arguments named by the type with its type
+    // parameters
+    let mut types = generics.into_iter();
+    let mut generics = vec![];
+    for arg in args {
+        if let Some(value) = ConstValue::parse(arg) {
+            generics.push(Generic::Const { name: None, value });
+        } else if let Some(r#type) = types.next() {
+            generics.push(r#type);
+        }
+    }
+    generics.extend(types);
+    Ok(generics)
+}
+
+/// Produces the template parameters that are children of the entry at
+/// `offset`, and whether any are value parameters.
+fn template_params<'dwarf, R>(
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    offset: crate::gimli::UnitOffset,
+) -> Result<(Vec<Generic<'dwarf, R>>, bool), crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let mut generics = vec![];
+    let mut has_values = false;
+    let mut tree = unit.entries_tree(Some(offset))?;
+    let mut children = tree.root()?.children();
+    while let Some(child) = children.next()? {
+        let entry = child.entry();
+        match entry.tag() {
+            crate::gimli::DW_TAG_template_type_parameter => {
+                let name = Name::from_die(dwarf, unit, entry)?;
+                let r#type = crate::get_type_res(unit, entry)?;
+                let r#type = Type::from_die(dwarf, unit, r#type)?;
+                generics.push(Generic::Type { name, r#type });
+            }
+            crate::gimli::DW_TAG_template_value_parameter => {
+                has_values = true;
+                let name = Name::from_die_opt(dwarf, unit, entry)?;
+                let value = const_value(dwarf, unit, entry)?;
+                generics.push(Generic::Const { name, value });
+            }
+            _ => {}
+        }
+    }
+    Ok((generics, has_values))
+}
+
+/// Interprets the `DW_AT_const_value` of a
+/// [`DW_TAG_template_value_parameter`][crate::gimli::DW_TAG_template_value_parameter].
+fn const_value<R>(
+    dwarf: &crate::gimli::Dwarf<R>,
+    unit: &crate::gimli::Unit<R, usize>,
+    entry: &crate::gimli::DebuggingInformationEntry<'_, '_, R>,
+) -> Result<ConstValue, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let value = crate::get(entry, crate::gimli::DW_AT_const_value)?;
+    let r#type = crate::get_type_res(unit, entry)?;
+    let r#type = Type::from_die(dwarf, unit, r#type)?;
+    let size = r#type.size()?;
+    let bits = match value {
+        crate::gimli::AttributeValue::Sdata(value) => value as u128,
+        crate::gimli::AttributeValue::Block(block) => {
+            let block = block.to_slice()?;
+            let mut bytes = [0u8; 16];
+            let len = block.len().min(16);
+            bytes[..len].copy_from_slice(&block[..len]);
+            u128::from_le_bytes(bytes)
+        }
+        value => value
+            .udata_value()
+            .ok_or_else(|| crate::error::invalid_attr(crate::gimli::DW_AT_const_value))?
+            .into(),
+    };
+    let unused = 128 - 8 * size.clamp(1, 16) as u32;
+    Ok(match r#type {
+        Type::bool(_) => ConstValue::Bool(bits != 0),
+        Type::char(_) => char::from_u32(bits as u32)
+            .map(ConstValue::Char)
+            .ok_or_else(|| crate::error::invalid_attr(crate::gimli::DW_AT_const_value))?,
+        Type::i8(_)
+        | Type::i16(_)
+        | Type::i32(_)
+        | Type::i64(_)
+        | Type::i128(_)
+        | Type::isize(_) => ConstValue::Int(((bits << unused) as i128) >> unused),
+        _ => ConstValue::UInt((bits << unused) >> unused),
+    })
+}
+
+/// Splits the generic arguments off a type name; e.g., `["u8", "3"]` for
+/// `Buf<u8, 3>`.
+fn generic_args(name: &str) -> Option<Vec<&str>> {
+    let name = name.strip_suffix('>')?;
+    let mut depth = 0usize;
+    let mut prev = None;
+    let mut start = None;
+    let mut args = vec![];
+    for (i, c) in name.char_indices() {
+        match c {
+            '<' if depth == 0 => {
+                depth += 1;
+                start = Some(i + 1);
+                args.clear();
+            }
+            '<' | '(' | '[' => depth += 1,
+            '>' if prev == Some('-') => {}
+            '>' | ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 1 => {
+                args.push(name[start?..i].trim());
+                start = Some(i + 1);
+            }
+            _ => {}
+        }
+        prev = Some(c);
+    }
+    if depth != 1 {
+        return None;
+    }
+    args.push(name[start?..].trim());
+    Some(args)
+}
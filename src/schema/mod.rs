@@ -1,6 +1,7 @@
 //! Reflections of Rust types.
 
 mod array;
+mod bitfield;
 mod r#box;
 mod boxed_dyn;
 mod boxed_slice;
@@ -9,24 +10,37 @@ mod r#enum;
 mod field;
 mod fields;
 mod function;
+mod generator;
+mod generics;
 mod name;
 mod offset;
 mod pointer;
+mod qualified;
 mod slice;
 mod str_impl;
 mod r#struct;
+mod typedef;
+mod unsized_slice;
+mod unspecified;
 mod variant;
 mod variants;
+mod vector;
+mod vtable;
 
 pub use array::Array;
+pub use bitfield::Bitfield;
 pub use boxed_dyn::BoxedDyn;
 pub use boxed_slice::BoxedSlice;
 pub use data::Data;
 pub use fields::{Fields, FieldsIter};
 pub use function::Function;
+pub use generator::Generator;
+pub use generics::{ConstValue, Generic};
 pub use name::Name;
 pub use offset::Offset;
+pub(crate) use pointer::Wide;
 pub use pointer::{Const, Mut, Pointer, Reference, Shared, Unique};
+pub use qualified::{Qualified, Qualifier};
 pub use r#box::Box;
 pub use r#enum::Enum;
 pub use r#field::Field;
@@ -34,7 +48,13 @@ pub use r#struct::Struct;
 pub use r#variant::Variant;
 pub use slice::Slice;
 pub use str_impl::str;
+pub use typedef::Typedef;
+pub use unsized_slice::UnsizedSlice;
+pub use unspecified::Unspecified;
 pub use variants::{Variants, VariantsIter};
+pub use vector::Vector;
+pub(crate) use vtable::split_qualified_self;
+pub use vtable::Vtable;
 
 /// A reflected shared reference type.
 pub type SharedRef<'dwarf, R> = crate::schema::Pointer<'dwarf, crate::schema::Shared, R>;
@@ -59,34 +79,73 @@ where
     ) -> Result<Self, crate::Error> {
         Ok(match entry.tag() {
             crate::gimli::DW_TAG_base_type => {
+                // Base types are classified by their encoding and size, rather
+                // than by name, so that C's `int`, `unsigned char`, etc.
+                // reflect as their Rust equivalents.
                 let name = Name::from_die(dwarf, unit, &entry)?;
                 let name = name.to_slice()?;
-                return match name.as_ref() {
-                    b"bool" => bool::from_dw_tag_base_type(dwarf, unit, entry).map(Self::bool),
-                    b"char" => char::from_dw_tag_base_type(dwarf, unit, entry).map(Self::char),
-                    b"f32" => f32::from_dw_tag_base_type(dwarf, unit, entry).map(Self::f32),
-                    b"f64" => f64::from_dw_tag_base_type(dwarf, unit, entry).map(Self::f64),
-                    b"i8" => i8::from_dw_tag_base_type(dwarf, unit, entry).map(Self::i8),
-                    b"i16" => i16::from_dw_tag_base_type(dwarf, unit, entry).map(Self::i16),
-                    b"i32" => i32::from_dw_tag_base_type(dwarf, unit, entry).map(Self::i32),
-                    b"i64" => i64::from_dw_tag_base_type(dwarf, unit, entry).map(Self::i64),
-                    b"i128" => i128::from_dw_tag_base_type(dwarf, unit, entry).map(Self::i128),
-                    b"isize" => isize::from_dw_tag_base_type(dwarf, unit, entry).map(Self::isize),
-                    b"u8" => u8::from_dw_tag_base_type(dwarf, unit, entry).map(Self::u8),
-                    b"u16" => u16::from_dw_tag_base_type(dwarf, unit, entry).map(Self::u16),
-                    b"u32" => u32::from_dw_tag_base_type(dwarf, unit, entry).map(Self::u32),
-                    b"u64" => u64::from_dw_tag_base_type(dwarf, unit, entry).map(Self::u64),
-                    b"u128" => u128::from_dw_tag_base_type(dwarf, unit, entry).map(Self::u128),
-                    b"usize" => usize::from_dw_tag_base_type(dwarf, unit, entry).map(Self::usize),
-                    b"()" => unit::from_dw_tag_base_type(dwarf, unit, entry).map(Self::unit),
-                    _ => unimplemented!(
-                        "unhandled primitive: {:#?}",
-                        crate::debug::DebugEntry::new(dwarf, unit, &entry)
-                    ),
+                let encoding = match crate::get(&entry, crate::gimli::DW_AT_encoding)? {
+                    crate::gimli::AttributeValue::Encoding(encoding) => encoding,
+                    _ => return Err(crate::error::invalid_attr(crate::gimli::DW_AT_encoding)),
+                };
+                let size = crate::get_size(&entry)?;
+                return match (encoding, size, name.as_ref()) {
+                    (_, 0, b"()") => {
+                        unit::from_dw_tag_base_type(dwarf, unit, entry).map(Self::unit)
+                    }
+                    (crate::gimli::DW_ATE_boolean, 1, _) => {
+                        bool::from_dw_tag_base_type(dwarf, unit, entry).map(Self::bool)
+                    }
+                    (crate::gimli::DW_ATE_UTF, 4, b"char") => {
+                        char::from_dw_tag_base_type(dwarf, unit, entry).map(Self::char)
+                    }
+                    (crate::gimli::DW_ATE_float, 4, _) => {
+                        f32::from_dw_tag_base_type(dwarf, unit, entry).map(Self::f32)
+                    }
+                    (crate::gimli::DW_ATE_float, 8, _) => {
+                        f64::from_dw_tag_base_type(dwarf, unit, entry).map(Self::f64)
+                    }
+                    (crate::gimli::DW_ATE_signed | crate::gimli::DW_ATE_signed_char, _, _) => {
+                        match (size, name.as_ref()) {
+                            (_, b"isize") => {
+                                isize::from_dw_tag_base_type(dwarf, unit, entry).map(Self::isize)
+                            }
+                            (1, _) => i8::from_dw_tag_base_type(dwarf, unit, entry).map(Self::i8),
+                            (2, _) => i16::from_dw_tag_base_type(dwarf, unit, entry).map(Self::i16),
+                            (4, _) => i32::from_dw_tag_base_type(dwarf, unit, entry).map(Self::i32),
+                            (8, _) => i64::from_dw_tag_base_type(dwarf, unit, entry).map(Self::i64),
+                            (16, _) => {
+                                i128::from_dw_tag_base_type(dwarf, unit, entry).map(Self::i128)
+                            }
+                            _ => Err(unsupported_base_type(dwarf, unit, &entry)),
+                        }
+                    }
+                    (
+                        crate::gimli::DW_ATE_unsigned
+                        | crate::gimli::DW_ATE_unsigned_char
+                        | crate::gimli::DW_ATE_UTF,
+                        _,
+                        _,
+                    ) => match (size, name.as_ref()) {
+                        (_, b"usize") => {
+                            usize::from_dw_tag_base_type(dwarf, unit, entry).map(Self::usize)
+                        }
+                        (1, _) => u8::from_dw_tag_base_type(dwarf, unit, entry).map(Self::u8),
+                        (2, _) => u16::from_dw_tag_base_type(dwarf, unit, entry).map(Self::u16),
+                        (4, _) => u32::from_dw_tag_base_type(dwarf, unit, entry).map(Self::u32),
+                        (8, _) => u64::from_dw_tag_base_type(dwarf, unit, entry).map(Self::u64),
+                        (16, _) => u128::from_dw_tag_base_type(dwarf, unit, entry).map(Self::u128),
+                        _ => Err(unsupported_base_type(dwarf, unit, &entry)),
+                    },
+                    _ => Err(unsupported_base_type(dwarf, unit, &entry)),
                 };
             }
             crate::gimli::DW_TAG_structure_type => {
-                let name = Name::from_die(dwarf, unit, &entry)?;
+                let Some(name) = Name::from_die_opt(dwarf, unit, &entry)? else {
+                    // anonymous C structs
+                    return Struct::from_dw_tag_structure_type(dwarf, unit, entry)
+                        .map(Self::Struct);
+                };
                 let name_slice = name.to_slice()?;
                 if name_slice.starts_with(b"&[") {
                     return Ok(Self::Slice(Slice::from_dw_tag_structure_type(
@@ -96,6 +155,22 @@ where
                     return Ok(Self::str(str::from_dw_tag_structure_type(
                         dwarf, unit, entry,
                     )?));
+                } else if let Some((wide, target)) =
+                    wide_pointer_of(dwarf, unit, &entry, &name_slice)?
+                {
+                    let offset = entry.offset();
+                    let name = Some(name.clone());
+                    return Ok(if name_slice.starts_with(b"alloc::boxed::Box<") {
+                        Self::Box(Box::new_wide(dwarf, unit, offset, name, target, wide))
+                    } else if name_slice.starts_with(b"&mut ") {
+                        Self::UniqueRef(Pointer::new_wide(dwarf, unit, offset, name, target, wide))
+                    } else if name_slice.starts_with(b"&") {
+                        Self::SharedRef(Pointer::new_wide(dwarf, unit, offset, name, target, wide))
+                    } else if name_slice.starts_with(b"*const ") {
+                        Self::ConstPtr(Pointer::new_wide(dwarf, unit, offset, name, target, wide))
+                    } else {
+                        Self::MutPtr(Pointer::new_wide(dwarf, unit, offset, name, target, wide))
+                    });
                 } else if name_slice.starts_with(b"alloc::boxed::Box<") {
                     // boxedslice: data_ptr + length
                     let schema = Struct::from_dw_tag_structure_type(dwarf, unit, entry)?;
@@ -133,19 +208,53 @@ where
                     }
 
                     if let Some(_variants) = variants {
-                        Self::Enum(Enum::from_dw_tag_structure_type(dwarf, unit, entry)?)
+                        if generator::is_generator_name(&name_slice) {
+                            Self::Generator(Generator::from_dw_tag_structure_type(
+                                dwarf, unit, entry,
+                            )?)
+                        } else {
+                            Self::Enum(Enum::from_dw_tag_structure_type(dwarf, unit, entry)?)
+                        }
+                    } else if let Ok(Some(_)) = vector::simd_lanes(dwarf, unit, &entry) {
+                        // a struct that cannot be probed is, at worst, not
+                        // reflected as a vector
+                        Self::Vector(Vector::from_dw_tag_structure_type(dwarf, unit, entry)?)
                     } else {
                         Self::Struct(Struct::from_dw_tag_structure_type(dwarf, unit, entry)?)
                     }
                 }
             }
+            crate::gimli::DW_TAG_class_type => {
+                Self::Struct(Struct::from_dw_tag_class_type(dwarf, unit, entry)?)
+            }
+            crate::gimli::DW_TAG_reference_type | crate::gimli::DW_TAG_rvalue_reference_type => {
+                // C++'s `T&` and `T&&`; see `Pointer::is_rvalue_reference`
+                let target = crate::get_type_ref(&entry)?;
+                Self::SharedRef(Pointer::new(dwarf, unit, entry.offset(), None, target))
+            }
             crate::gimli::DW_TAG_enumeration_type => {
                 Self::Enum(Enum::from_dw_tag_enumeration_type(dwarf, unit, entry)?)
             }
             crate::gimli::DW_TAG_pointer_type => {
                 let name = Name::from_die_opt(dwarf, unit, &entry)?;
-                let target = crate::get_type_ref(&entry)?;
-                if let Some(name) = name {
+                let Some(target) = crate::get_attr_ref(&entry, crate::gimli::DW_AT_type)? else {
+                    // C's `void *`
+                    return Ok(Self::MutPtr(Pointer::void(
+                        dwarf,
+                        unit,
+                        entry.offset(),
+                        name,
+                    )));
+                };
+                let target_entry = unit.entry(target)?;
+                if target_entry.tag() == crate::gimli::DW_TAG_subroutine_type {
+                    Self::Function(Function::from_dw_tag_pointer_type(
+                        dwarf,
+                        unit,
+                        entry,
+                        target_entry,
+                    )?)
+                } else if let Some(name) = name {
                     let name_as_slice = name.to_slice()?;
                     if name_as_slice.starts_with(b"*mut ") {
                         Self::MutPtr(Pointer::new(
@@ -171,7 +280,7 @@ where
                             Some(name),
                             target,
                         ))
-                    } else if name_as_slice.starts_with(b"&") || name_as_slice.starts_with(b"fn") {
+                    } else if name_as_slice.starts_with(b"&") {
                         Self::SharedRef(Pointer::new(
                             dwarf,
                             unit,
@@ -198,9 +307,23 @@ where
             crate::gimli::DW_TAG_subroutine_type => {
                 Self::Function(Function::from_dw_tag_subroutine_type(dwarf, unit, entry)?)
             }
+            crate::gimli::DW_TAG_array_type if vector::is_gnu_vector(&entry)? => {
+                Self::Vector(Vector::from_dw_tag_array_type(dwarf, unit, entry)?)
+            }
             crate::gimli::DW_TAG_array_type => {
                 Self::Array(Array::from_dw_tag_array_type(dwarf, unit, entry)?)
             }
+            crate::gimli::DW_TAG_typedef => {
+                Self::Typedef(Typedef::from_dw_tag_typedef(dwarf, unit, entry)?)
+            }
+            crate::gimli::DW_TAG_const_type
+            | crate::gimli::DW_TAG_volatile_type
+            | crate::gimli::DW_TAG_restrict_type => {
+                Self::Qualified(Qualified::from_dw_tag_qualifier(dwarf, unit, entry)?)
+            }
+            crate::gimli::DW_TAG_unspecified_type => Self::Unspecified(
+                Unspecified::from_dw_tag_unspecified_type(dwarf, unit, entry)?,
+            ),
             _otherwise => {
                 eprintln!(
                     "UNHANDLED DEBUG ENTRY:\n{:#?}",
@@ -242,13 +365,174 @@ where
             Self::str(v) => v.size(),
             Self::Struct(v) => v.size(),
             Self::Enum(v) => v.size(),
-            Self::Function(_) => Ok(0),
-            Self::SharedRef(_) => Ok(std::mem::size_of::<std::primitive::usize>() as _),
-            Self::UniqueRef(_) => Ok(std::mem::size_of::<std::primitive::usize>() as _),
-            Self::ConstPtr(_) => Ok(std::mem::size_of::<std::primitive::usize>() as _),
-            Self::MutPtr(_) => Ok(std::mem::size_of::<std::primitive::usize>() as _),
+            Self::Generator(v) => v.size(),
+            Self::Function(v) => Ok(v.size()),
+            Self::Bitfield(v) => Ok(v.size()),
+            Self::Typedef(v) => v.size(),
+            Self::Qualified(v) => v.size(),
+            Self::Unspecified(v) => Ok(v.size()?.unwrap_or(0)),
+            Self::UnsizedSlice(v) => v.size(),
+            Self::Vector(v) => v.size(),
+            Self::SharedRef(v) => Ok(v.size()),
+            Self::UniqueRef(v) => Ok(v.size()),
+            Self::ConstPtr(v) => Ok(v.size()),
+            Self::MutPtr(v) => Ok(v.size()),
+        }
+    }
+
+    /// The DWARF sections, unit and entry offset of this type.
+    pub(crate) fn die(
+        &self,
+    ) -> (
+        &'dwarf crate::gimli::Dwarf<R>,
+        &crate::gimli::Unit<R>,
+        crate::gimli::UnitOffset,
+    ) {
+        match self {
+            Self::bool(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::char(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::f32(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::f64(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::i8(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::i16(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::i32(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::i64(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::i128(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::isize(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::u8(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::u16(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::u32(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::u64(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::u128(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::usize(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::unit(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::Box(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::BoxedSlice(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::BoxedDyn(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::Array(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::Slice(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::str(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::Struct(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::Enum(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::Generator(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::Function(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::Bitfield(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::Typedef(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::Qualified(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::Unspecified(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::UnsizedSlice(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::Vector(v) => (v.dwarf(), v.unit(), v.entry().offset()),
+            Self::SharedRef(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::UniqueRef(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::ConstPtr(v) => (v.dwarf(), v.unit(), v.offset()),
+            Self::MutPtr(v) => (v.dwarf(), v.unit(), v.offset()),
+        }
+    }
+
+    /// The fully-qualified path of this type, if it has a name; e.g.,
+    /// `alloc::string::String`.
+    pub fn path(&self) -> Result<Option<String>, crate::Error> {
+        match self {
+            Self::Struct(schema) => return schema.path().map(|path| Some(path.to_owned())),
+            Self::Generator(schema) => return schema.path().map(Some),
+            _ => {}
+        }
+        let (dwarf, unit, offset) = self.die();
+        let entry = unit.entry(offset)?;
+        match entry.tag() {
+            crate::gimli::DW_TAG_structure_type
+            | crate::gimli::DW_TAG_class_type
+            | crate::gimli::DW_TAG_union_type
+            | crate::gimli::DW_TAG_enumeration_type => {
+                crate::get_qualified_name(dwarf, unit, offset).map(Some)
+            }
+            _ => Name::from_die_opt(dwarf, unit, &entry)?
+                .map(|name| Ok(name.to_string_lossy()?.into_owned()))
+                .transpose(),
+        }
+    }
+
+    /// The paths of the traits this type has vtables for (i.e., the traits
+    /// it is used as a trait object of); e.g., `core::fmt::Debug`.
+    ///
+    /// Vtables are found by this type's path, so this includes the traits of
+    /// any other types that share it.
+    pub fn implemented_traits<P>(&self, provider: &P) -> Result<Vec<String>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        let Some(path) = self.path()? else {
+            return Ok(vec![]);
+        };
+        let mut traits: Vec<_> = provider
+            .vtables_named(self.die().0, &path)?
+            .into_iter()
+            .map(|(r#trait, _)| r#trait)
+            .collect();
+        traits.sort();
+        traits.dedup();
+        Ok(traits)
+    }
+
+    /// Produces a reflected `Value` of the value of this type at `ptr`.
+    ///
+    /// ## Safety
+    /// `ptr` must point to a valid, initialized value of this type, which must
+    /// live (and not be mutated) for `'value`, and `provider` must be the
+    /// provider of this type's debug info.
+    pub unsafe fn reflect_at<'value, P>(
+        &self,
+        ptr: *const std::ffi::c_void,
+        provider: &'dwarf P,
+    ) -> Result<crate::Value<'value, 'dwarf, P>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        if ptr.is_null() {
+            bail!("cannot reflect a value at a null pointer");
         }
+        let size = self.size()?.try_into()?;
+        let value = std::ptr::slice_from_raw_parts(ptr as *const crate::Byte, size);
+        crate::Value::with_type(self.clone(), &*value, provider)
+    }
+}
+
+/// Produces the layout and referent of the wide pointer (e.g., `&Packet`,
+/// `Box<Packet>` or `*const ArcInner<Mutex<dyn Trait>>`) described by `entry`,
+/// if it points to a dynamically sized struct.
+fn wide_pointer_of<'dwarf, R>(
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, std::primitive::usize>,
+    entry: &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    name: &[std::primitive::u8],
+) -> Result<Option<(pointer::Wide, crate::gimli::UnitOffset)>, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = std::primitive::usize>,
+{
+    if !(name.starts_with(b"&")
+        || name.starts_with(b"*const ")
+        || name.starts_with(b"*mut ")
+        || name.starts_with(b"alloc::boxed::Box<"))
+    {
+        return Ok(None);
     }
+    pointer::wide_pointer(dwarf, unit, entry)
+}
+
+/// Produces an error for a base type with no Rust equivalent; e.g., C's
+/// `long double`.
+fn unsupported_base_type<R>(
+    dwarf: &crate::gimli::Dwarf<R>,
+    unit: &crate::gimli::Unit<R>,
+    entry: &crate::gimli::DebuggingInformationEntry<'_, '_, R>,
+) -> crate::Error
+where
+    R: crate::gimli::Reader<Offset = std::primitive::usize>,
+{
+    anyhow!(
+        "unhandled primitive: {:#?}",
+        crate::debug::DebugEntry::new(dwarf, unit, entry)
+    )
 }
 
 pub use super::Type;
@@ -292,13 +576,6 @@ macro_rules! generate_primitive {
             ) -> Result<Self, crate::Error> {
                 crate::check_tag(&entry, crate::gimli::DW_TAG_base_type)?;
 
-                let name = Name::from_die(dwarf, unit, &entry)?;
-                let expected = std::any::type_name::<std::primitive::$t>();
-                if name.to_slice()? != expected.as_bytes() {
-                    let actual = name.to_string_lossy()?.to_string();
-                    Err(crate::error::name_mismatch(expected, actual))?;
-                }
-
                 let size: std::primitive::usize = crate::get_size(&entry)?
                     .try_into()?;
                 let expected = core::mem::size_of::<std::primitive::$t>();
@@ -314,6 +591,27 @@ macro_rules! generate_primitive {
             }
 
 
+            /// The [DWARF](crate::gimli::Dwarf) sections that this type's debuginfo
+            /// belongs to.
+            #[allow(dead_code)]
+            pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+                self.dwarf
+            }
+
+            /// The DWARF [unit][crate::gimli::Unit] that this type's debuginfo belongs
+            /// to.
+            #[allow(dead_code)]
+            pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, std::primitive::usize> {
+                self.unit
+            }
+
+            /// The offset of the [debugging information
+            /// entry][crate::gimli::DebuggingInformationEntry] this type abstracts over.
+            #[allow(dead_code)]
+            pub(crate) fn offset(&self) -> crate::gimli::UnitOffset {
+                self.entry
+            }
+
             /// The size of this type.
             pub fn name(&self) -> &'static std::primitive::str {
                 std::any::type_name::<std::primitive::$t>()
@@ -419,6 +717,27 @@ where
         })
     }
 
+    /// The [DWARF](crate::gimli::Dwarf) sections that this type's debuginfo
+    /// belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+        self.dwarf
+    }
+
+    /// The DWARF [unit][crate::gimli::Unit] that this type's debuginfo belongs
+    /// to.
+    #[allow(dead_code)]
+    pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, std::primitive::usize> {
+        self.unit
+    }
+
+    /// The offset of the [debugging information
+    /// entry][crate::gimli::DebuggingInformationEntry] this type abstracts over.
+    #[allow(dead_code)]
+    pub(crate) fn offset(&self) -> crate::gimli::UnitOffset {
+        self.entry
+    }
+
     /// The size of this type.
     pub fn name(&self) -> &'static std::primitive::str {
         std::any::type_name::<()>()
@@ -15,6 +15,7 @@ where
 {
     Udata(u64),
     Expression(crate::gimli::read::Expression<R>),
+    Bits(u64),
 }
 
 impl<'dwarf, R> Offset<'dwarf, R>
@@ -29,14 +30,33 @@ where
     }
 
     /// Construct a new `Offset` from a given `entry`'s
-    /// `DW_AT_data_member_location` attribute.
+    /// `DW_AT_data_member_location` attribute, or, for bitfield members, its
+    /// `DW_AT_data_bit_offset` (or DWARF 2-style `DW_AT_bit_offset`)
+    /// attribute.
     pub(crate) fn from_die<'entry>(
         unit: &'dwarf crate::gimli::Unit<R, usize>,
         entry: &'entry crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
     ) -> Result<Self, crate::Error> {
-        let location = crate::get(entry, crate::gimli::DW_AT_data_member_location)?;
+        if let Some(bits) = crate::get_opt(entry, crate::gimli::DW_AT_data_bit_offset)? {
+            let bits = bits
+                .udata_value()
+                .ok_or_else(|| crate::error::invalid_attr(crate::gimli::DW_AT_data_bit_offset))?;
+            let inner = OffsetInner::Bits(bits);
+            return Ok(Self { unit, inner });
+        }
+        let location = crate::get_opt(entry, crate::gimli::DW_AT_data_member_location)?;
+        // members that begin at the start of their containing entity (e.g.,
+        // the members of C unions) may omit their location
+        let location = location.unwrap_or(crate::gimli::AttributeValue::Udata(0));
         let inner = if let Some(offset) = location.udata_value() {
-            OffsetInner::Udata(offset)
+            if let Some(bits) = legacy_bit_offset(unit, entry)? {
+                let bits = (offset * 8)
+                    .checked_add_signed(bits)
+                    .ok_or_else(crate::error::arithmetic_overflow)?;
+                OffsetInner::Bits(bits)
+            } else {
+                OffsetInner::Udata(offset)
+            }
         } else if let Some(expression) = location.exprloc_value() {
             OffsetInner::Expression(expression)
         } else {
@@ -48,9 +68,23 @@ where
     }
 
     /// Produces the actual memory address referred to by this offset.
+    ///
+    /// For offsets that are not byte-aligned, this is the address of the byte
+    /// containing the first bit; see [`bit_address`][Self::bit_address].
     pub fn address(self, start: u64) -> Result<u64, crate::Error> {
-        match self.inner {
-            OffsetInner::Udata(offset) => Ok(start + offset),
+        Ok(self.bit_address(start)? / 8)
+    }
+
+    /// Produces the actual memory address referred to by this offset, in bits
+    /// (i.e., the byte address times eight, plus the bit offset within that
+    /// byte).
+    pub fn bit_address(self, start: u64) -> Result<u64, crate::Error> {
+        let start_bits = start
+            .checked_mul(8)
+            .ok_or_else(crate::error::arithmetic_overflow)?;
+        let bits = match self.inner {
+            OffsetInner::Udata(offset) => start_bits.checked_add(offset * 8),
+            OffsetInner::Bits(bits) => start_bits.checked_add(bits),
             OffsetInner::Expression(expression) => {
                 let mut eval = expression.evaluation(self.unit.encoding());
                 eval.set_initial_value(start);
@@ -58,18 +92,77 @@ where
                     let result = eval.result();
                     match result[..] {
                         [crate::gimli::Piece {
-                            size_in_bits: None,
-                            bit_offset: None,
+                            size_in_bits: _,
+                            bit_offset,
                             location: crate::gimli::Location::Address { address },
-                        }] => Ok(address),
-                        _ => unimplemented!("unsupported evaluation result {:?}", result,),
+                        }] => address
+                            .checked_mul(8)
+                            .and_then(|bits| bits.checked_add(bit_offset.unwrap_or(0))),
+                        _ => bail!("unsupported evaluation result {:?}", result),
                     }
                 } else {
-                    unimplemented!("unsupported evaluation result");
+                    // e.g., the offsets of C++ virtual base classes, which
+                    // must be read from the vtable
+                    bail!("offset cannot be evaluated without reading memory");
                 }
             }
-        }
+        };
+        bits.ok_or_else(crate::error::arithmetic_overflow)
+    }
+}
+
+/// The offset, in bits, of a DWARF 2-style bitfield member (i.e., one with
+/// `DW_AT_bit_offset`) from the start of its `DW_AT_data_member_location`.
+fn legacy_bit_offset<'dwarf, R>(
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+) -> Result<Option<i64>, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let Some(bit_offset) = crate::get_opt(entry, crate::gimli::DW_AT_bit_offset)? else {
+        return Ok(None);
+    };
+    let bit_offset = bit_offset
+        .udata_value()
+        .ok_or_else(|| crate::error::invalid_attr(crate::gimli::DW_AT_bit_offset))?;
+    let bit_size = crate::get(entry, crate::gimli::DW_AT_bit_size)?
+        .udata_value()
+        .ok_or_else(|| crate::error::invalid_attr(crate::gimli::DW_AT_bit_size))?;
+    let byte_size = match crate::get_size_opt(entry)? {
+        Some(byte_size) => byte_size,
+        // the storage unit is otherwise that of the member's type
+        None => storage_size(unit, entry)?,
+    };
+    // `DW_AT_bit_offset` counts from the most significant bit of the storage
+    // unit of size `DW_AT_byte_size`.
+    let bits = if cfg!(target_endian = "little") {
+        (byte_size * 8) as i64 - bit_offset as i64 - bit_size as i64
+    } else {
+        bit_offset as i64
+    };
+    Ok(Some(bits))
+}
+
+/// The size of the type of `entry`, looking through typedefs and qualifiers
+/// (e.g., `const uint32_t`).
+fn storage_size<'dwarf, R>(
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+) -> Result<u64, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let mut entry = crate::get_type_res(unit, entry)?;
+    while matches!(
+        entry.tag(),
+        crate::gimli::DW_TAG_typedef
+            | crate::gimli::DW_TAG_const_type
+            | crate::gimli::DW_TAG_volatile_type
+    ) {
+        entry = crate::get_type_res(unit, &entry)?;
     }
+    crate::get_size(&entry)
 }
 
 impl<'dwarf, R> Clone for Offset<'dwarf, R>
@@ -92,6 +185,7 @@ where
         match self {
             Self::Udata(offset) => Self::Udata(*offset),
             Self::Expression(expr) => Self::Expression(expr.clone()),
+            Self::Bits(bits) => Self::Bits(*bits),
         }
     }
 }
@@ -39,7 +39,8 @@ where
     unit: &'dwarf crate::gimli::Unit<R, usize>,
     entry: UnitOffset,
     name: Option<super::Name<R>>,
-    target: UnitOffset,
+    target: Option<UnitOffset>,
+    wide: Option<Wide>,
     kind: PhantomData<K>,
 }
 
@@ -60,11 +61,70 @@ where
             unit,
             entry,
             name,
-            target,
+            target: Some(target),
+            wide: None,
             kind: PhantomData,
         }
     }
 
+    /// Construct a new wide pointer to a dynamically sized struct from the
+    /// [`DW_TAG_structure_type`][crate::gimli::DW_TAG_structure_type] at
+    /// `entry`.
+    pub(super) fn new_wide(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: UnitOffset,
+        name: Option<super::Name<R>>,
+        target: UnitOffset,
+        wide: Wide,
+    ) -> Self {
+        Self {
+            wide: Some(wide),
+            ..Self::new(dwarf, unit, entry, name, target)
+        }
+    }
+
+    /// Construct a new pointer to `void`; i.e., a
+    /// [`DW_TAG_pointer_type`][crate::gimli::DW_TAG_pointer_type] without a
+    /// [`DW_AT_type`][crate::gimli::DW_AT_type].
+    pub(super) fn void(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: UnitOffset,
+        name: Option<super::Name<R>>,
+    ) -> Self {
+        Self {
+            dwarf,
+            unit,
+            entry,
+            name,
+            target: None,
+            wide: None,
+            kind: PhantomData,
+        }
+    }
+
+    /// The [DWARF](crate::gimli::Dwarf) sections that this type's debuginfo
+    /// belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+        self.dwarf
+    }
+
+    /// The DWARF [unit][crate::gimli::Unit] that this type's debuginfo belongs
+    /// to.
+    #[allow(dead_code)]
+    pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, usize> {
+        self.unit
+    }
+
+    /// The offset of the [debugging information
+    /// entry][crate::gimli::DebuggingInformationEntry] this type abstracts over.
+    #[allow(dead_code)]
+    pub(crate) fn offset(&self) -> crate::gimli::UnitOffset {
+        self.entry
+    }
+
     /// The name of this reference type.
     pub fn name(&self) -> Option<&super::Name<R>> {
         self.name.as_ref()
@@ -72,12 +132,42 @@ where
 
     /// The size of this type, in bytes.
     pub fn size(&self) -> u64 {
-        core::mem::size_of::<usize>() as _
+        match self.wide {
+            Some(_) => 2 * core::mem::size_of::<usize>() as u64,
+            None => core::mem::size_of::<usize>() as _,
+        }
+    }
+
+    /// Whether this is a wide pointer to a dynamically sized struct; e.g.,
+    /// `&Packet` for `struct Packet { header: u32, body: [u8] }`.
+    pub fn is_wide(&self) -> bool {
+        self.wide.is_some()
+    }
+
+    /// The layout of this wide pointer, if it is one.
+    pub(crate) fn wide(&self) -> Option<Wide> {
+        self.wide
+    }
+
+    /// Whether this is a C++ rvalue reference (i.e., `T&&`), rather than a
+    /// C++ lvalue reference (i.e., `T&`) or a Rust reference.
+    pub fn is_rvalue_reference(&self) -> Result<bool, crate::Error> {
+        let entry = self.unit.entry(self.entry)?;
+        Ok(entry.tag() == crate::gimli::DW_TAG_rvalue_reference_type)
+    }
+
+    /// Whether this is a pointer to `void` (i.e., to a referent of unknown
+    /// type).
+    pub fn is_void(&self) -> bool {
+        self.target.is_none()
     }
 
     /// The type of the referent.
     pub fn r#type(&self) -> Result<super::Type<'dwarf, R>, crate::Error> {
-        let entry = self.unit.entry(self.target)?;
+        let target = self
+            .target
+            .ok_or_else(|| crate::error::missing_attr(crate::gimli::DW_AT_type))?;
+        let entry = self.unit.entry(target)?;
         super::Type::from_die(self.dwarf, self.unit, entry)
     }
 }
@@ -103,10 +193,81 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(name) = self.name() {
             name.fmt(f)
+        } else if self.is_void() {
+            f.write_str("*? void")
         } else {
-            f.write_str("*? ")?;
+            let entry = self.unit.entry(self.entry).map_err(crate::fmt_err)?;
+            f.write_str(match entry.tag() {
+                crate::gimli::DW_TAG_reference_type => "&",
+                crate::gimli::DW_TAG_rvalue_reference_type => "&&",
+                _ => "*? ",
+            })?;
             let target = self.r#type().map_err(crate::fmt_err)?;
             target.fmt(f)
         }
     }
 }
+
+/// The layout of a wide pointer to a dynamically sized struct.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Wide {
+    /// The offset of the data pointer within the wide pointer.
+    pub(crate) pointer: u64,
+    /// The offset of the metadata within the wide pointer.
+    pub(crate) metadata: u64,
+    /// Whether the metadata is a vtable address, rather than a length.
+    pub(crate) is_dyn: bool,
+}
+
+/// Produces the layout and referent of the wide pointer described by the
+/// [`DW_TAG_structure_type`][crate::gimli::DW_TAG_structure_type] `entry`, if
+/// it points to a dynamically sized struct.
+///
+/// rustc describes these pointers as a struct of a `data_ptr` and a `length`,
+/// or of a `pointer` and a `vtable`.
+pub(crate) fn wide_pointer<'dwarf, R>(
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+) -> Result<Option<(Wide, UnitOffset)>, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let schema = super::Struct::from_dw_tag_structure_type(dwarf, unit, entry.clone())?;
+    let mut fields = schema.fields()?;
+    let mut fields = fields.iter()?;
+    let (Some(pointer), Some(metadata), None) =
+        (fields.try_next()?, fields.try_next()?, fields.try_next()?)
+    else {
+        return Ok(None);
+    };
+    let is_dyn = match (
+        &*pointer.name()?.to_slice()?,
+        &*metadata.name()?.to_slice()?,
+    ) {
+        (b"data_ptr", b"length") => false,
+        (b"pointer", b"vtable") => true,
+        _ => return Ok(None),
+    };
+    let pointer_type = crate::get_type_res(unit, pointer.entry())?;
+    if pointer_type.tag() != crate::gimli::DW_TAG_pointer_type {
+        return Ok(None);
+    }
+    let Some(target) = crate::get_attr_ref(&pointer_type, crate::gimli::DW_AT_type)? else {
+        return Ok(None);
+    };
+    let target_entry = unit.entry(target)?;
+    if target_entry.tag() != crate::gimli::DW_TAG_structure_type {
+        return Ok(None);
+    }
+    let target_schema = super::Struct::from_dw_tag_structure_type(dwarf, unit, target_entry)?;
+    if target_schema.is_dyn()? || target_schema.unsized_tail()?.is_none() {
+        return Ok(None);
+    }
+    let wide = Wide {
+        pointer: super::Offset::from_die(unit, pointer.entry())?.address(0)?,
+        metadata: super::Offset::from_die(unit, metadata.entry())?.address(0)?,
+        is_dyn,
+    };
+    Ok(Some((wide, target)))
+}
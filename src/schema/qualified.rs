@@ -0,0 +1,154 @@
+use super::Type;
+use std::fmt;
+
+/// A type qualifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Qualifier {
+    /// `const`; i.e., [`DW_TAG_const_type`][crate::gimli::DW_TAG_const_type].
+    Const,
+    /// `volatile`; i.e.,
+    /// [`DW_TAG_volatile_type`][crate::gimli::DW_TAG_volatile_type].
+    Volatile,
+    /// `restrict`; i.e.,
+    /// [`DW_TAG_restrict_type`][crate::gimli::DW_TAG_restrict_type].
+    Restrict,
+}
+
+impl Qualifier {
+    fn from_tag(tag: crate::gimli::DwTag) -> Option<Self> {
+        match tag {
+            crate::gimli::DW_TAG_const_type => Some(Self::Const),
+            crate::gimli::DW_TAG_volatile_type => Some(Self::Volatile),
+            crate::gimli::DW_TAG_restrict_type => Some(Self::Restrict),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Qualifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Const => "const",
+            Self::Volatile => "volatile",
+            Self::Restrict => "restrict",
+        })
+    }
+}
+
+/// A schema for a qualified type; e.g., `const T`.
+///
+/// Qualified types delegate their layout to their [underlying
+/// type][Self::r#type].
+#[derive(Clone)]
+pub struct Qualified<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    qualifier: Qualifier,
+}
+
+impl<'dwarf, R> Qualified<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    /// Construct a new `Qualified` from a
+    /// [`DW_TAG_const_type`][crate::gimli::DW_TAG_const_type],
+    /// [`DW_TAG_volatile_type`][crate::gimli::DW_TAG_volatile_type], or
+    /// [`DW_TAG_restrict_type`][crate::gimli::DW_TAG_restrict_type].
+    pub(crate) fn from_dw_tag_qualifier(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        let Some(qualifier) = Qualifier::from_tag(entry.tag()) else {
+            return Err(crate::error::tag_mismatch(
+                crate::gimli::DW_TAG_const_type,
+                entry.tag(),
+            ));
+        };
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            qualifier,
+        })
+    }
+
+    /// The [DWARF](crate::gimli::Dwarf) sections that this `Qualified`'s
+    /// debuginfo belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+        self.dwarf
+    }
+
+    /// The DWARF [unit][crate::gimli::Unit] that this `Qualified`'s debuginfo
+    /// belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, usize> {
+        self.unit
+    }
+
+    /// The [debugging information
+    /// entry][crate::gimli::DebuggingInformationEntry] this `Qualified`
+    /// abstracts over.
+    #[allow(dead_code)]
+    pub(crate) fn entry(&self) -> &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R> {
+        &self.entry
+    }
+
+    /// The qualifier applied to the underlying type.
+    pub fn qualifier(&self) -> Qualifier {
+        self.qualifier
+    }
+
+    /// The underlying type, or `None` if it is `void`.
+    pub fn r#type(&self) -> Result<Option<Type<'dwarf, R>>, crate::Error> {
+        let Some(offset) = crate::get_attr_ref(&self.entry, crate::gimli::DW_AT_type)? else {
+            return Ok(None);
+        };
+        let entry = self.unit.entry(offset)?;
+        Type::from_die(self.dwarf, self.unit, entry).map(Some)
+    }
+
+    /// The size of this type, in bytes.
+    pub fn size(&self) -> Result<u64, crate::Error> {
+        let r#type = self.r#type()?;
+        r#type
+            .ok_or_else(|| crate::error::missing_attr(crate::gimli::DW_AT_type))?
+            .size()
+    }
+}
+
+impl<'dwarf, R> fmt::Debug for Qualified<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple("deflect::schema::Qualified");
+        debug_tuple.field(&crate::debug::DebugEntry::new(
+            self.dwarf(),
+            self.unit(),
+            self.entry(),
+        ));
+        debug_tuple.finish()
+    }
+}
+
+impl<'dwarf, R> fmt::Display for Qualified<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.r#type().map_err(crate::fmt_err)? {
+            // `restrict` qualifies pointers, and so is written after them
+            Some(r#type) if self.qualifier == Qualifier::Restrict => {
+                write!(f, "{type} {}", self.qualifier)
+            }
+            Some(r#type) => write!(f, "{} {type}", self.qualifier),
+            None => write!(f, "{} void", self.qualifier),
+        }
+    }
+}
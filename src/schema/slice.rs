@@ -86,7 +86,7 @@ where
     /// The element type of this slice.
     pub fn elt(&self) -> Result<super::Type<'dwarf, R>, crate::Error> {
         if let super::Type::MutPtr(r#ref) = self.data_ptr().r#type()? {
-            return r#ref.r#type();
+            r#ref.r#type()
         } else {
             unreachable!()
         }
@@ -11,6 +11,8 @@ where
     dwarf: &'dwarf crate::gimli::Dwarf<R>,
     unit: &'dwarf crate::gimli::Unit<R, usize>,
     entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    /// The fully-qualified path of this type, once computed.
+    path: once_cell::unsync::OnceCell<String>,
 }
 
 impl<'dwarf, R> Struct<'dwarf, R>
@@ -25,7 +27,28 @@ where
         entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
     ) -> Result<Self, crate::Error> {
         crate::check_tag(&entry, crate::gimli::DW_TAG_structure_type)?;
-        Ok(Self { dwarf, unit, entry })
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            path: Default::default(),
+        })
+    }
+
+    /// Construct a new `Struct` from a (C++)
+    /// [`DW_TAG_class_type`][crate::gimli::DW_TAG_class_type].
+    pub(crate) fn from_dw_tag_class_type(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        crate::check_tag(&entry, crate::gimli::DW_TAG_class_type)?;
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            path: Default::default(),
+        })
     }
 
     /// The [DWARF](crate::gimli::Dwarf) sections that this `Struct`'s debuginfo
@@ -55,7 +78,36 @@ where
         Name::from_die(self.dwarf(), self.unit(), self.entry())
     }
 
-    /// The size of this field, in bytes.
+    /// The name of this type, or `None` if it is anonymous (as C structs may
+    /// be).
+    pub(crate) fn name_opt(&self) -> Result<Option<Name<R>>, crate::Error> {
+        Name::from_die_opt(self.dwarf(), self.unit(), self.entry())
+    }
+
+    /// The fully-qualified path of this type; e.g., `alloc::string::String`.
+    pub(crate) fn path(&self) -> Result<&str, crate::Error> {
+        // computing the path requires a traversal of the whole unit
+        self.path
+            .get_or_try_init(|| {
+                crate::get_qualified_name(self.dwarf(), self.unit(), self.entry().offset())
+            })
+            .map(String::as_str)
+    }
+
+    /// The name of this type for display; anonymous (C) structs are displayed
+    /// as `struct`.
+    pub(crate) fn display_name(&self) -> Result<String, crate::Error> {
+        match self.name_opt()? {
+            Some(name) => Ok(name.to_string_lossy()?.into_owned()),
+            None => Ok("struct".to_owned()),
+        }
+    }
+
+    /// The size of this struct, in bytes.
+    ///
+    /// For [unsized][Self::is_unsized] structs, this is the size of their
+    /// sized prefix; their full size depends on the metadata of the pointer
+    /// through which they are reached.
     pub fn size(&self) -> Result<u64, crate::Error> {
         crate::get_size(self.entry())
     }
@@ -66,10 +118,104 @@ where
     }
 
     /// The fields of this struct.
+    ///
+    /// For C++ classes, these include the base-class subobjects (see
+    /// [`Field::is_base`][super::Field::is_base]) and vtable pointers, but not
+    /// static members.
     pub fn fields(&self) -> Result<super::Fields<'dwarf, R>, crate::Error> {
         let tree = self.unit.entries_tree(Some(self.entry.offset()))?;
         Ok(super::Fields::from_tree(self.dwarf, self.unit, tree))
     }
+
+    /// The generic arguments of this struct; e.g., `T = u32` and
+    /// `A = alloc::alloc::Global` for `Vec<u32>`.
+    pub fn generics(&self) -> Result<Vec<super::Generic<'dwarf, R>>, crate::Error> {
+        super::generics::generics_of(self.dwarf, self.unit, &self.entry)
+    }
+
+    /// Whether this is a `dyn Trait` object type.
+    pub fn is_dyn(&self) -> Result<bool, crate::Error> {
+        let Some(name) = Name::from_die_opt(self.dwarf(), self.unit(), self.entry())? else {
+            return Ok(false);
+        };
+        Ok(name.to_slice()?.starts_with(b"dyn "))
+    }
+
+    /// Whether this struct is dynamically sized; i.e., is a `dyn Trait` or
+    /// has an [unsized tail][Self::unsized_tail].
+    pub fn is_unsized(&self) -> Result<bool, crate::Error> {
+        Ok(self.is_dyn()? || self.unsized_tail()?.is_some())
+    }
+
+    /// The unsized last field of this struct, if any; e.g., the `body` of
+    /// `struct Packet { header: u32, body: [u8] }`, or the `data` of
+    /// `ArcInner<Mutex<dyn Trait>>`.
+    pub fn unsized_tail(&self) -> Result<Option<super::Field<'dwarf, R>>, crate::Error> {
+        let mut last = None;
+        let mut fields = self.fields()?;
+        let mut fields = fields.iter()?;
+        while let Some(field) = fields.try_next()? {
+            last = Some(field);
+        }
+        match last {
+            Some(field) if field.is_unsized()? => Ok(Some(field)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Whether this is a C++ `class`.
+    pub fn is_class(&self) -> bool {
+        self.entry.tag() == crate::gimli::DW_TAG_class_type
+    }
+
+    /// The base-class subobjects of this C++ class or struct, in declaration
+    /// order.
+    pub fn bases(&self) -> Result<Vec<super::Field<'dwarf, R>>, crate::Error> {
+        let mut bases = vec![];
+        let mut fields = self.fields()?;
+        let mut fields = fields.iter()?;
+        while let Some(field) = fields.try_next()? {
+            if field.is_base() {
+                bases.push(field);
+            }
+        }
+        Ok(bases)
+    }
+
+    /// The vtable pointer (i.e., `_vptr`) of this C++ class or struct, if it
+    /// has one of its own.
+    pub fn vtable_pointer(&self) -> Result<Option<super::Field<'dwarf, R>>, crate::Error> {
+        let mut fields = self.fields()?;
+        let mut fields = fields.iter()?;
+        while let Some(field) = fields.try_next()? {
+            if field.is_vtable_pointer()? {
+                return Ok(Some(field));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The static members of this C++ class or struct.
+    ///
+    /// Static members are not part of the layout of their class, and so have
+    /// no [offset][super::Field::offset]; their storage is instead at their
+    /// [static address][super::Field::static_address].
+    pub fn static_fields(&self) -> Result<Vec<super::Field<'dwarf, R>>, crate::Error> {
+        let mut statics = vec![];
+        let mut tree = self.unit.entries_tree(Some(self.entry.offset()))?;
+        let mut children = tree.root()?.children();
+        while let Some(child) = children.next()? {
+            let entry = child.entry();
+            if super::field::is_static(entry)? {
+                statics.push(super::Field::from_dw_tag_static(
+                    self.dwarf,
+                    self.unit,
+                    entry.clone(),
+                )?);
+            }
+        }
+        Ok(statics)
+    }
 }
 
 impl<'dwarf, R> fmt::Debug for Struct<'dwarf, R>
@@ -92,8 +238,7 @@ where
     R: crate::gimli::Reader<Offset = usize>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let type_name = self.name().map_err(crate::fmt_err)?;
-        let type_name = type_name.to_string_lossy().map_err(crate::fmt_err)?;
+        let type_name = self.display_name().map_err(crate::fmt_err)?;
         let mut debug_struct = f.debug_struct(&type_name);
         let mut fields = self.fields().map_err(crate::fmt_err)?;
         let mut fields = fields.iter().map_err(crate::fmt_err)?;
@@ -0,0 +1,95 @@
+use super::{Name, Type};
+use std::fmt;
+
+/// A schema for a type alias; i.e., a
+/// [`DW_TAG_typedef`][crate::gimli::DW_TAG_typedef].
+///
+/// Typedefs keep their own name for display, but delegate their layout to
+/// their [underlying type][Self::r#type].
+#[derive(Clone)]
+pub struct Typedef<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+}
+
+impl<'dwarf, R> Typedef<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    /// Construct a new `Typedef` from a
+    /// [`DW_TAG_typedef`][crate::gimli::DW_TAG_typedef].
+    pub(crate) fn from_dw_tag_typedef(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        crate::check_tag(&entry, crate::gimli::DW_TAG_typedef)?;
+        Ok(Self { dwarf, unit, entry })
+    }
+
+    /// The [DWARF](crate::gimli::Dwarf) sections that this `Typedef`'s
+    /// debuginfo belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+        self.dwarf
+    }
+
+    /// The DWARF [unit][crate::gimli::Unit] that this `Typedef`'s debuginfo
+    /// belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, usize> {
+        self.unit
+    }
+
+    /// The [debugging information
+    /// entry][crate::gimli::DebuggingInformationEntry] this `Typedef`
+    /// abstracts over.
+    #[allow(dead_code)]
+    pub(crate) fn entry(&self) -> &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R> {
+        &self.entry
+    }
+
+    /// The name of this typedef; e.g., `size_t`.
+    pub fn name(&self) -> Result<Name<R>, crate::Error> {
+        Name::from_die(self.dwarf(), self.unit(), self.entry())
+    }
+
+    /// The type this typedef aliases.
+    pub fn r#type(&self) -> Result<Type<'dwarf, R>, crate::Error> {
+        let r#type = crate::get_type_res(self.unit, &self.entry)?;
+        Type::from_die(self.dwarf, self.unit, r#type)
+    }
+
+    /// The size of this type, in bytes.
+    pub fn size(&self) -> Result<u64, crate::Error> {
+        self.r#type()?.size()
+    }
+}
+
+impl<'dwarf, R> fmt::Debug for Typedef<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple("deflect::schema::Typedef");
+        debug_tuple.field(&crate::debug::DebugEntry::new(
+            self.dwarf(),
+            self.unit(),
+            self.entry(),
+        ));
+        debug_tuple.finish()
+    }
+}
+
+impl<'dwarf, R> fmt::Display for Typedef<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().map_err(crate::fmt_err)?.fmt(f)
+    }
+}
@@ -0,0 +1,93 @@
+use super::Type;
+use std::fmt;
+
+/// A schema for an unsized, in-place slice, `[T]`; e.g., the `body` of
+/// `struct Packet { header: u32, body: [u8] }`.
+///
+/// rustc describes such a field as a
+/// [`DW_TAG_member`][crate::gimli::DW_TAG_member] of the element type, `T`,
+/// that begins at or past the end of its (sized) struct. Its length is only
+/// known from the metadata of a wide pointer to that struct.
+#[derive(Clone)]
+pub struct UnsizedSlice<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+}
+
+impl<'dwarf, R> UnsizedSlice<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    /// Construct a new `UnsizedSlice` from the
+    /// [`DW_TAG_member`][crate::gimli::DW_TAG_member] of an unsized tail.
+    pub(crate) fn from_dw_tag_member(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        crate::check_tag(&entry, crate::gimli::DW_TAG_member)?;
+        Ok(Self { dwarf, unit, entry })
+    }
+
+    /// The [DWARF](crate::gimli::Dwarf) sections that this `UnsizedSlice`'s
+    /// debuginfo belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+        self.dwarf
+    }
+
+    /// The DWARF [unit][crate::gimli::Unit] that this `UnsizedSlice`'s
+    /// debuginfo belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, usize> {
+        self.unit
+    }
+
+    /// The [debugging information
+    /// entry][crate::gimli::DebuggingInformationEntry] this `UnsizedSlice`
+    /// abstracts over.
+    #[allow(dead_code)]
+    pub(crate) fn entry(&self) -> &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R> {
+        &self.entry
+    }
+
+    /// The element type, `T`, of this `[T]`.
+    pub fn elt_type(&self) -> Result<Type<'dwarf, R>, crate::Error> {
+        let r#type = crate::get_type_res(self.unit, &self.entry)?;
+        Type::from_die(self.dwarf, self.unit, r#type)
+    }
+
+    /// The size of this slice, which is not statically known.
+    pub fn size(&self) -> Result<u64, crate::Error> {
+        bail!("`[{}]` is unsized", self.elt_type()?)
+    }
+}
+
+impl<'dwarf, R> fmt::Debug for UnsizedSlice<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple("deflect::schema::UnsizedSlice");
+        debug_tuple.field(&crate::debug::DebugEntry::new(
+            self.dwarf(),
+            self.unit(),
+            self.entry(),
+        ));
+        debug_tuple.finish()
+    }
+}
+
+impl<'dwarf, R> fmt::Display for UnsizedSlice<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elt_type = self.elt_type().map_err(crate::fmt_err)?;
+        write!(f, "[{elt_type}]")
+    }
+}
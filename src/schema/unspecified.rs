@@ -0,0 +1,90 @@
+use super::Name;
+use std::fmt;
+
+/// A schema for a type whose layout is not described by the debuginfo; i.e.,
+/// a [`DW_TAG_unspecified_type`][crate::gimli::DW_TAG_unspecified_type] (e.g.,
+/// C++'s `decltype(nullptr)`).
+#[derive(Clone)]
+pub struct Unspecified<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+}
+
+impl<'dwarf, R> Unspecified<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    /// Construct a new `Unspecified` from a
+    /// [`DW_TAG_unspecified_type`][crate::gimli::DW_TAG_unspecified_type].
+    pub(crate) fn from_dw_tag_unspecified_type(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        crate::check_tag(&entry, crate::gimli::DW_TAG_unspecified_type)?;
+        Ok(Self { dwarf, unit, entry })
+    }
+
+    /// The [DWARF](crate::gimli::Dwarf) sections that this `Unspecified`'s
+    /// debuginfo belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+        self.dwarf
+    }
+
+    /// The DWARF [unit][crate::gimli::Unit] that this `Unspecified`'s
+    /// debuginfo belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, usize> {
+        self.unit
+    }
+
+    /// The [debugging information
+    /// entry][crate::gimli::DebuggingInformationEntry] this `Unspecified`
+    /// abstracts over.
+    #[allow(dead_code)]
+    pub(crate) fn entry(&self) -> &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R> {
+        &self.entry
+    }
+
+    /// The name of this type, if any.
+    pub fn name(&self) -> Result<Option<Name<R>>, crate::Error> {
+        Name::from_die_opt(self.dwarf(), self.unit(), self.entry())
+    }
+
+    /// The size of this type, in bytes, if recorded.
+    pub fn size(&self) -> Result<Option<u64>, crate::Error> {
+        crate::get_size_opt(self.entry())
+    }
+}
+
+impl<'dwarf, R> fmt::Debug for Unspecified<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple("deflect::schema::Unspecified");
+        debug_tuple.field(&crate::debug::DebugEntry::new(
+            self.dwarf(),
+            self.unit(),
+            self.entry(),
+        ));
+        debug_tuple.finish()
+    }
+}
+
+impl<'dwarf, R> fmt::Display for Unspecified<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name().map_err(crate::fmt_err)? {
+            Some(name) => name.fmt(f),
+            None => f.write_str("<unspecified>"),
+        }
+    }
+}
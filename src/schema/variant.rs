@@ -10,6 +10,7 @@ where
     dwarf: &'dwarf crate::gimli::Dwarf<R>,
     unit: &'dwarf crate::gimli::Unit<R, usize>,
     entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    member: Option<crate::gimli::UnitOffset>,
     discriminant_val: Option<super::Data>,
 }
 
@@ -21,12 +22,14 @@ where
         dwarf: &'dwarf crate::gimli::Dwarf<R>,
         unit: &'dwarf crate::gimli::Unit<R, usize>,
         entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+        member: Option<crate::gimli::UnitOffset>,
         discriminant_val: Option<super::Data>,
     ) -> Self {
         Self {
             dwarf,
             unit,
             entry,
+            member,
             discriminant_val,
         }
     }
@@ -69,8 +72,33 @@ where
     }
 
     /// The file the variant was defined in (if available).
+    ///
+    /// For the states of generators, this is the file of the corresponding
+    /// yield point.
     pub fn file(&self) -> Result<Option<Cow<'_, str>>, crate::Error> {
-        crate::get_file(self.dwarf, self.unit, &self.entry)
+        if let Some(file) = crate::get_file(self.dwarf, self.unit, &self.entry)? {
+            return Ok(Some(file));
+        }
+        if let Some(member) = self.member {
+            let member = self.unit.entry(member)?;
+            return crate::get_file(self.dwarf, self.unit, &member);
+        }
+        Ok(None)
+    }
+
+    /// The line the variant was defined on (if available).
+    ///
+    /// For the states of generators, this is the line of the corresponding
+    /// yield point.
+    pub fn line(&self) -> Result<Option<u64>, crate::Error> {
+        if let Some(line) = crate::get_line(&self.entry)? {
+            return Ok(Some(line));
+        }
+        if let Some(member) = self.member {
+            let member = self.unit.entry(member)?;
+            return crate::get_line(&member);
+        }
+        Ok(None)
     }
 
     /// The discriminant value (if any).
@@ -58,7 +58,9 @@ where
     /// Produces the next variant, if any.
     pub fn try_next(&mut self) -> Result<Option<super::Variant<'dwarf, R>>, crate::Error> {
         loop {
-            let Some(next) = self.iter.next()? else { return Ok(None) };
+            let Some(next) = self.iter.next()? else {
+                return Ok(None);
+            };
             let entry = next.entry();
             match entry.tag() {
                 crate::gimli::DW_TAG_variant => {
@@ -73,27 +75,34 @@ where
                     let entry = entry.next()?;
                     let entry = entry
                         .ok_or_else(|| crate::error::missing_child(crate::gimli::DW_TAG_member))?;
+                    let member = entry.entry().offset();
                     let entry = crate::get_type(entry.entry())?;
                     let entry = self.unit.entry(entry)?;
                     return Ok(Some(super::Variant::new(
                         self.dwarf,
                         self.unit,
                         entry,
+                        Some(member),
                         discriminant_value,
                     )));
                 }
                 crate::gimli::DW_TAG_enumerator => {
+                    // enumerators of fieldless Rust enums and C enums record
+                    // their discriminant in `DW_AT_const_value`
                     let discriminant_value = entry
                         .attr_value(crate::gimli::DW_AT_discr_value)?
-                        .and_then(|dw_at_discr_value| dw_at_discr_value.udata_value())
-                        .map(|dw_at_discr_value| {
-                            discriminant_value(self.discriminant_type, dw_at_discr_value)
-                        });
+                        .or(entry.attr_value(crate::gimli::DW_AT_const_value)?)
+                        .and_then(|value| match value {
+                            crate::gimli::AttributeValue::Sdata(value) => Some(value as u64),
+                            value => value.udata_value(),
+                        })
+                        .map(|value| discriminant_value(self.discriminant_type, value));
 
                     return Ok(Some(super::Variant::new(
                         self.dwarf,
                         self.unit,
                         entry.clone(),
+                        None,
                         discriminant_value,
                     )));
                 }
@@ -132,6 +141,14 @@ where
         super::Type::u16(_) => super::Data::u16(v as _),
         super::Type::u32(_) => super::Data::u32(v as _),
         super::Type::u64(_) => super::Data::u64(v as _),
+        super::Type::i8(_) => super::Data::u8(v as _),
+        super::Type::i16(_) => super::Data::u16(v as _),
+        super::Type::i32(_) => super::Data::u32(v as _),
+        super::Type::i64(_) => super::Data::u64(v as _),
+        super::Type::isize(_) | super::Type::usize(_) => match std::mem::size_of::<usize>() {
+            4 => super::Data::u32(v as _),
+            _ => super::Data::u64(v as _),
+        },
         _ => unimplemented!(),
     }
 }
@@ -0,0 +1,234 @@
+use super::{Array, Name, Type};
+use std::fmt;
+
+/// A schema for a SIMD vector; e.g., `core::arch::x86_64::__m128` or
+/// `std::simd::Simd<f32, 4>`.
+///
+/// Vectors are described either by a
+/// [`DW_TAG_array_type`][crate::gimli::DW_TAG_array_type] marked with
+/// [`DW_AT_GNU_vector`][crate::gimli::DW_AT_GNU_vector] (e.g., C's
+/// `float __attribute__((vector_size(16)))`), or by a `#[repr(simd)]` struct
+/// of `core`'s SIMD modules, which wraps an array of its lanes and is more
+/// strictly aligned than them.
+#[derive(Clone)]
+pub struct Vector<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    lanes: Array<'dwarf, R>,
+}
+
+impl<'dwarf, R> Vector<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    /// Construct a new `Vector` from a
+    /// [`DW_TAG_array_type`][crate::gimli::DW_TAG_array_type] marked with
+    /// [`DW_AT_GNU_vector`][crate::gimli::DW_AT_GNU_vector].
+    pub(crate) fn from_dw_tag_array_type(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        crate::check_tag(&entry, crate::gimli::DW_TAG_array_type)?;
+        if !is_gnu_vector(&entry)? {
+            return Err(crate::error::missing_attr(crate::gimli::DW_AT_GNU_vector));
+        }
+        let lanes = Array::from_dw_tag_array_type(dwarf, unit, entry.clone())?;
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            lanes,
+        })
+    }
+
+    /// Construct a new `Vector` from the
+    /// [`DW_TAG_structure_type`][crate::gimli::DW_TAG_structure_type] of a
+    /// `#[repr(simd)]` struct.
+    pub(crate) fn from_dw_tag_structure_type(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        crate::check_tag(&entry, crate::gimli::DW_TAG_structure_type)?;
+        let Some(lanes) = simd_lanes(dwarf, unit, &entry)? else {
+            bail!(
+                "`{}` is not a SIMD vector",
+                Name::from_die(dwarf, unit, &entry)?
+            )
+        };
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            lanes,
+        })
+    }
+
+    /// The [DWARF](crate::gimli::Dwarf) sections that this `Vector`'s
+    /// debuginfo belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+        self.dwarf
+    }
+
+    /// The DWARF [unit][crate::gimli::Unit] that this `Vector`'s debuginfo
+    /// belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, usize> {
+        self.unit
+    }
+
+    /// The [debugging information
+    /// entry][crate::gimli::DebuggingInformationEntry] this `Vector`
+    /// abstracts over.
+    #[allow(dead_code)]
+    pub(crate) fn entry(&self) -> &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R> {
+        &self.entry
+    }
+
+    /// The name of this vector type, if it has one; e.g., `__m128`.
+    pub fn name(&self) -> Result<Option<Name<R>>, crate::Error> {
+        Name::from_die_opt(self.dwarf, self.unit, &self.entry)
+    }
+
+    /// The type of the lanes of this vector; e.g., `f32` for `__m128`.
+    pub fn lane_type(&self) -> Result<Type<'dwarf, R>, crate::Error> {
+        self.lanes.elt_type()
+    }
+
+    /// The number of lanes of this vector; e.g., `4` for `__m128`.
+    pub fn lanes(&self) -> Result<u64, crate::Error> {
+        self.lanes.len()
+    }
+
+    /// The lanes of this vector, as an array.
+    pub fn as_array(&self) -> &Array<'dwarf, R> {
+        &self.lanes
+    }
+
+    /// The size of this vector, in bytes.
+    pub fn size(&self) -> Result<u64, crate::Error> {
+        match crate::get_size_opt(&self.entry)? {
+            Some(size) => Ok(size),
+            None => self.lanes.bytes(),
+        }
+    }
+
+    /// The alignment of this vector, in bytes.
+    ///
+    /// Vectors without a recorded alignment are aligned to their size.
+    pub fn align(&self) -> Result<u64, crate::Error> {
+        match crate::get_align(&self.entry)? {
+            Some(align) => Ok(align),
+            None => self.size(),
+        }
+    }
+}
+
+impl<'dwarf, R> fmt::Debug for Vector<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple("deflect::schema::Vector");
+        debug_tuple.field(&crate::debug::DebugEntry::new(
+            self.dwarf(),
+            self.unit(),
+            self.entry(),
+        ));
+        debug_tuple.finish()
+    }
+}
+
+impl<'dwarf, R> fmt::Display for Vector<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.name().map_err(crate::fmt_err)? {
+            return name.fmt(f);
+        }
+        let lane_type = self.lane_type().map_err(crate::fmt_err)?;
+        let lanes = self.lanes().map_err(crate::fmt_err)?;
+        write!(f, "Simd<{lane_type}, {lanes}>")
+    }
+}
+
+/// Whether `entry` is marked with
+/// [`DW_AT_GNU_vector`][crate::gimli::DW_AT_GNU_vector].
+pub(crate) fn is_gnu_vector<R>(
+    entry: &crate::gimli::DebuggingInformationEntry<'_, '_, R>,
+) -> Result<bool, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    Ok(matches!(
+        crate::get_opt(entry, crate::gimli::DW_AT_GNU_vector)?,
+        Some(crate::gimli::AttributeValue::Flag(true))
+    ))
+}
+
+/// The paths of the modules that define `#[repr(simd)]` vectors; e.g.,
+/// `core::core_arch::x86::__m128` and `core::core_simd::vector::Simd`.
+const SIMD_MODULES: &[&str] = &["core::core_arch::", "core::core_simd::", "core::simd::"];
+
+/// Produces the array of lanes of the struct `entry`, if it is a
+/// `#[repr(simd)]` vector.
+///
+/// rustc does not mark these structs as vectors, so only the structs of
+/// `core`'s SIMD modules are considered. They consist of exactly one array of
+/// primitive lanes, and are aligned to their size, which exceeds the
+/// alignment of their lanes.
+pub(crate) fn simd_lanes<'dwarf, R>(
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+) -> Result<Option<Array<'dwarf, R>>, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let (Some(size), Some(align)) = (crate::get_size_opt(entry)?, crate::get_align(entry)?) else {
+        return Ok(None);
+    };
+    if size != align || size < 8 {
+        return Ok(None);
+    }
+    let path = crate::get_qualified_name(dwarf, unit, entry.offset())?;
+    if !SIMD_MODULES.iter().any(|module| path.starts_with(module)) {
+        return Ok(None);
+    }
+    let schema = super::Struct::from_dw_tag_structure_type(dwarf, unit, entry.clone())?;
+    let mut fields = schema.fields()?;
+    let mut fields = fields.iter()?;
+    let (Some(field), None) = (fields.try_next()?, fields.try_next()?) else {
+        return Ok(None);
+    };
+    let Type::Array(lanes) = field.r#type()? else {
+        return Ok(None);
+    };
+    let lane_type = lanes.elt_type()?;
+    let is_primitive = matches!(
+        lane_type,
+        Type::f32(_)
+            | Type::f64(_)
+            | Type::i8(_)
+            | Type::i16(_)
+            | Type::i32(_)
+            | Type::i64(_)
+            | Type::isize(_)
+            | Type::u8(_)
+            | Type::u16(_)
+            | Type::u32(_)
+            | Type::u64(_)
+            | Type::usize(_)
+    );
+    if !is_primitive || lanes.bytes()? != size || lane_type.size()? >= align {
+        return Ok(None);
+    }
+    Ok(Some(lanes))
+}
@@ -0,0 +1,164 @@
+use super::Name;
+use std::fmt;
+
+/// A schema for the vtable of a trait object; i.e., `<T as Trait>::{vtable}`.
+///
+/// Vtables are encoded in DWARF as a
+/// [`DW_TAG_variable`][crate::gimli::DW_TAG_variable] of a
+/// `<T as Trait>::{vtable_type}` struct, whose members are the drop glue, size
+/// and alignment of `T`, followed by a `__methodN` member for each method of
+/// `Trait`.
+#[derive(Clone)]
+pub struct Vtable<'dwarf, R: crate::gimli::Reader<Offset = usize>>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    dwarf: &'dwarf crate::gimli::Dwarf<R>,
+    unit: &'dwarf crate::gimli::Unit<R, usize>,
+    entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    schema: super::Struct<'dwarf, R>,
+}
+
+impl<'dwarf, R> Vtable<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    /// Construct a new `Vtable` from a
+    /// [`DW_TAG_variable`][crate::gimli::DW_TAG_variable].
+    pub(crate) fn from_dw_tag_variable(
+        dwarf: &'dwarf crate::gimli::Dwarf<R>,
+        unit: &'dwarf crate::gimli::Unit<R, usize>,
+        entry: crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R>,
+    ) -> Result<Self, crate::Error> {
+        crate::check_tag(&entry, crate::gimli::DW_TAG_variable)?;
+        let name = Name::from_die(dwarf, unit, &entry)?;
+        if !name.to_slice()?.ends_with(b"::{vtable}") {
+            let actual = name.to_string_lossy()?.to_string();
+            Err(crate::error::name_mismatch(
+                "<T as Trait>::{vtable}",
+                actual,
+            ))?;
+        }
+        let r#type = crate::get_type_res(unit, &entry)?;
+        let schema = super::Struct::from_dw_tag_structure_type(dwarf, unit, r#type)?;
+        Ok(Self {
+            dwarf,
+            unit,
+            entry,
+            schema,
+        })
+    }
+
+    /// The [DWARF](crate::gimli::Dwarf) sections that this `Vtable`'s
+    /// debuginfo belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn dwarf(&self) -> &'dwarf crate::gimli::Dwarf<R> {
+        self.dwarf
+    }
+
+    /// The DWARF [unit][crate::gimli::Unit] that this `Vtable`'s debuginfo
+    /// belongs to.
+    #[allow(dead_code)]
+    pub(crate) fn unit(&self) -> &crate::gimli::Unit<R, usize> {
+        self.unit
+    }
+
+    /// The [debugging information
+    /// entry][crate::gimli::DebuggingInformationEntry] this `Vtable`
+    /// abstracts over.
+    #[allow(dead_code)]
+    pub(crate) fn entry(&self) -> &crate::gimli::DebuggingInformationEntry<'dwarf, 'dwarf, R> {
+        &self.entry
+    }
+
+    /// The name of this vtable; e.g., `<my_crate::Foo as core::fmt::Debug>::{vtable}`.
+    pub fn name(&self) -> Result<Name<R>, crate::Error> {
+        Name::from_die(self.dwarf(), self.unit(), self.entry())
+    }
+
+    /// The path of the trait this vtable implements; e.g., `core::fmt::Debug`.
+    pub fn trait_name(&self) -> Result<Option<String>, crate::Error> {
+        let name = self.name()?;
+        let name = name.to_string_lossy()?;
+        Ok(split_qualified_self(&name).map(|(_, r#trait)| r#trait.to_owned()))
+    }
+
+    /// The path of the type this vtable is for; e.g., `my_crate::Foo`.
+    pub fn self_type_name(&self) -> Result<Option<String>, crate::Error> {
+        let name = self.name()?;
+        let name = name.to_string_lossy()?;
+        Ok(split_qualified_self(&name).map(|(r#type, _)| r#type.to_owned()))
+    }
+
+    /// The concrete type this vtable is for, if recorded in DWARF (via the
+    /// `DW_AT_containing_type` of the `{vtable_type}`).
+    pub fn r#type(&self) -> Result<Option<super::Type<'dwarf, R>>, crate::Error> {
+        let Some(offset) =
+            crate::get_attr_ref(self.schema.entry(), crate::gimli::DW_AT_containing_type)?
+        else {
+            return Ok(None);
+        };
+        let entry = self.unit.entry(offset)?;
+        super::Type::from_die(self.dwarf, self.unit, entry).map(Some)
+    }
+
+    /// The layout of this vtable, interpreted as a struct.
+    pub fn as_struct(&self) -> &super::Struct<'dwarf, R> {
+        &self.schema
+    }
+
+    /// The size of this vtable, in bytes.
+    pub fn size(&self) -> Result<u64, crate::Error> {
+        self.schema.size()
+    }
+
+    /// The alignment of this vtable, in bytes.
+    pub fn align(&self) -> Result<Option<u64>, crate::Error> {
+        self.schema.align()
+    }
+}
+
+/// Splits a qualified path of the form `<T as Trait>::{vtable}` into `T` and
+/// `Trait`.
+pub(crate) fn split_qualified_self(name: &str) -> Option<(&str, &str)> {
+    let name = name.strip_prefix('<')?.strip_suffix(">::{vtable}")?;
+    let mut depth = 0usize;
+    let mut prev = None;
+    for (i, c) in name.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' if prev == Some('-') => {}
+            '>' | ')' | ']' => depth = depth.saturating_sub(1),
+            ' ' if depth == 0 && name[i..].starts_with(" as ") => {
+                return Some((&name[..i], &name[i + " as ".len()..]));
+            }
+            _ => {}
+        }
+        prev = Some(c);
+    }
+    None
+}
+
+impl<'dwarf, R> fmt::Debug for Vtable<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_tuple = f.debug_tuple("deflect::schema::Vtable");
+        debug_tuple.field(&crate::debug::DebugEntry::new(
+            self.dwarf(),
+            self.unit(),
+            self.entry(),
+        ));
+        debug_tuple.finish()
+    }
+}
+
+impl<'dwarf, R> fmt::Display for Vtable<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().map_err(crate::fmt_err)?.fmt(f)
+    }
+}
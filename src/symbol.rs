@@ -0,0 +1,78 @@
+use std::fmt;
+
+/// A code address, resolved to a function name and source location.
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    address: u64,
+    name: Option<String>,
+    file: Option<String>,
+    line: Option<u32>,
+}
+
+impl Symbol {
+    /// Resolves the (dynamic) code `address` using `provider`'s debug info.
+    pub(crate) fn resolve<P>(provider: &P, address: u64) -> Result<Self, crate::Error>
+    where
+        P: crate::DebugInfoProvider,
+    {
+        let (context, static_addr) = provider.context_for(address)?;
+        let mut frames = context.find_frames(static_addr)?;
+        let mut outermost = None;
+        while let Some(frame) = frames.next()? {
+            outermost = Some(frame);
+        }
+        let mut symbol = Self {
+            address,
+            name: None,
+            file: None,
+            line: None,
+        };
+        if let Some(frame) = outermost {
+            if let Some(function) = frame.function {
+                symbol.name = Some(function.demangle()?.into_owned());
+            }
+            if let Some(location) = frame.location {
+                symbol.file = location.file.map(str::to_owned);
+                symbol.line = location.line;
+            }
+        }
+        Ok(symbol)
+    }
+
+    /// The (dynamic) code address of this symbol.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The demangled name of the function at this address, if known.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The source file of the code at this address, if known.
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    /// The source line of the code at this address, if known.
+    pub fn line(&self) -> Option<u32> {
+        self.line
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.name() {
+            f.write_str(name)?;
+        } else {
+            write!(f, "{:#x}", self.address)?;
+        }
+        if let Some(file) = self.file() {
+            write!(f, " @ {file}")?;
+            if let Some(line) = self.line() {
+                write!(f, ":{line}")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -48,6 +48,16 @@ where
         &self.schema
     }
 
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
+
+    /// The bytes of this value.
+    pub(crate) fn bytes(&self) -> crate::Bytes<'value> {
+        self.value
+    }
+
     /// An iterator over values in the array.
     pub fn iter(&self) -> Result<super::Iter<'value, 'dwarf, P>, crate::Error> {
         let elt_type = self.schema.elt_type()?;
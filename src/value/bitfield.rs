@@ -0,0 +1,107 @@
+use std::fmt;
+
+/// A reflected bitfield value.
+pub struct Bitfield<'value, 'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: crate::schema::Bitfield<'dwarf, P::Reader>,
+    value: crate::Bytes<'value>,
+    _provider: &'dwarf P,
+}
+
+impl<'dwarf, R> crate::schema::Bitfield<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = std::primitive::usize>,
+{
+    pub(crate) unsafe fn with_bytes<'value, P>(
+        self,
+        provider: &'dwarf P,
+        value: crate::Bytes<'value>,
+    ) -> Result<Bitfield<'value, 'dwarf, P>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        let size = self.size().try_into()?;
+        let Some(value) = value.get(..size) else {
+            bail!(
+                "bitfield spans {size} bytes, but only {} are available",
+                value.len()
+            )
+        };
+        Ok(Bitfield {
+            schema: self,
+            value,
+            _provider: provider,
+        })
+    }
+}
+
+impl<'value, 'dwarf, P> Bitfield<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// The schema of this value.
+    pub fn schema(&self) -> &crate::schema::Bitfield<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// The bits of this bitfield, zero-extended.
+    pub fn bits(&self) -> u64 {
+        let bytes = self.value.iter().map(|byte| unsafe { byte.assume_init() });
+        let bit_offset = u32::from(self.schema.bit_offset());
+        let bit_size = u32::from(self.schema.bit_size());
+        // bits are numbered from the least significant bit of the first byte on
+        // little-endian targets, and from the most significant bit on
+        // big-endian targets
+        let raw = if cfg!(target_endian = "little") {
+            let raw = bytes
+                .rev()
+                .fold(0u128, |raw, byte| (raw << 8) | u128::from(byte));
+            raw >> bit_offset
+        } else {
+            let raw = bytes.fold(0u128, |raw, byte| (raw << 8) | u128::from(byte));
+            raw >> (self.value.len() as u32 * 8 - bit_offset - bit_size)
+        };
+        (raw & ((1u128 << bit_size) - 1)) as u64
+    }
+
+    /// The value of this bitfield, interpreted as an unsigned integer.
+    pub fn as_u64(&self) -> u64 {
+        self.bits()
+    }
+
+    /// The value of this bitfield, interpreted as a two's-complement signed
+    /// integer.
+    pub fn as_i64(&self) -> i64 {
+        let unused = 64 - u32::from(self.schema.bit_size());
+        ((self.bits() << unused) as i64) >> unused
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Debug for Bitfield<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::Bitfield");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("value", &self.value);
+        debug_struct.finish()
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Display for Bitfield<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.schema.encoding().map_err(crate::fmt_err)? {
+            Some(crate::gimli::DW_ATE_boolean) => (self.bits() != 0).fmt(f),
+            Some(crate::gimli::DW_ATE_signed | crate::gimli::DW_ATE_signed_char) => {
+                self.as_i64().fmt(f)
+            }
+            _ => self.bits().fmt(f),
+        }
+    }
+}
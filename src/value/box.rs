@@ -39,10 +39,39 @@ where
         &self.schema
     }
 
+    /// The bytes of this value.
+    pub(crate) fn bytes(&self) -> crate::Bytes<'value> {
+        self.value
+    }
+
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
+
+    /// The address this box points to.
+    pub fn address(&self) -> usize {
+        let offset = self.schema.wide().map_or(0, |wide| wide.pointer as usize);
+        unsafe { (self.value[offset..].as_ptr() as *const usize).read_unaligned() }
+    }
+
+    /// The metadata of this box, if it is a wide pointer to a dynamically
+    /// sized struct.
+    pub fn metadata(&self) -> Result<Option<super::Metadata>, crate::Error> {
+        let Some(wide) = self.schema.wide() else {
+            return Ok(None);
+        };
+        Ok(Some(super::wide::read(self.value, wide)?.1))
+    }
+
     /// The reflected value behind this reference.
     pub fn deref(&self) -> Result<super::Value<'value, 'dwarf, P>, crate::Error> {
-        let value = unsafe { *(self.value.as_ptr() as *const *const crate::Byte) };
         let r#type = self.schema.r#type()?;
+        if let Some(wide) = self.schema.wide() {
+            return unsafe { super::wide::deref(self.provider, self.value, wide, r#type) };
+        }
+        let value = unsafe { *(self.value.as_ptr() as *const *const crate::Byte) };
+        crate::safety::check_type(value as usize, &r#type)?;
         let size = r#type.size()?;
         let size = size.try_into()?;
         let value = std::ptr::slice_from_raw_parts(value, size);
@@ -51,23 +80,6 @@ where
     }
 }
 
-/*
-impl<'value, 'dwarf, P> Box<'value, 'dwarf, P>
-where
-    P: crate::DebugInfoProvider,
-{
-    /// The unreflected value behind this reference.
-    pub(crate) fn deref_raw(&self) -> Result<crate::Bytes<'value>, crate::Error> {
-        let value = unsafe { *(self.value.as_ptr() as *const *const crate::Byte) };
-        let r#type = self.schema.r#type()?;
-        let size = r#type.size()?;
-        let size = size.try_into()?;
-        let value = std::ptr::slice_from_raw_parts(value, size);
-        let value = unsafe { &*value };
-        Ok(value)
-    }
-}*/
-
 impl<'value, 'dwarf, P> fmt::Debug for Box<'value, 'dwarf, P>
 where
     P: crate::DebugInfoProvider,
@@ -85,7 +97,11 @@ where
     P: crate::DebugInfoProvider,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self.deref() {
+            Ok(value) => value,
+            Err(err) => return crate::safety::display_invalid(err, f),
+        };
         f.write_str("box ")?;
-        self.deref().map_err(crate::fmt_err)?.fmt(f)
+        super::referents::display(&value, f, true)
     }
-}
\ No newline at end of file
+}
@@ -38,41 +38,95 @@ where
     pub fn schema(&self) -> &crate::schema::BoxedDyn<'dwarf, P::Reader> {
         &self.schema
     }
-    
-    fn data(&self, size: usize) -> Result<crate::Bytes<'value>, crate::Error> {
+
+    /// The bytes of this value.
+    pub(crate) fn bytes(&self) -> crate::Bytes<'value> {
+        self.value
+    }
+
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
+
+    /// The bytes of the trait object behind this box, which spans `size` bytes
+    /// and is aligned to `align`.
+    fn data(&self, size: usize, align: usize) -> Result<crate::Bytes<'value>, crate::Error> {
         let field =
             unsafe { super::Field::new(self.schema.pointer().clone(), self.value, self.provider) };
         let value = field.value()?;
         let value: super::Pointer<crate::schema::Mut, _> = value.try_into()?;
-        let ptr = value.deref_raw_dyn(size)?;
+        let ptr = value.deref_raw_dyn(size, align)?;
         Ok(ptr)
     }
 
-    fn vtable(&self) -> Result<super::Value<'value, 'dwarf, P>, crate::Error> {
+    /// The address of the trait object behind this box.
+    pub(crate) fn address(&self) -> Result<usize, crate::Error> {
+        Ok(self.data(0, 1)?.as_ptr() as usize)
+    }
+
+    fn vtable_ptr(&self) -> Result<super::Value<'value, 'dwarf, P>, crate::Error> {
         let vtable = self.schema.vtable().clone();
         let field = unsafe { super::Field::new(vtable, self.value, self.provider) };
         let value = field.value()?;
         Ok(value)
     }
 
+    /// The vtable of this trait object.
+    pub fn vtable(&self) -> Result<super::Vtable<'value, 'dwarf, P>, crate::Error> {
+        let vtable = self.vtable_ptr()?;
+        let vtable: super::Pointer<crate::schema::Shared, _> = vtable.try_into()?;
+        let address = vtable
+            .deref_raw_dyn(0, std::mem::align_of::<usize>())?
+            .as_ptr() as u64;
+        unsafe { super::vtable::vtable_at(self.provider, address) }
+    }
+
     /// [`Box`]'d `dyn Trait` object value.
+    ///
+    /// The concrete type of the object is recovered from the DWARF of its
+    /// vtable, falling back to the type of its drop glue when that is
+    /// unavailable.
     pub fn deref(&self) -> Result<super::Value<'value, 'dwarf, P>, crate::Error> {
-        let vtable = self.vtable()?;
+        let vtable = match self.vtable() {
+            Ok(vtable) => vtable,
+            Err(err)
+                if err.is::<crate::error::VtableNotFound>()
+                    || err.is::<crate::error::Unsupported>() =>
+            {
+                return self.deref_by_drop_glue();
+            }
+            Err(err) => return Err(err),
+        };
+        if let Some(schema) = vtable.schema().r#type()? {
+            let data = self.data(vtable.size()?, vtable.align()?)?;
+            return unsafe { crate::Value::with_type(schema, data, self.provider) };
+        }
+        self.deref_by_drop_glue()
+    }
+
+    /// Dereferences this trait object, using its drop glue to determine its
+    /// concrete type.
+    fn deref_by_drop_glue(&self) -> Result<super::Value<'value, 'dwarf, P>, crate::Error> {
+        let vtable = self.vtable_ptr()?;
         let vtable: super::Pointer<crate::schema::Shared, _> = vtable.try_into()?;
         let vtable = vtable.deref()?;
         let vtable: super::Array<_> = vtable.try_into()?;
         let mut vtable = vtable.iter()?;
-        let drop_glue = vtable.next();
-        let drop_glue = drop_glue.unwrap();
-        let drop_glue = drop_glue?;
-        let drop_glue: usize = drop_glue.try_into()?;
+        let mut next = |entry| {
+            vtable
+                .next()
+                .ok_or_else(|| anyhow!("vtable does not have a {entry} entry"))?
+        };
+        let drop_glue: usize = next("drop glue")?.try_into()?;
+        if drop_glue == 0 {
+            bail!("could not determine the concrete type of a trait object without drop glue");
+        }
 
-        let size = vtable.next();
-        let size = size.unwrap();
-        let size = size?;
-        let size: usize = size.try_into()?;
+        let size: usize = next("size")?.try_into()?;
+        let align: usize = next("align")?.try_into()?;
 
-        let data = self.data(size)?;
+        let data = self.data(size, align)?;
 
         let crate::DebugInfo {
             context,
@@ -103,8 +157,11 @@ where
     P: crate::DebugInfoProvider,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let value = self.deref().map_err(crate::fmt_err)?;
+        let value = match self.deref() {
+            Ok(value) => value,
+            Err(err) => return crate::safety::display_invalid(err, f),
+        };
         f.write_str("box ")?;
-        value.fmt(f)
+        super::referents::display(&value, f, true)
     }
 }
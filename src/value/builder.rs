@@ -0,0 +1,580 @@
+use super::value_mut::short_name;
+use crate::schema::Type;
+use std::{alloc, fmt, ptr::NonNull};
+
+/// Builds a value of a reflected type, piece by piece.
+///
+/// Fill in struct fields by name with [`field`][Self::field], choose enum
+/// variants with [`variant`][Self::variant], and fill arrays with
+/// [`element`][Self::element]; then, [`finish`][Self::finish] the value once
+/// every part of it has been initialized.
+///
+/// Values that have been written into an unfinished `Builder` are leaked,
+/// not dropped, when it is dropped.
+///
+/// Reflection disregards privacy, so a `Builder` can construct values that
+/// the safe API of their type never would (e.g., a `NonZeroU32` of zero);
+/// hence, constructing a `Builder` is `unsafe`.
+pub struct Builder<'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: Type<'dwarf, P::Reader>,
+    allocation: Allocation,
+    state: State,
+    provider: &'dwarf P,
+}
+
+/// The initialization state of (part of) a value under construction.
+#[derive(Clone)]
+enum State {
+    /// Nothing has been written.
+    Uninit,
+    /// The whole value has been written.
+    Init,
+    /// Some of the named fields of a struct have been written.
+    Fields(Vec<(String, State)>),
+    /// A variant of an enum has been chosen, and some of its named fields
+    /// have been written.
+    Variant(String, Vec<(String, State)>),
+    /// Some of the elements of an array have been written.
+    Elements(Vec<State>),
+}
+
+/// A zeroed, suitably aligned allocation.
+struct Allocation {
+    pointer: NonNull<u8>,
+    layout: alloc::Layout,
+}
+
+impl<'dwarf, P> Builder<'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// Prepares to build a value of type `schema`.
+    ///
+    /// ## Safety
+    /// The value built must uphold every invariant of its type, and of the
+    /// types of its parts, including those of their private fields. No other
+    /// type of the program may share the path of a type whose value is
+    /// [`set`][Slot::set] into the value.
+    pub unsafe fn new(
+        schema: Type<'dwarf, P::Reader>,
+        provider: &'dwarf P,
+    ) -> Result<Self, crate::Error> {
+        let size = schema.size()?.try_into()?;
+        let align = align_of(&schema)?.try_into()?;
+        let layout = alloc::Layout::from_size_align(size, align)?;
+        Ok(Self {
+            allocation: Allocation::new(layout),
+            state: initial_state(&schema)?,
+            schema,
+            provider,
+        })
+    }
+
+    /// The schema of the value under construction.
+    pub fn schema(&self) -> &Type<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// The whole value under construction.
+    pub fn root(&mut self) -> Slot<'_, 'dwarf, P> {
+        Slot {
+            schema: self.schema.clone(),
+            value: self.allocation.bytes_mut(),
+            state: &mut self.state,
+            provider: self.provider,
+        }
+    }
+
+    /// The field named `field_name` of this struct, or of the chosen variant
+    /// of this enum. See [`Slot::field`].
+    pub fn field<N>(&mut self, field_name: N) -> Result<Slot<'_, 'dwarf, P>, crate::Error>
+    where
+        N: AsRef<[u8]>,
+    {
+        self.root().field(field_name)
+    }
+
+    /// Chooses the variant named `name` of this enum. See [`Slot::variant`].
+    pub fn variant(&mut self, name: &str) -> Result<Slot<'_, 'dwarf, P>, crate::Error> {
+        self.root().variant(name)
+    }
+
+    /// The element at `index` of this array. See [`Slot::element`].
+    pub fn element(&mut self, index: usize) -> Result<Slot<'_, 'dwarf, P>, crate::Error> {
+        self.root().element(index)
+    }
+
+    /// Initializes the whole value with `value`. See [`Slot::set`].
+    pub fn set<T>(&mut self, value: T) -> Result<(), crate::Error>
+    where
+        T: crate::Reflect + 'static,
+    {
+        self.root().set(value)
+    }
+
+    /// Checks that every part of the value has been initialized, reporting
+    /// the first part that has not; e.g., `` `Config.mode::Auto.max` is
+    /// uninitialized ``.
+    pub fn check(&self) -> Result<(), crate::Error> {
+        if let Some(path) = uninit_path(&self.state) {
+            bail!("`{}{path}` is uninitialized", short_name(&self.schema)?);
+        }
+        Ok(())
+    }
+
+    /// Finishes building the value.
+    ///
+    /// Fails if any part of the value is uninitialized; see
+    /// [`check`][Self::check].
+    pub fn finish(self) -> Result<Built<'dwarf, P>, crate::Error> {
+        self.check()?;
+        Ok(Built {
+            schema: self.schema,
+            allocation: self.allocation,
+            provider: self.provider,
+        })
+    }
+}
+
+impl<'dwarf, P> fmt::Debug for Builder<'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::Builder");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("layout", &self.allocation.layout);
+        debug_struct.finish()
+    }
+}
+
+/// A part of a value under construction by a [`Builder`].
+pub struct Slot<'b, 'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: Type<'dwarf, P::Reader>,
+    value: &'b mut [crate::Byte],
+    state: &'b mut State,
+    provider: &'dwarf P,
+}
+
+impl<'b, 'dwarf, P> Slot<'b, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// The schema of this part of the value.
+    pub fn schema(&self) -> &Type<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// This part of the value, reborrowed; e.g., to fill in several of its
+    /// fields.
+    pub fn reborrow(&mut self) -> Slot<'_, 'dwarf, P> {
+        Slot {
+            schema: self.schema.clone(),
+            value: self.value,
+            state: self.state,
+            provider: self.provider,
+        }
+    }
+
+    /// The field named `field_name` of this struct, or of the chosen variant
+    /// of this enum.
+    ///
+    /// Bitfields cannot be built, and are left zeroed.
+    pub fn field<N>(self, field_name: N) -> Result<Slot<'b, 'dwarf, P>, crate::Error>
+    where
+        N: AsRef<[u8]>,
+    {
+        let target_name = field_name.as_ref();
+        let (mut fields, states) = match (&self.schema, &mut *self.state) {
+            (Type::Struct(schema), state) => {
+                if !matches!(state, State::Fields(_)) {
+                    let init = matches!(state, State::Init);
+                    *state = State::Fields(field_states(schema.fields()?, init)?);
+                }
+                let State::Fields(states) = state else {
+                    unreachable!()
+                };
+                (schema.fields()?, states)
+            }
+            (Type::Enum(schema), state) => {
+                if matches!(state, State::Init) {
+                    // the variant of an initialized enum is already known
+                    let value = unsafe { schema.clone().with_bytes(self.provider, self.value)? };
+                    let variant = value.variant()?;
+                    let variant = variant.schema();
+                    let name = variant.name()?.to_string_lossy()?.into_owned();
+                    *state = State::Variant(name, field_states(variant.fields()?, true)?);
+                }
+                let State::Variant(name, states) = state else {
+                    bail!("no variant of `{}` has been chosen", schema.name())
+                };
+                (find_variant(schema, name)?.fields()?, states)
+            }
+            (schema, _) => bail!("`{}` does not have fields", short_name(schema)?),
+        };
+        let mut fields = fields.iter()?;
+        let mut states = states.iter_mut();
+        while let (Some(field), Some((name, state))) = (fields.try_next()?, states.next()) {
+            if name.as_bytes() != target_name {
+                continue;
+            }
+            if field.bit_size()?.is_some() || field.is_unsized()? {
+                bail!("field `{name}` cannot be built");
+            }
+            let (start, end) = super::value_mut::field_range(&field)?;
+            let Some(value) = self.value.get_mut(start..end) else {
+                bail!("field `{name}` spans bytes {start}..{end}, which are out of bounds")
+            };
+            return Ok(Slot {
+                schema: field.r#type()?,
+                value,
+                state,
+                provider: self.provider,
+            });
+        }
+        bail!(
+            "`{}` has no field named `{}`",
+            short_name(&self.schema)?,
+            String::from_utf8_lossy(target_name)
+        )
+    }
+
+    /// Chooses the variant named `name` of this enum, writing its
+    /// discriminant.
+    ///
+    /// The fields of the variant must then be initialized with
+    /// [`field`][Self::field]. Fields of a previously chosen variant are
+    /// leaked, not dropped.
+    pub fn variant(self, name: &str) -> Result<Slot<'b, 'dwarf, P>, crate::Error> {
+        let Type::Enum(schema) = &self.schema else {
+            bail!("`{}` is not an enum", short_name(&self.schema)?)
+        };
+        let variant = find_variant(schema, name)?;
+        super::value_mut::write_discriminant(self.value, schema, &variant)?;
+        *self.state = State::Variant(name.to_owned(), field_states(variant.fields()?, false)?);
+        Ok(self)
+    }
+
+    /// The element at `index` of this array.
+    pub fn element(self, index: usize) -> Result<Slot<'b, 'dwarf, P>, crate::Error> {
+        let Type::Array(schema) = &self.schema else {
+            bail!("`{}` is not an array", short_name(&self.schema)?)
+        };
+        let len: usize = schema.len()?.try_into()?;
+        if index >= len {
+            bail!("index {index} is out of bounds of `{}`", self.schema);
+        }
+        let elt_type = schema.elt_type()?;
+        let elt_size: usize = elt_type.size()?.try_into()?;
+        if !matches!(self.state, State::Elements(_)) {
+            let state = match self.state {
+                State::Init => State::Init,
+                _ => initial_state(&elt_type)?,
+            };
+            *self.state = State::Elements(vec![state; len]);
+        }
+        let State::Elements(states) = self.state else {
+            unreachable!()
+        };
+        let start = index * elt_size;
+        Ok(Slot {
+            schema: elt_type,
+            value: &mut self.value[start..start + elt_size],
+            state: &mut states[index],
+            provider: self.provider,
+        })
+    }
+
+    /// Initializes this part of the value with `value`, dropping any value
+    /// it previously held in full.
+    ///
+    /// Fails if the type of this part of the value does not share the path,
+    /// size and structure of `T`'s. Distinct types may share a path (e.g.,
+    /// those of two versions of one crate); the safety contract of
+    /// [`Builder::new`] precludes this.
+    pub fn set<T>(self, value: T) -> Result<(), crate::Error>
+    where
+        T: crate::Reflect + 'static,
+    {
+        let r#type = (&value as &dyn crate::Reflect).reflect_type(self.provider)?;
+        // SAFETY: no other type shares the path of `T`, as promised to
+        // `Builder::new`
+        if !unsafe { super::value_mut::same_path(&self.schema, &r#type)? }
+            || self.value.len() != std::mem::size_of::<T>()
+        {
+            bail!(
+                "cannot set a `{}` to a `{}`",
+                short_name(&self.schema)?,
+                short_name(&r#type)?
+            );
+        }
+        let pointer = self.value.as_mut_ptr() as *mut T;
+        if matches!(self.state, State::Init) {
+            drop(unsafe { pointer.read_unaligned() });
+        }
+        unsafe { pointer.write_unaligned(value) };
+        *self.state = State::Init;
+        Ok(())
+    }
+}
+
+impl<'b, 'dwarf, P> fmt::Debug for Slot<'b, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::Slot");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("value", &self.value);
+        debug_struct.finish()
+    }
+}
+
+/// A value built by a [`Builder`].
+///
+/// The value is leaked, not dropped, unless it is converted
+/// [into a `Box`][Self::into_box].
+pub struct Built<'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: Type<'dwarf, P::Reader>,
+    allocation: Allocation,
+    provider: &'dwarf P,
+}
+
+impl<'dwarf, P> Built<'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// The schema of this value.
+    pub fn schema(&self) -> &Type<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// This value, reflected.
+    pub fn value(&self) -> Result<super::Value<'_, 'dwarf, P>, crate::Error> {
+        let value = self.allocation.bytes();
+        unsafe { super::Value::with_type(self.schema.clone(), value, self.provider) }
+    }
+
+    /// Whether this value's type shares the path, size, structure and layout
+    /// of `T`'s; it is then of type `T`, unless another type of the program
+    /// shares the path of `T`.
+    pub fn is<T: crate::Reflect>(&self) -> Result<bool, crate::Error> {
+        let local_type_id = <T as crate::Reflect>::local_type_id as *const () as usize;
+        let r#type = crate::type_of_local_id(self.provider, local_type_id)?;
+        // SAFETY: the result is not relied upon here
+        Ok(
+            unsafe { super::value_mut::same_path(&self.schema, &r#type)? }
+                && self.allocation.layout == alloc::Layout::new::<T>(),
+        )
+    }
+
+    /// Converts this value into a `Box<T>`.
+    ///
+    /// Fails if this value is not of type `T`; see [`is`][Self::is].
+    ///
+    /// ## Safety
+    /// No other type of the program may share the path of `T`.
+    pub unsafe fn into_box<T>(self) -> Result<Box<T>, crate::Error>
+    where
+        T: crate::Reflect + 'static,
+    {
+        if !self.is::<T>()? {
+            bail!(
+                "cannot convert a `{}` into a `Box<{}>`",
+                short_name(&self.schema)?,
+                std::any::type_name::<T>()
+            );
+        }
+        let pointer = self.allocation.pointer.as_ptr() as *mut T;
+        // the allocation now belongs to the box
+        std::mem::forget(self.allocation);
+        Ok(unsafe { Box::from_raw(pointer) })
+    }
+}
+
+impl<'dwarf, P> fmt::Debug for Built<'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::Built");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("layout", &self.allocation.layout);
+        debug_struct.finish()
+    }
+}
+
+impl<'dwarf, P> fmt::Display for Built<'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value().map_err(crate::fmt_err)?.fmt(f)
+    }
+}
+
+impl Allocation {
+    fn new(layout: alloc::Layout) -> Self {
+        let pointer = if layout.size() == 0 {
+            // zero-sized values need only be aligned
+            NonNull::new(layout.align() as *mut u8)
+        } else {
+            NonNull::new(unsafe { alloc::alloc_zeroed(layout) })
+        };
+        let Some(pointer) = pointer else {
+            alloc::handle_alloc_error(layout)
+        };
+        Self { pointer, layout }
+    }
+
+    fn bytes(&self) -> crate::Bytes<'_> {
+        let pointer = self.pointer.as_ptr() as *const crate::Byte;
+        unsafe { std::slice::from_raw_parts(pointer, self.layout.size()) }
+    }
+
+    fn bytes_mut(&mut self) -> &mut [crate::Byte] {
+        let pointer = self.pointer.as_ptr() as *mut crate::Byte;
+        unsafe { std::slice::from_raw_parts_mut(pointer, self.layout.size()) }
+    }
+}
+
+impl Drop for Allocation {
+    fn drop(&mut self) {
+        if self.layout.size() != 0 {
+            unsafe { alloc::dealloc(self.pointer.as_ptr(), self.layout) }
+        }
+    }
+}
+
+/// The alignment of values of `r#type`, in bytes.
+pub(crate) fn align_of<R>(r#type: &Type<'_, R>) -> Result<u64, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let align = match r#type {
+        Type::bool(v) => v.align(),
+        Type::char(v) => v.align(),
+        Type::f32(v) => v.align(),
+        Type::f64(v) => v.align(),
+        Type::i8(v) => v.align(),
+        Type::i16(v) => v.align(),
+        Type::i32(v) => v.align(),
+        Type::i64(v) => v.align(),
+        Type::i128(v) => v.align(),
+        Type::isize(v) => v.align(),
+        Type::u8(v) => v.align(),
+        Type::u16(v) => v.align(),
+        Type::u32(v) => v.align(),
+        Type::u64(v) => v.align(),
+        Type::u128(v) => v.align(),
+        Type::usize(v) => v.align(),
+        Type::unit(v) => v.align(),
+        Type::str(_)
+        | Type::Box(_)
+        | Type::BoxedSlice(_)
+        | Type::BoxedDyn(_)
+        | Type::Slice(_)
+        | Type::SharedRef(_)
+        | Type::UniqueRef(_)
+        | Type::ConstPtr(_)
+        | Type::MutPtr(_)
+        | Type::Function(_) => std::mem::align_of::<usize>() as u64,
+        Type::Array(v) => align_of(&v.elt_type()?)?,
+        Type::Vector(v) => v.align()?,
+        Type::Struct(v) => v
+            .align()?
+            .ok_or_else(|| crate::error::missing_attr(crate::gimli::DW_AT_alignment))?,
+        Type::Enum(v) => v
+            .align()?
+            .ok_or_else(|| crate::error::missing_attr(crate::gimli::DW_AT_alignment))?,
+        r#type => bail!(
+            "cannot determine the alignment of `{}`",
+            short_name(r#type)?
+        ),
+    };
+    Ok(align)
+}
+
+/// The initial state of a value of `r#type`; zero-sized values (other than
+/// enums, which may be uninhabited) need no initialization.
+fn initial_state<R>(r#type: &Type<'_, R>) -> Result<State, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    if r#type.size()? == 0 && !matches!(r#type, Type::Enum(_)) {
+        Ok(State::Init)
+    } else {
+        Ok(State::Uninit)
+    }
+}
+
+/// The initial states of `fields`, each named and either initialized or not.
+fn field_states<R>(
+    mut fields: crate::schema::Fields<'_, R>,
+    init: bool,
+) -> Result<Vec<(String, State)>, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let mut states = vec![];
+    let mut fields = fields.iter()?;
+    while let Some(field) = fields.try_next()? {
+        let name = field.name()?.to_string_lossy()?.into_owned();
+        let state = if init || field.bit_size()?.is_some() {
+            // bitfields cannot be built, and so are left zeroed
+            State::Init
+        } else if field.is_unsized()? {
+            State::Uninit
+        } else {
+            initial_state(&field.r#type()?)?
+        };
+        states.push((name, state));
+    }
+    Ok(states)
+}
+
+/// The variant of `schema` named `name`.
+fn find_variant<'dwarf, R>(
+    schema: &crate::schema::Enum<'dwarf, R>,
+    name: &str,
+) -> Result<crate::schema::Variant<'dwarf, R>, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let mut variants = schema.variants()?;
+    let mut variants = variants.iter()?;
+    while let Some(variant) = variants.try_next()? {
+        if variant.name()?.to_slice()? == name.as_bytes() {
+            return Ok(variant);
+        }
+    }
+    bail!("`{}` has no variant named `{name}`", schema.name())
+}
+
+/// The path to the first uninitialized part of `state`, if any; e.g.,
+/// `.mode::Auto.min`.
+fn uninit_path(state: &State) -> Option<String> {
+    match state {
+        State::Init => None,
+        State::Uninit => Some(String::new()),
+        State::Fields(fields) => fields
+            .iter()
+            .find_map(|(name, state)| Some(format!(".{name}{}", uninit_path(state)?))),
+        State::Variant(variant, fields) => fields
+            .iter()
+            .find_map(|(name, state)| Some(format!("::{variant}.{name}{}", uninit_path(state)?))),
+        State::Elements(elements) => elements
+            .iter()
+            .enumerate()
+            .find_map(|(index, state)| Some(format!("[{index}]{}", uninit_path(state)?))),
+    }
+}
@@ -0,0 +1,483 @@
+//! Deserialization of reflected types with [`serde`].
+
+use super::serde_names;
+use super::value_mut::short_name;
+use crate::schema::Type;
+use serde::de::{self, DeserializeSeed, Error as _};
+use std::fmt;
+
+/// A [`DeserializeSeed`] that builds a value of a reflected type.
+///
+/// Values are deserialized as `#[derive(Deserialize)]` would deserialize
+/// them: structs, tuples and enums through the [`Deserializer`][de::Deserializer]
+/// methods for their kinds, under the names of their types, variants and
+/// fields, arrays as tuples, and `Option`s as optional values. Hence, values
+/// may be deserialized from self-describing and non-self-describing formats
+/// alike. `String`s are deserialized as strings; other types containing
+/// pointers cannot be deserialized.
+///
+/// Reflection disregards privacy, so a `Seed` can deserialize values that the
+/// safe API of their type never would (e.g., a `NonZeroU32` from `0`); hence,
+/// constructing a `Seed` is `unsafe`.
+///
+/// ```
+/// use serde::de::DeserializeSeed;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Point {
+///     x: i16,
+///     y: i16,
+/// }
+///
+/// let provider = deflect::default_provider()?;
+/// let origin: &dyn deflect::Reflect = &Point { x: 0, y: 0 };
+/// let schema: deflect::value::Struct = origin.reflect(&provider)?.try_into()?;
+/// // SAFETY: `Point` has no invariants, and no other type shares its path
+/// let seed = unsafe { deflect::value::Seed::new(schema.schema().clone().into(), &provider) };
+/// let mut json = serde_json::Deserializer::from_str(r#"{ "x": 1, "y": -2 }"#);
+/// let point = unsafe { seed.deserialize(&mut json)?.into_box::<Point>()? };
+/// assert_eq!(*point, Point { x: 1, y: -2 });
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct Seed<'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: Type<'dwarf, P::Reader>,
+    provider: &'dwarf P,
+}
+
+impl<'dwarf, P> Seed<'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// Prepares to deserialize a value of type `schema`.
+    ///
+    /// ## Safety
+    /// Every value that may be deserialized must uphold the invariants of
+    /// `schema`'s type, and of the types of its parts, including those of
+    /// their private fields. No other type of the program may share the path
+    /// of `String`. See [`Builder::new`][super::Builder::new].
+    pub unsafe fn new(schema: Type<'dwarf, P::Reader>, provider: &'dwarf P) -> Self {
+        Self { schema, provider }
+    }
+}
+
+impl<'de, 'dwarf, P> DeserializeSeed<'de> for Seed<'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    type Value = super::Built<'dwarf, P>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        // SAFETY: this seed's safety contract is `Builder::new`'s
+        let mut builder =
+            unsafe { super::Builder::new(self.schema, self.provider) }.map_err(D::Error::custom)?;
+        SlotSeed(builder.root()).deserialize(deserializer)?;
+        builder.finish().map_err(D::Error::custom)
+    }
+}
+
+impl<'dwarf, P> fmt::Debug for Seed<'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::Seed");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.finish()
+    }
+}
+
+/// Deserializes a part of a value into its [`Slot`][super::Slot].
+struct SlotSeed<'b, 'dwarf, P>(super::Slot<'b, 'dwarf, P>)
+where
+    P: crate::DebugInfoProvider;
+
+impl<'de, 'b, 'dwarf, P> DeserializeSeed<'de> for SlotSeed<'b, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let slot = self.0;
+        macro_rules! primitive {
+            ($t:ty) => {{
+                let value = <$t as de::Deserialize>::deserialize(deserializer)?;
+                slot.set(value).map_err(D::Error::custom)
+            }};
+        }
+        match slot.schema().clone() {
+            Type::bool(_) => primitive!(bool),
+            Type::char(_) => primitive!(char),
+            Type::f32(_) => primitive!(f32),
+            Type::f64(_) => primitive!(f64),
+            Type::i8(_) => primitive!(i8),
+            Type::i16(_) => primitive!(i16),
+            Type::i32(_) => primitive!(i32),
+            Type::i64(_) => primitive!(i64),
+            Type::i128(_) => primitive!(i128),
+            Type::isize(_) => primitive!(isize),
+            Type::u8(_) => primitive!(u8),
+            Type::u16(_) => primitive!(u16),
+            Type::u32(_) => primitive!(u32),
+            Type::u64(_) => primitive!(u64),
+            Type::u128(_) => primitive!(u128),
+            Type::usize(_) => primitive!(usize),
+            Type::unit(_) => primitive!(()),
+            r#type @ Type::Struct(_) if is_string(&r#type).map_err(D::Error::custom)? => {
+                primitive!(String)
+            }
+            Type::Struct(schema) => {
+                let name = schema.name().map_err(D::Error::custom)?;
+                let name = name.to_string_lossy().map_err(D::Error::custom)?;
+                let names = field_names(schema.fields().map_err(D::Error::custom)?)
+                    .map_err(D::Error::custom)?;
+                let visitor = FieldsVisitor { slot, names };
+                let len = visitor.names.len();
+                if serde_names::is_tuple(&name) {
+                    return deserializer.deserialize_tuple(len, visitor);
+                }
+                let name = serde_names::type_name(&name);
+                if visitor.names.is_empty() {
+                    deserializer.deserialize_unit_struct(name, visitor)
+                } else if visitor.is_newtype() {
+                    deserializer.deserialize_newtype_struct(name, visitor)
+                } else if visitor.is_tuple() {
+                    deserializer.deserialize_tuple_struct(name, len, visitor)
+                } else {
+                    let fields = serde_names::intern_all(&visitor.names);
+                    deserializer.deserialize_struct(name, fields, visitor)
+                }
+            }
+            Type::Enum(schema) if schema.is_option().map_err(D::Error::custom)? => {
+                deserializer.deserialize_option(OptionVisitor(slot))
+            }
+            Type::Enum(schema) => {
+                let name = schema.name().to_string_lossy().map_err(D::Error::custom)?;
+                let variants = variant_fields(&schema).map_err(D::Error::custom)?;
+                let names: Vec<_> = variants.iter().map(|(name, _)| name.clone()).collect();
+                let visitor = EnumVisitor { slot, variants };
+                deserializer.deserialize_enum(
+                    serde_names::type_name(&name),
+                    serde_names::intern_all(&names),
+                    visitor,
+                )
+            }
+            Type::Array(schema) => {
+                let len = schema
+                    .len()
+                    .map_err(D::Error::custom)?
+                    .try_into()
+                    .map_err(D::Error::custom)?;
+                deserializer.deserialize_tuple(len, ArrayVisitor { slot, len })
+            }
+            r#type => Err(D::Error::custom(format_args!(
+                "cannot deserialize a `{}`",
+                short_name(&r#type).map_err(D::Error::custom)?
+            ))),
+        }
+    }
+}
+
+/// Deserializes the fields of a struct or enum variant.
+struct FieldsVisitor<'b, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    slot: super::Slot<'b, 'dwarf, P>,
+    names: Vec<String>,
+}
+
+impl<'b, 'dwarf, P> FieldsVisitor<'b, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// Whether these are the fields of a tuple struct or variant.
+    fn is_tuple(&self) -> bool {
+        serde_names::are_positional(&self.names)
+    }
+
+    /// Whether these are the fields of a newtype struct or variant, which
+    /// `serde` represents by their only field.
+    fn is_newtype(&self) -> bool {
+        self.names.len() == 1 && self.is_tuple()
+    }
+}
+
+impl<'de, 'b, 'dwarf, P> de::Visitor<'de> for FieldsVisitor<'b, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the fields ")?;
+        f.debug_list().entries(&self.names).finish()
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(())
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let field = self.slot.field(&self.names[0]).map_err(D::Error::custom)?;
+        SlotSeed(field).deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        for (index, name) in self.names.iter().enumerate() {
+            let field = self.slot.reborrow().field(name).map_err(A::Error::custom)?;
+            if seq.next_element_seed(SlotSeed(field))?.is_none() {
+                return Err(A::Error::invalid_length(index, &self));
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        while let Some(name) = map.next_key::<String>()? {
+            let field = self
+                .slot
+                .reborrow()
+                .field(&name)
+                .map_err(A::Error::custom)?;
+            map.next_value_seed(SlotSeed(field))?;
+        }
+        Ok(())
+    }
+}
+
+/// Deserializes the elements of an array.
+struct ArrayVisitor<'b, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    slot: super::Slot<'b, 'dwarf, P>,
+    len: usize,
+}
+
+impl<'de, 'b, 'dwarf, P> de::Visitor<'de> for ArrayVisitor<'b, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an array of length {}", self.len)
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        for index in 0..self.len {
+            let element = self
+                .slot
+                .reborrow()
+                .element(index)
+                .map_err(A::Error::custom)?;
+            if seq.next_element_seed(SlotSeed(element))?.is_none() {
+                return Err(A::Error::invalid_length(index, &self));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Deserializes an `Option`, as `None` or `Some`.
+struct OptionVisitor<'b, 'dwarf, P>(super::Slot<'b, 'dwarf, P>)
+where
+    P: crate::DebugInfoProvider;
+
+impl<'de, 'b, 'dwarf, P> de::Visitor<'de> for OptionVisitor<'b, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an optional value")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0.variant("None").map_err(E::custom)?;
+        Ok(())
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let slot = self.0.variant("Some").map_err(D::Error::custom)?;
+        let field = slot.field("__0").map_err(D::Error::custom)?;
+        SlotSeed(field).deserialize(deserializer)
+    }
+}
+
+/// Deserializes an externally tagged enum variant.
+struct EnumVisitor<'b, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    slot: super::Slot<'b, 'dwarf, P>,
+    /// The names of the variants of the enum, and of their fields.
+    variants: Vec<(String, Vec<String>)>,
+}
+
+impl<'de, 'b, 'dwarf, P> de::Visitor<'de> for EnumVisitor<'b, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an enum variant")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::EnumAccess<'de>,
+    {
+        let names = self.variants.iter().map(|(name, _)| name.clone()).collect();
+        let (name, access) = data.variant_seed(VariantSeed(names))?;
+        let slot = self.slot.variant(&name).map_err(A::Error::custom)?;
+        let names = self
+            .variants
+            .into_iter()
+            .find_map(|(variant, fields)| (variant == name).then_some(fields))
+            .unwrap_or_default();
+        let visitor = FieldsVisitor { slot, names };
+        if visitor.names.is_empty() {
+            de::VariantAccess::unit_variant(access)
+        } else if visitor.is_newtype() {
+            let field = visitor.slot.field(&visitor.names[0]);
+            de::VariantAccess::newtype_variant_seed(
+                access,
+                SlotSeed(field.map_err(A::Error::custom)?),
+            )
+        } else if visitor.is_tuple() {
+            de::VariantAccess::tuple_variant(access, visitor.names.len(), visitor)
+        } else {
+            let fields = serde_names::intern_all(&visitor.names);
+            de::VariantAccess::struct_variant(access, fields, visitor)
+        }
+    }
+}
+
+/// Deserializes the name (or index) of an enum variant.
+struct VariantSeed(Vec<String>);
+
+impl<'de> DeserializeSeed<'de> for VariantSeed {
+    type Value = String;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for VariantSeed {
+    type Value = String;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "one of the variants ")?;
+        f.debug_list().entries(&self.0).finish()
+    }
+
+    fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(name.to_owned())
+    }
+
+    fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let name = usize::try_from(index)
+            .ok()
+            .and_then(|index| self.0.get(index));
+        match name {
+            Some(name) => Ok(name.clone()),
+            None => Err(E::invalid_value(de::Unexpected::Unsigned(index), &self)),
+        }
+    }
+}
+
+/// Whether `r#type` is `alloc::string::String`.
+fn is_string<R>(r#type: &Type<'_, R>) -> Result<bool, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let Type::Struct(schema) = r#type else {
+        return Ok(false);
+    };
+    let Some(name) = schema.name_opt()? else {
+        return Ok(false);
+    };
+    // check the name first, as computing the path is comparatively costly
+    Ok(&*name.to_slice()? == b"String" && schema.path()? == "alloc::string::String")
+}
+
+/// The names of `fields`.
+fn field_names<R>(mut fields: crate::schema::Fields<'_, R>) -> Result<Vec<String>, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let mut names = vec![];
+    let mut fields = fields.iter()?;
+    while let Some(field) = fields.try_next()? {
+        names.push(field.name()?.to_string_lossy()?.into_owned());
+    }
+    Ok(names)
+}
+
+/// The names of the variants of `schema`, and of their fields.
+fn variant_fields<R>(
+    schema: &crate::schema::Enum<'_, R>,
+) -> Result<Vec<(String, Vec<String>)>, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let mut names = vec![];
+    let mut variants = schema.variants()?;
+    let mut variants = variants.iter()?;
+    while let Some(variant) = variants.try_next()? {
+        let name = variant.name()?.to_string_lossy()?.into_owned();
+        names.push((name, field_names(variant.fields()?)?));
+    }
+    Ok(names)
+}
@@ -41,6 +41,52 @@ where
         &self.schema
     }
 
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
+
+    /// The bytes of this value.
+    pub(crate) fn bytes(&self) -> crate::Bytes<'value> {
+        self.value
+    }
+
+    /// This value's type's own [`Debug`][fmt::Debug] impl, if the program
+    /// contains a vtable for it.
+    ///
+    /// ## Safety
+    /// No other type of the program may share the path of this value's type
+    /// (as may, e.g., the types of semver-incompatible versions of a crate);
+    /// vtables are found by that path when the program has none for this
+    /// value's own DIE.
+    pub unsafe fn as_debug(
+        &self,
+    ) -> Result<Option<super::Impl<'value, dyn fmt::Debug>>, crate::Error> {
+        let r#type = self.schema.clone().into();
+        let vtable = super::vtable::impl_vtable(self.provider, &r#type, "core::fmt::Debug")?;
+        vtable
+            .map(|vtable| unsafe { super::vtable::fmt_impl(self.provider, self.value, vtable) })
+            .transpose()
+    }
+
+    /// This value's type's own [`Display`][fmt::Display] impl, if the program
+    /// contains a vtable for it.
+    ///
+    /// ## Safety
+    /// No other type of the program may share the path of this value's type
+    /// (as may, e.g., the types of semver-incompatible versions of a crate);
+    /// vtables are found by that path when the program has none for this
+    /// value's own DIE.
+    pub unsafe fn as_display(
+        &self,
+    ) -> Result<Option<super::Impl<'value, dyn fmt::Display>>, crate::Error> {
+        let r#type = self.schema.clone().into();
+        let vtable = super::vtable::impl_vtable(self.provider, &r#type, "core::fmt::Display")?;
+        vtable
+            .map(|vtable| unsafe { super::vtable::fmt_impl(self.provider, self.value, vtable) })
+            .transpose()
+    }
+
     /// The variant of this enum.
     pub fn variant(&self) -> Result<super::Variant<'value, 'dwarf, P>, crate::Error> {
         let mut default = None;
@@ -95,6 +141,13 @@ where
     P: crate::DebugInfoProvider,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if super::prefers_impls() {
+            // SAFETY: values are only displayed with impls by `WithImpls`,
+            // whose constructor upholds the invariant of `as_debug`.
+            if let Ok(Some(debug)) = unsafe { self.as_debug() } {
+                return fmt::Debug::fmt(&debug, f);
+            }
+        }
         self.schema().name().fmt(f)?;
         f.write_str("::")?;
         self.variant().map_err(crate::fmt_err)?.fmt(f)
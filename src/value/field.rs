@@ -7,6 +7,7 @@ where
 {
     schema: crate::schema::Field<'dwarf, P::Reader>,
     value: crate::Bytes<'value>,
+    metadata: Option<super::Metadata>,
     provider: &'dwarf P,
 }
 
@@ -22,10 +23,17 @@ where
         Self {
             schema,
             value,
+            metadata: None,
             provider,
         }
     }
 
+    /// Attaches the pointer `metadata` of this field, if it is the unsized
+    /// tail of a dynamically sized struct.
+    pub(crate) fn with_metadata(self, metadata: Option<super::Metadata>) -> Self {
+        Self { metadata, ..self }
+    }
+
     /// The schema of this field.
     pub fn schema(&self) -> &crate::schema::Field<'dwarf, P::Reader> {
         &self.schema
@@ -34,11 +42,34 @@ where
     /// The value of this field.
     pub fn value(&self) -> Result<super::Value<'value, 'dwarf, P>, crate::Error> {
         let schema = self.schema();
+        if schema.is_static()? {
+            return self.static_value();
+        }
+        if let Some(metadata) = self.metadata {
+            return unsafe { super::wide::tail(self.provider, schema, self.value, metadata) };
+        }
         let r#type = schema.r#type()?;
         let offset = schema.offset()?.address(0)? as usize;
         let value = &self.value[offset..];
         unsafe { super::Value::with_type(r#type, value, self.provider) }
     }
+
+    /// The value of this (C++) static member, which is stored apart from its
+    /// struct.
+    fn static_value(&self) -> Result<super::Value<'value, 'dwarf, P>, crate::Error> {
+        let schema = self.schema();
+        let Some(static_addr) = schema.static_address()? else {
+            bail!("static member `{}` has no storage", schema.name()?);
+        };
+        let address = self.provider.dynamic_address(schema.dwarf(), static_addr)?;
+        let address: usize = address.try_into()?;
+        let r#type = schema.r#type()?;
+        crate::safety::check_type(address, &r#type)?;
+        let size = r#type.size()?.try_into()?;
+        // static members live for the remainder of the program
+        let value = std::ptr::slice_from_raw_parts(address as *const crate::Byte, size);
+        unsafe { super::Value::with_type(r#type, &*value, self.provider) }
+    }
 }
 
 impl<'value, 'dwarf, P> fmt::Display for Field<'value, 'dwarf, P>
@@ -7,6 +7,7 @@ where
 {
     schema: crate::schema::Fields<'dwarf, P::Reader>,
     value: crate::Bytes<'value>,
+    metadata: Option<super::Metadata>,
     provider: &'dwarf P,
 }
 
@@ -22,10 +23,17 @@ where
         Self {
             schema,
             value,
+            metadata: None,
             provider,
         }
     }
 
+    /// Attaches the `metadata` of the wide pointer through which these
+    /// fields' dynamically sized struct was reached.
+    pub(crate) fn with_metadata(self, metadata: Option<super::Metadata>) -> Self {
+        Self { metadata, ..self }
+    }
+
     /// Produces an iterator over variants.
     pub fn iter<'tree>(
         &'tree mut self,
@@ -33,6 +41,7 @@ where
         Ok(FieldsIter {
             schema: self.schema.iter()?,
             value: self.value,
+            metadata: self.metadata,
             provider: self.provider,
         })
     }
@@ -45,6 +54,7 @@ where
 {
     schema: crate::schema::FieldsIter<'dwarf, 'tree, P::Reader>,
     value: crate::Bytes<'value>,
+    metadata: Option<super::Metadata>,
     provider: &'dwarf P,
 }
 
@@ -54,9 +64,15 @@ where
 {
     /// Produces the next field, if any.
     pub fn try_next(&mut self) -> Result<Option<super::Field<'value, 'dwarf, P>>, crate::Error> {
-        let Some(next) = self.schema.try_next()? else { return Ok(None) };
+        let Some(next) = self.schema.try_next()? else {
+            return Ok(None);
+        };
+        let metadata = match self.metadata {
+            Some(metadata) if next.is_unsized()? => Some(metadata),
+            _ => None,
+        };
         Ok(Some(unsafe {
-            super::field::Field::new(next, self.value, self.provider)
+            super::field::Field::new(next, self.value, self.provider).with_metadata(metadata)
         }))
     }
 }
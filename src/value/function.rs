@@ -5,9 +5,9 @@ pub struct Function<'value, 'dwarf, P = crate::DefaultProvider>
 where
     P: crate::DebugInfoProvider,
 {
-    _value: crate::Bytes<'value>,
+    value: crate::Bytes<'value>,
     schema: crate::schema::Function<'dwarf, P::Reader>,
-    _provider: &'dwarf P,
+    provider: &'dwarf P,
 }
 
 impl<'dwarf, R> crate::schema::Function<'dwarf, R>
@@ -24,8 +24,8 @@ where
     {
         Ok(Function {
             schema: self,
-            _value: value,
-            _provider: provider,
+            value,
+            provider,
         })
     }
 }
@@ -38,6 +38,37 @@ where
     pub fn schema(&self) -> &crate::schema::Function<'dwarf, P::Reader> {
         &self.schema
     }
+
+    /// The bytes of this value.
+    pub(crate) fn bytes(&self) -> crate::Bytes<'value> {
+        self.value
+    }
+
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
+
+    /// The code address of this function, if this value is a function
+    /// pointer.
+    pub fn address(&self) -> Option<u64> {
+        if !self.schema.is_pointer() {
+            return None;
+        }
+        if self.value.len() < std::mem::size_of::<usize>() {
+            return None;
+        }
+        let address = unsafe { (self.value.as_ptr() as *const usize).read_unaligned() };
+        Some(address as u64)
+    }
+
+    /// The symbol (name and source location) of this function, if this value
+    /// is a function pointer.
+    pub fn symbol(&self) -> Result<Option<crate::Symbol>, crate::Error> {
+        self.address()
+            .map(|address| crate::Symbol::resolve(self.provider, address))
+            .transpose()
+    }
 }
 
 impl<'value, 'dwarf, P> fmt::Debug for Function<'value, 'dwarf, P>
@@ -45,7 +76,10 @@ where
     P: crate::DebugInfoProvider,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("fn(){}")
+        let mut debug_struct = f.debug_struct("deflect::value::Function");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("value", &self.value);
+        debug_struct.finish()
     }
 }
 
@@ -54,7 +88,9 @@ where
     P: crate::DebugInfoProvider,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.schema.fmt(f)
+        match self.symbol() {
+            Ok(Some(symbol)) if symbol.name().is_some() => write!(f, "fn {symbol}"),
+            _ => self.schema.fmt(f),
+        }
     }
 }
-
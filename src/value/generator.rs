@@ -0,0 +1,156 @@
+use std::fmt;
+
+/// A reflected generator value; e.g., a suspended `async fn`.
+pub struct Generator<'value, 'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: crate::schema::Generator<'dwarf, P::Reader>,
+    value: crate::Bytes<'value>,
+    provider: &'dwarf P,
+}
+
+impl<'dwarf, R> crate::schema::Generator<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = std::primitive::usize>,
+{
+    pub(crate) unsafe fn with_bytes<'value, P>(
+        self,
+        provider: &'dwarf P,
+        value: crate::Bytes<'value>,
+    ) -> Result<Generator<'value, 'dwarf, P>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        let size = self.size()?.try_into()?;
+        let value = &value[..size];
+        Ok(Generator {
+            schema: self,
+            value,
+            provider,
+        })
+    }
+}
+
+impl<'value, 'dwarf, P> Generator<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// The schema of this value.
+    pub fn schema(&self) -> &crate::schema::Generator<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// The bytes of this value.
+    pub(crate) fn bytes(&self) -> crate::Bytes<'value> {
+        self.value
+    }
+
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
+
+    /// This generator, interpreted as an enum of its states.
+    pub fn as_enum(&self) -> Result<super::Enum<'value, 'dwarf, P>, crate::Error> {
+        let schema = self.schema.as_enum().clone();
+        unsafe { schema.with_bytes(self.provider, self.value) }
+    }
+
+    /// The current state of this generator; e.g., `Unresumed` or `Suspend0`.
+    pub fn state(&self) -> Result<super::Variant<'value, 'dwarf, P>, crate::Error> {
+        self.as_enum()?.variant()
+    }
+
+    /// The source location (file and line) of the yield point (e.g., the
+    /// `.await`) at which this generator is suspended, if any.
+    pub fn location(&self) -> Result<Option<(String, u64)>, crate::Error> {
+        let state = self.state()?;
+        if !is_suspended(&state)? {
+            return Ok(None);
+        }
+        let schema = state.schema();
+        let file = schema.file()?;
+        let line = schema.line()?;
+        Ok(file.zip(line).map(|(file, line)| (file.into_owned(), line)))
+    }
+
+    /// The locals of this generator that are live in its current state, under
+    /// their source names.
+    ///
+    /// Compiler-introduced temporaries (e.g., `__awaitee`) are omitted.
+    pub fn locals(&self) -> Result<Vec<super::Field<'value, 'dwarf, P>>, crate::Error> {
+        let state = self.state()?;
+        let mut fields = state.fields()?;
+        let mut fields = fields.iter()?;
+        let mut locals = vec![];
+        while let Some(field) = fields.try_next()? {
+            let name = field.schema().name()?;
+            if !name.to_slice()?.starts_with(b"__") {
+                locals.push(field);
+            }
+        }
+        Ok(locals)
+    }
+
+    /// The sub-future this generator is currently awaiting, if any.
+    pub fn awaitee(&self) -> Result<Option<super::Value<'value, 'dwarf, P>>, crate::Error> {
+        let state = self.state()?;
+        let mut fields = state.fields()?;
+        let mut fields = fields.iter()?;
+        while let Some(field) = fields.try_next()? {
+            if &*field.schema().name()?.to_slice()? == b"__awaitee" {
+                return field.value().map(Some);
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Whether `state` is a `SuspendN` state.
+fn is_suspended<'value, 'dwarf, P>(
+    state: &super::Variant<'value, 'dwarf, P>,
+) -> Result<bool, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    Ok(state.schema().name()?.to_slice()?.starts_with(b"Suspend"))
+}
+
+impl<'value, 'dwarf, P> fmt::Debug for Generator<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::Generator");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("value", &self.value);
+        debug_struct.finish()
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Display for Generator<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let function = self.schema().function().map_err(crate::fmt_err)?;
+        let state = self.state().map_err(crate::fmt_err)?;
+        let state = state.schema().name().map_err(crate::fmt_err)?;
+        let mut header = format!("{function}::{state}");
+        if let Some((file, line)) = self.location().map_err(crate::fmt_err)? {
+            header = format!("{header} @ {file}:{line}");
+        }
+        let mut debug_struct = f.debug_struct(&header);
+        for local in self.locals().map_err(crate::fmt_err)? {
+            let name = local.schema().name().map_err(crate::fmt_err)?;
+            let name = name.to_string_lossy().map_err(crate::fmt_err)?;
+            let value = local.value().map_err(crate::fmt_err)?;
+            debug_struct.field(&name, &crate::DebugDisplay(value));
+        }
+        if let Some(awaitee) = self.awaitee().map_err(crate::fmt_err)? {
+            debug_struct.field(".await", &crate::DebugDisplay(awaitee));
+        }
+        debug_struct.finish()
+    }
+}
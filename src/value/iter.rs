@@ -48,4 +48,13 @@ where
 
         Some(unsafe { super::Value::with_type(self.elt_type.clone(), elt, self.provider) })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length, Some(self.length))
+    }
+}
+
+impl<'value, 'dwarf, P> ExactSizeIterator for Iter<'value, 'dwarf, P> where
+    P: crate::DebugInfoProvider
+{
 }
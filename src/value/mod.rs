@@ -1,34 +1,70 @@
 //! Reflections of Rust values.
 
 mod array;
+mod bitfield;
 mod r#box;
 mod boxed_dyn;
 mod boxed_slice;
+mod builder;
+#[cfg(feature = "serde")]
+mod de;
 mod r#enum;
 mod field;
 mod fields;
 mod function;
+mod generator;
 mod iter;
+mod owned;
 mod pointer;
+mod qualified;
+pub(crate) mod referents;
+#[cfg(feature = "serde")]
+mod ser;
+#[cfg(feature = "serde")]
+mod serde_names;
 mod slice_impl;
 mod str_impl;
 mod r#struct;
+mod typedef;
+mod unsized_slice;
+mod unspecified;
+mod value_mut;
 mod variant;
+mod vector;
+mod visit;
+mod vtable;
+mod wide;
 
 pub use array::Array;
+pub use bitfield::Bitfield;
 pub use boxed_dyn::BoxedDyn;
 pub use boxed_slice::BoxedSlice;
+pub(crate) use builder::align_of;
+pub use builder::{Builder, Built, Slot};
+#[cfg(feature = "serde")]
+pub use de::Seed;
 pub use field::Field;
 pub use fields::{Fields, FieldsIter};
 pub use function::Function;
+pub use generator::Generator;
 pub use iter::Iter;
+pub use owned::OwnedValue;
 pub use pointer::Pointer;
+pub use qualified::Qualified;
 pub use r#box::Box;
 pub use r#enum::Enum;
 pub use r#struct::Struct;
 pub use slice_impl::Slice;
 pub use str_impl::str;
+pub use typedef::Typedef;
+pub use unsized_slice::UnsizedSlice;
+pub use unspecified::Unspecified;
+pub use value_mut::ValueMut;
 pub use variant::Variant;
+pub use vector::Vector;
+pub use visit::{Flow, Path, Segment, Visit};
+pub use vtable::{Impl, Vtable};
+pub use wide::Metadata;
 
 /// A reflected shared reference value.
 pub type SharedRef<'value, 'dwarf, P = crate::DefaultProvider> =
@@ -48,6 +84,183 @@ pub type MutPtr<'value, 'dwarf, P = crate::DefaultProvider> =
 
 pub use super::Value;
 
+impl<'value, 'dwarf, P> Value<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// This value's type's own [`Debug`][std::fmt::Debug] impl, if the
+    /// program contains a vtable for it.
+    ///
+    /// ## Safety
+    /// No other type of the program may share the path of this value's type
+    /// (as may, e.g., the types of semver-incompatible versions of a crate);
+    /// vtables are found by that path when the program has none for this
+    /// value's own DIE.
+    pub unsafe fn as_debug(
+        &self,
+    ) -> Result<Option<Impl<'value, dyn std::fmt::Debug>>, crate::Error> {
+        match self {
+            Self::Struct(value) => value.as_debug(),
+            Self::Enum(value) => value.as_debug(),
+            _ => Ok(None),
+        }
+    }
+
+    /// This value's type's own [`Display`][std::fmt::Display] impl, if the
+    /// program contains a vtable for it.
+    ///
+    /// ## Safety
+    /// No other type of the program may share the path of this value's type
+    /// (as may, e.g., the types of semver-incompatible versions of a crate);
+    /// vtables are found by that path when the program has none for this
+    /// value's own DIE.
+    pub unsafe fn as_display(
+        &self,
+    ) -> Result<Option<Impl<'value, dyn std::fmt::Display>>, crate::Error> {
+        match self {
+            Self::Struct(value) => value.as_display(),
+            Self::Enum(value) => value.as_display(),
+            _ => Ok(None),
+        }
+    }
+
+    /// Displays this value, preferring the [`Debug`][std::fmt::Debug] impls of
+    /// its (sub)values' types over their structural output, where the program
+    /// contains vtables for those impls.
+    ///
+    /// ## Safety
+    /// No other type of the program may share the path of this value's type,
+    /// or of the types of its (sub)values; see [`Value::as_debug`].
+    pub unsafe fn with_impls(&self) -> WithImpls<'_, 'value, 'dwarf, P> {
+        WithImpls(self)
+    }
+
+    /// This value as a `&T`.
+    ///
+    /// Fails if this value's type is not certainly `T`'s, or if this value is
+    /// not aligned like a `T` (e.g., if it is a field of a packed struct).
+    /// Types are identical if they are described by the same DIE, or are the
+    /// same primitive, or share their path, size and the names, offsets and
+    /// types of their fields.
+    pub fn downcast_ref<T>(&self) -> Result<&'value T, crate::Error>
+    where
+        T: 'static,
+    {
+        let Some((r#type, bytes, provider)) = self.parts()? else {
+            bail!("reflected values of this kind cannot be downcast");
+        };
+        let local_type_id =
+            <T as crate::Reflect>::local_type_id as *const () as std::primitive::usize;
+        let target = crate::type_of_local_id(provider, local_type_id)?;
+        if !value_mut::same_type(&r#type, &target)? || bytes.len() < std::mem::size_of::<T>() {
+            bail!(
+                "cannot downcast a `{}` to a `{}`",
+                value_mut::short_name(&r#type)?,
+                value_mut::short_name(&target)?
+            );
+        }
+        let pointer = bytes.as_ptr() as *const T;
+        if pointer as std::primitive::usize % std::mem::align_of::<T>() != 0 {
+            bail!("`{}` is misaligned", value_mut::short_name(&r#type)?);
+        }
+        // this value is an aligned `T`
+        Ok(unsafe { &*pointer })
+    }
+
+    /// The type, bytes and debug info provider of this value, if it occupies
+    /// memory of its own.
+    pub(crate) fn parts(&self) -> Result<Option<Parts<'value, 'dwarf, P>>, crate::Error> {
+        macro_rules! primitive {
+            ($value:expr) => {{
+                let size = std::mem::size_of_val($value.value());
+                let pointer = $value.value() as *const _ as *const crate::Byte;
+                let bytes = unsafe { std::slice::from_raw_parts(pointer, size) };
+                ($value.schema().clone().into(), bytes, $value.provider())
+            }};
+        }
+        macro_rules! bytes {
+            ($value:expr) => {
+                (
+                    $value.schema().clone().into(),
+                    $value.bytes(),
+                    $value.provider(),
+                )
+            };
+        }
+        Ok(Some(match self {
+            Self::bool(v) => primitive!(v),
+            Self::char(v) => primitive!(v),
+            Self::f32(v) => primitive!(v),
+            Self::f64(v) => primitive!(v),
+            Self::i8(v) => primitive!(v),
+            Self::i16(v) => primitive!(v),
+            Self::i32(v) => primitive!(v),
+            Self::i64(v) => primitive!(v),
+            Self::i128(v) => primitive!(v),
+            Self::isize(v) => primitive!(v),
+            Self::u8(v) => primitive!(v),
+            Self::u16(v) => primitive!(v),
+            Self::u32(v) => primitive!(v),
+            Self::u64(v) => primitive!(v),
+            Self::u128(v) => primitive!(v),
+            Self::usize(v) => primitive!(v),
+            Self::unit(v) => primitive!(v),
+            Self::Array(v) => bytes!(v),
+            Self::Box(v) => bytes!(v),
+            Self::BoxedSlice(v) => bytes!(v),
+            Self::BoxedDyn(v) => bytes!(v),
+            Self::Slice(v) => bytes!(v),
+            Self::Struct(v) => bytes!(v),
+            Self::Enum(v) => bytes!(v),
+            Self::Generator(v) => bytes!(v),
+            Self::Function(v) => bytes!(v),
+            Self::Unspecified(v) => bytes!(v),
+            Self::Vector(v) => bytes!(v),
+            Self::SharedRef(v) => bytes!(v),
+            Self::UniqueRef(v) => bytes!(v),
+            Self::ConstPtr(v) => bytes!(v),
+            Self::MutPtr(v) => bytes!(v),
+            Self::Typedef(v) => return v.value()?.parts(),
+            Self::Qualified(v) => return v.value()?.parts(),
+            Self::str(_) | Self::Bitfield(_) | Self::UnsizedSlice(_) => return Ok(None),
+        }))
+    }
+}
+
+/// The type, bytes and debug info provider of a [`Value`].
+type Parts<'value, 'dwarf, P> = (
+    crate::schema::Type<'dwarf, <P as crate::DebugInfoProvider>::Reader>,
+    crate::Bytes<'value>,
+    &'dwarf P,
+);
+
+/// Displays a [`Value`] using the [`Debug`][std::fmt::Debug] impls of its
+/// types, where available. See [`Value::with_impls`].
+pub struct WithImpls<'a, 'value, 'dwarf, P = crate::DefaultProvider>(&'a Value<'value, 'dwarf, P>)
+where
+    P: crate::DebugInfoProvider;
+
+thread_local! {
+    static PREFER_IMPLS: std::cell::Cell<std::primitive::bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Whether values are currently being displayed with [`WithImpls`].
+pub(crate) fn prefers_impls() -> std::primitive::bool {
+    PREFER_IMPLS.with(std::cell::Cell::get)
+}
+
+impl<'a, 'value, 'dwarf, P> std::fmt::Display for WithImpls<'a, 'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let prev = PREFER_IMPLS.with(|prefer| prefer.replace(true));
+        let result = self.0.fmt(f);
+        PREFER_IMPLS.with(|prefer| prefer.set(prev));
+        result
+    }
+}
+
 macro_rules! generate_primitive_conversions {
     ($t:ident) => {
         impl<'value, 'dwarf, P> From<$t<'value, 'dwarf, P>> for &'value std::primitive::$t
@@ -147,14 +360,14 @@ macro_rules! generate_primitive {
         {
             value: &'value std::primitive::$t,
             schema: crate::schema::$t<'dwarf, P::Reader>,
-            provider: std::marker::PhantomData<P>,
+            provider: &'dwarf P,
         }
 
         impl<'dwarf, R> crate::schema::$t<'dwarf, R>
         where
             R: crate::gimli::Reader<Offset = std::primitive::usize>
         {
-            pub(crate) unsafe fn with_bytes<'value, P>(self, _provider: &'dwarf P, bytes: crate::Bytes<'value>) -> Result<$t<'value, 'dwarf, P>, crate::Error>
+            pub(crate) unsafe fn with_bytes<'value, P>(self, provider: &'dwarf P, bytes: crate::Bytes<'value>) -> Result<$t<'value, 'dwarf, P>, crate::Error>
             where
                 P: crate::DebugInfoProvider<Reader = R>,
             {
@@ -166,7 +379,7 @@ macro_rules! generate_primitive {
                 Ok($t {
                     value,
                     schema: self,
-                    provider: std::marker::PhantomData,
+                    provider,
                 })
             }
         }
@@ -184,6 +397,11 @@ macro_rules! generate_primitive {
             pub fn value(&self) -> &'value std::primitive::$t {
                 self.value
             }
+
+            /// The debug info provider of this value.
+            pub(crate) fn provider(&self) -> &'dwarf P {
+                self.provider
+            }
         }
 
         impl<'value, 'dwarf, P> std::fmt::Debug for $t<'value, 'dwarf, P>
@@ -239,6 +457,7 @@ where
 {
     value: &'value (),
     schema: crate::schema::unit<'dwarf, P::Reader>,
+    provider: &'dwarf P,
 }
 
 impl<'dwarf, R> crate::schema::unit<'dwarf, R>
@@ -247,7 +466,7 @@ where
 {
     pub(crate) unsafe fn with_bytes<'value, P>(
         self,
-        _provider: &'dwarf P,
+        provider: &'dwarf P,
         bytes: crate::Bytes<'value>,
     ) -> Result<unit<'value, 'dwarf, P>, crate::Error>
     where
@@ -259,6 +478,7 @@ where
         Ok(unit {
             value,
             schema: self,
+            provider,
         })
     }
 }
@@ -276,6 +496,11 @@ where
     pub fn schema(&self) -> &crate::schema::unit<'dwarf, P::Reader> {
         &self.schema
     }
+
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
 }
 
 impl<'value, 'dwarf, P> std::fmt::Debug for unit<'value, 'dwarf, P>
@@ -0,0 +1,405 @@
+use std::fmt;
+
+use crate::schema::Type;
+
+/// An owned, schema-less snapshot of a reflected value.
+///
+/// Unlike a [`Value`][super::Value], a snapshot borrows neither the memory it
+/// was taken from nor the debug info that described it, and so may outlive
+/// both (and be sent to other threads). Snapshots are taken with
+/// [`Value::snapshot`][super::Value::snapshot].
+#[derive(Clone, Debug)]
+pub enum OwnedValue {
+    /// A [`bool`] (or boolean bitfield).
+    Bool(bool),
+    /// A [`char`].
+    Char(char),
+    /// A signed integer (or signed bitfield).
+    Int {
+        /// The name of the integer's type; e.g., `i32`.
+        type_name: String,
+        /// The value of the integer.
+        value: i128,
+    },
+    /// An unsigned integer (or unsigned bitfield).
+    UInt {
+        /// The name of the integer's type; e.g., `u32`.
+        type_name: String,
+        /// The value of the integer.
+        value: u128,
+    },
+    /// A floating-point number.
+    Float {
+        /// The name of the number's type; e.g., `f32`.
+        type_name: String,
+        /// The value of the number.
+        value: f64,
+    },
+    /// The [`()`][prim@unit] value.
+    Unit,
+    /// A `str` or `String`.
+    Str(String),
+    /// A struct.
+    Struct {
+        /// The name of the struct's type.
+        type_name: String,
+        /// The names and values of the struct's fields.
+        fields: Vec<(String, OwnedValue)>,
+    },
+    /// A variant of an enum (or state of a generator).
+    Enum {
+        /// The name of the enum's type.
+        type_name: String,
+        /// The name of the variant.
+        variant: String,
+        /// The names and values of the variant's fields.
+        fields: Vec<(String, OwnedValue)>,
+    },
+    /// An array, slice, SIMD vector or `Vec`.
+    Sequence {
+        /// The name of the sequence's type.
+        type_name: String,
+        /// The elements of the sequence.
+        elements: Vec<OwnedValue>,
+    },
+    /// A pointer, reference or box.
+    Pointer {
+        /// The name of the pointer's type.
+        type_name: String,
+        /// The address the pointer points to.
+        address: usize,
+        /// A snapshot of the pointer's referent, or `None` if the pointer was
+        /// not followed (because it is a raw pointer, or lies beyond the
+        /// snapshot's depth).
+        pointee: Option<std::boxed::Box<OwnedValue>>,
+    },
+    /// A function (pointer).
+    Function {
+        /// The name of the function's type.
+        type_name: String,
+        /// The code address of the function, if it was a function pointer.
+        address: Option<u64>,
+        /// The symbol the function's address resolves to, if any.
+        symbol: Option<crate::Symbol>,
+    },
+    /// A value of a type without a known layout.
+    Opaque {
+        /// The name of the value's type.
+        type_name: String,
+        /// The bytes of the value.
+        bytes: Vec<u8>,
+    },
+}
+
+impl OwnedValue {
+    /// The number of pointers followed by [`Value::snapshot`][super::Value::snapshot].
+    pub const DEFAULT_DEPTH: usize = 8;
+}
+
+impl<'value, 'dwarf, P> super::Value<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// Takes an owned snapshot of this value, following references and boxes
+    /// up to [`OwnedValue::DEFAULT_DEPTH`] pointers deep.
+    pub fn snapshot(&self) -> Result<OwnedValue, crate::Error> {
+        self.snapshot_with_depth(OwnedValue::DEFAULT_DEPTH)
+    }
+
+    /// Takes an owned snapshot of this value, following references and boxes
+    /// up to `depth` pointers deep. Raw pointers are never followed.
+    pub fn snapshot_with_depth(&self, depth: usize) -> Result<OwnedValue, crate::Error> {
+        snapshot(self, depth)
+    }
+}
+
+fn snapshot<'value, 'dwarf, P>(
+    value: &super::Value<'value, 'dwarf, P>,
+    depth: usize,
+) -> Result<OwnedValue, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    use super::Value;
+    macro_rules! int {
+        ($v:expr, $variant:ident, $t:ty) => {
+            OwnedValue::$variant {
+                type_name: stringify!($t).to_owned(),
+                value: (*$v.value()).into(),
+            }
+        };
+    }
+    Ok(match value {
+        Value::bool(v) => OwnedValue::Bool(*v.value()),
+        Value::char(v) => OwnedValue::Char(*v.value()),
+        Value::f32(v) => OwnedValue::Float {
+            type_name: "f32".to_owned(),
+            value: (*v.value()).into(),
+        },
+        Value::f64(v) => OwnedValue::Float {
+            type_name: "f64".to_owned(),
+            value: *v.value(),
+        },
+        Value::i8(v) => int!(v, Int, i8),
+        Value::i16(v) => int!(v, Int, i16),
+        Value::i32(v) => int!(v, Int, i32),
+        Value::i64(v) => int!(v, Int, i64),
+        Value::i128(v) => int!(v, Int, i128),
+        Value::isize(v) => OwnedValue::Int {
+            type_name: "isize".to_owned(),
+            value: *v.value() as i128,
+        },
+        Value::u8(v) => int!(v, UInt, u8),
+        Value::u16(v) => int!(v, UInt, u16),
+        Value::u32(v) => int!(v, UInt, u32),
+        Value::u64(v) => int!(v, UInt, u64),
+        Value::u128(v) => int!(v, UInt, u128),
+        Value::usize(v) => OwnedValue::UInt {
+            type_name: "usize".to_owned(),
+            value: *v.value() as u128,
+        },
+        Value::unit(_) => OwnedValue::Unit,
+        Value::str(v) => OwnedValue::Str(v.value().to_owned()),
+        Value::Array(v) => OwnedValue::Sequence {
+            type_name: type_name(v.schema())?,
+            elements: snapshot_iter(v.iter()?, depth)?,
+        },
+        Value::Vector(v) => OwnedValue::Sequence {
+            type_name: type_name(v.schema())?,
+            elements: snapshot_iter(v.iter()?, depth)?,
+        },
+        Value::UnsizedSlice(v) => OwnedValue::Sequence {
+            type_name: type_name(v.schema())?,
+            elements: snapshot_iter(v.iter()?, depth)?,
+        },
+        Value::Slice(v) => {
+            let elt_name = short_name(&v.schema().elt()?)?;
+            pointer(
+                type_name(v.schema())?,
+                v.data_ptr()?.as_ptr() as usize,
+                depth,
+                |depth| {
+                    Ok(OwnedValue::Sequence {
+                        type_name: format!("[{elt_name}]"),
+                        elements: snapshot_iter(v.iter()?, depth)?,
+                    })
+                },
+            )?
+        }
+        Value::BoxedSlice(v) => {
+            let elt_name = short_name(&v.schema().elt()?)?;
+            pointer(
+                type_name(v.schema())?,
+                v.data_ptr()?.as_ptr() as usize,
+                depth,
+                |depth| {
+                    Ok(OwnedValue::Sequence {
+                        type_name: format!("[{elt_name}]"),
+                        elements: snapshot_iter(v.iter()?, depth)?,
+                    })
+                },
+            )?
+        }
+        Value::Box(v) => pointer(type_name(v.schema())?, v.address(), depth, |depth| {
+            snapshot(&v.deref()?, depth)
+        })?,
+        Value::BoxedDyn(v) => pointer(type_name(v.schema())?, v.address()?, depth, |depth| {
+            snapshot(&v.deref()?, depth)
+        })?,
+        Value::SharedRef(v) => pointer(type_name(v.schema())?, v.address(), depth, |depth| {
+            snapshot(&v.deref()?, depth)
+        })?,
+        Value::UniqueRef(v) => pointer(type_name(v.schema())?, v.address(), depth, |depth| {
+            snapshot(&v.deref()?, depth)
+        })?,
+        Value::ConstPtr(v) => OwnedValue::Pointer {
+            type_name: type_name(v.schema())?,
+            address: v.address(),
+            pointee: None,
+        },
+        Value::MutPtr(v) => OwnedValue::Pointer {
+            type_name: type_name(v.schema())?,
+            address: v.address(),
+            pointee: None,
+        },
+        Value::Struct(v) => {
+            if let Some(string) = v.as_string()? {
+                OwnedValue::Str(string.to_owned())
+            } else if let Some(elements) = v.as_vec()? {
+                OwnedValue::Sequence {
+                    type_name: type_name(v.schema())?,
+                    elements: snapshot_iter(elements, depth)?,
+                }
+            } else {
+                OwnedValue::Struct {
+                    type_name: v.schema().display_name()?,
+                    fields: snapshot_fields(v.fields()?, depth)?,
+                }
+            }
+        }
+        Value::Enum(v) => snapshot_enum(v, depth)?,
+        Value::Generator(v) => snapshot_enum(&v.as_enum()?, depth)?,
+        Value::Function(v) => OwnedValue::Function {
+            type_name: type_name(v.schema())?,
+            address: v.address(),
+            symbol: v.symbol()?,
+        },
+        Value::Bitfield(v) => match v.schema().encoding()? {
+            Some(crate::gimli::DW_ATE_boolean) => OwnedValue::Bool(v.bits() != 0),
+            Some(crate::gimli::DW_ATE_signed | crate::gimli::DW_ATE_signed_char) => {
+                OwnedValue::Int {
+                    type_name: type_name(v.schema())?,
+                    value: v.as_i64().into(),
+                }
+            }
+            _ => OwnedValue::UInt {
+                type_name: type_name(v.schema())?,
+                value: v.bits().into(),
+            },
+        },
+        Value::Typedef(v) => snapshot(&v.value()?, depth)?,
+        Value::Qualified(v) => snapshot(&v.value()?, depth)?,
+        Value::Unspecified(v) => OwnedValue::Opaque {
+            type_name: type_name(v.schema())?,
+            bytes: v
+                .bytes()
+                .iter()
+                .map(|byte| unsafe { byte.assume_init() })
+                .collect(),
+        },
+    })
+}
+
+/// Snapshots the referent of a pointer (with `pointee`), if `depth` permits.
+fn pointer<F>(
+    type_name: String,
+    address: usize,
+    depth: usize,
+    pointee: F,
+) -> Result<OwnedValue, crate::Error>
+where
+    F: FnOnce(usize) -> Result<OwnedValue, crate::Error>,
+{
+    let pointee = match depth.checked_sub(1) {
+        Some(depth) => Some(std::boxed::Box::new(pointee(depth)?)),
+        None => None,
+    };
+    Ok(OwnedValue::Pointer {
+        type_name,
+        address,
+        pointee,
+    })
+}
+
+fn snapshot_enum<'value, 'dwarf, P>(
+    value: &super::Enum<'value, 'dwarf, P>,
+    depth: usize,
+) -> Result<OwnedValue, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    let variant = value.variant()?;
+    let variant_name = variant.schema().name()?.to_string_lossy()?.into_owned();
+    Ok(OwnedValue::Enum {
+        type_name: value.schema().name().to_string_lossy()?.into_owned(),
+        variant: variant_name,
+        fields: snapshot_fields(variant.fields()?, depth)?,
+    })
+}
+
+fn snapshot_fields<'value, 'dwarf, P>(
+    mut fields: super::Fields<'value, 'dwarf, P>,
+    depth: usize,
+) -> Result<Vec<(String, OwnedValue)>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    let mut snapshots = vec![];
+    let mut fields = fields.iter()?;
+    while let Some(field) = fields.try_next()? {
+        let name = field.schema().name()?.to_string_lossy()?.into_owned();
+        snapshots.push((name, snapshot(&field.value()?, depth)?));
+    }
+    Ok(snapshots)
+}
+
+fn snapshot_iter<'value, 'dwarf, P>(
+    elements: super::Iter<'value, 'dwarf, P>,
+    depth: usize,
+) -> Result<Vec<OwnedValue>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    elements.map(|element| snapshot(&element?, depth)).collect()
+}
+
+/// The name of the type described by `schema`.
+fn type_name<'dwarf, R, S>(schema: &S) -> Result<String, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize> + 'dwarf,
+    S: Clone + Into<Type<'dwarf, R>>,
+{
+    short_name(&schema.clone().into())
+}
+
+fn short_name<R>(r#type: &Type<'_, R>) -> Result<String, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    super::value_mut::short_name(r#type)
+}
+
+impl fmt::Display for OwnedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => value.fmt(f),
+            Self::Char(value) => value.fmt(f),
+            Self::Int { value, .. } => value.fmt(f),
+            Self::UInt { value, .. } => value.fmt(f),
+            Self::Float { value, .. } => value.fmt(f),
+            Self::Unit => f.write_str("()"),
+            Self::Str(value) => fmt::Debug::fmt(value, f),
+            Self::Struct { type_name, fields } => {
+                let mut debug_struct = f.debug_struct(type_name);
+                for (name, value) in fields {
+                    debug_struct.field(name, &crate::DebugDisplay(value));
+                }
+                debug_struct.finish()
+            }
+            Self::Enum {
+                type_name,
+                variant,
+                fields,
+            } => {
+                write!(f, "{type_name}::")?;
+                let mut debug_struct = f.debug_struct(variant);
+                for (name, value) in fields {
+                    debug_struct.field(name, &crate::DebugDisplay(value));
+                }
+                debug_struct.finish()
+            }
+            Self::Sequence { elements, .. } => {
+                let mut debug_list = f.debug_list();
+                for element in elements {
+                    debug_list.entry(&crate::DebugDisplay(element));
+                }
+                debug_list.finish()
+            }
+            Self::Pointer {
+                pointee: Some(pointee),
+                ..
+            } => write!(f, "&{pointee}"),
+            Self::Pointer {
+                type_name,
+                address,
+                pointee: None,
+            } => write!(f, "{address:#x} as {type_name}"),
+            Self::Function {
+                symbol: Some(symbol),
+                ..
+            } if symbol.name().is_some() => write!(f, "fn {symbol}"),
+            Self::Function { type_name, .. } => f.write_str(type_name),
+            Self::Opaque { type_name, .. } => write!(f, "<{type_name}>"),
+        }
+    }
+}
@@ -40,8 +40,12 @@ where
     where
         K: crate::schema::Reference,
     {
-        let value = unsafe { *(self.value.as_ptr() as *const *const crate::Byte) };
         let r#type = self.schema.r#type()?;
+        if let Some(wide) = self.schema.wide() {
+            return unsafe { super::wide::deref(self.provider, self.value, wide, r#type) };
+        }
+        let value = unsafe { *(self.value.as_ptr() as *const *const crate::Byte) };
+        crate::safety::check_type(value as usize, &r#type)?;
         let size = r#type.size()?;
         let size = size.try_into()?;
         let value = std::ptr::slice_from_raw_parts(value, size);
@@ -59,10 +63,36 @@ where
         &self.schema
     }
 
+    /// The bytes of this value.
+    pub(crate) fn bytes(&self) -> crate::Bytes<'value> {
+        self.value
+    }
+
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
+
+    /// The address this pointer points to.
+    pub fn address(&self) -> usize {
+        let offset = self.schema.wide().map_or(0, |wide| wide.pointer as usize);
+        unsafe { (self.value[offset..].as_ptr() as *const usize).read_unaligned() }
+    }
+
+    /// The metadata of this pointer, if it is a wide pointer to a dynamically
+    /// sized struct.
+    pub fn metadata(&self) -> Result<Option<super::Metadata>, crate::Error> {
+        let Some(wide) = self.schema.wide() else {
+            return Ok(None);
+        };
+        Ok(Some(super::wide::read(self.value, wide)?.1))
+    }
+
     /// The unreflected value behind this reference.
     pub(crate) fn deref_raw(&self) -> Result<crate::Bytes<'value>, crate::Error> {
         let value = unsafe { *(self.value.as_ptr() as *const *const crate::Byte) };
         let r#type = self.schema.r#type()?;
+        crate::safety::check_type(value as usize, &r#type)?;
         let size = r#type.size()?;
         let size = size.try_into()?;
         let value = std::ptr::slice_from_raw_parts(value, size);
@@ -70,9 +100,15 @@ where
         Ok(value)
     }
 
-    /// The unreflected value behind this reference.
-    pub(crate) fn deref_raw_dyn(&self, size: usize) -> Result<crate::Bytes<'value>, crate::Error> {
+    /// The unreflected value behind this reference, which spans `size` bytes
+    /// and is aligned to `align`.
+    pub(crate) fn deref_raw_dyn(
+        &self,
+        size: usize,
+        align: usize,
+    ) -> Result<crate::Bytes<'value>, crate::Error> {
         let value = unsafe { *(self.value.as_ptr() as *const *const crate::Byte) };
+        crate::safety::check(value as usize, size, align)?;
         let value = std::ptr::slice_from_raw_parts(value, size);
         let value = unsafe { &*value };
         Ok(value)
@@ -96,8 +132,12 @@ where
     P: crate::DebugInfoProvider,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self.deref() {
+            Ok(value) => value,
+            Err(err) => return crate::safety::display_invalid(err, f),
+        };
         f.write_str("&")?;
-        self.deref().map_err(crate::fmt_err)?.fmt(f)
+        super::referents::display(&value, f, true)
     }
 }
 
@@ -106,8 +146,12 @@ where
     P: crate::DebugInfoProvider,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self.deref() {
+            Ok(value) => value,
+            Err(err) => return crate::safety::display_invalid(err, f),
+        };
         f.write_str("&mut ")?;
-        self.deref().map_err(crate::fmt_err)?.fmt(f)
+        super::referents::display(&value, f, true)
     }
 }
 
@@ -116,9 +160,7 @@ where
     P: crate::DebugInfoProvider,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let value = self.deref_raw().map_err(crate::fmt_err)?;
-        let addr = value.as_ptr() as usize;
-        addr.fmt(f)?;
+        self.address().fmt(f)?;
         f.write_str(" as *const _")
     }
 }
@@ -128,10 +170,7 @@ where
     P: crate::DebugInfoProvider,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let value = self.deref_raw().map_err(crate::fmt_err)?;
-        let addr = value.as_ptr() as usize;
-        addr.fmt(f)?;
+        self.address().fmt(f)?;
         f.write_str(" as *mut _")
     }
 }
-
@@ -0,0 +1,82 @@
+use std::fmt;
+
+/// A value of a qualified type; e.g., `const T`.
+pub struct Qualified<'value, 'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: crate::schema::Qualified<'dwarf, P::Reader>,
+    value: crate::Bytes<'value>,
+    provider: &'dwarf P,
+}
+
+impl<'dwarf, R> crate::schema::Qualified<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = std::primitive::usize>,
+{
+    pub(crate) unsafe fn with_bytes<'value, P>(
+        self,
+        provider: &'dwarf P,
+        value: crate::Bytes<'value>,
+    ) -> Result<Qualified<'value, 'dwarf, P>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        // e.g., `const void`, which has no bytes
+        let size = match self.r#type()? {
+            Some(r#type) => r#type.size()?.try_into()?,
+            None => 0,
+        };
+        let Some(value) = value.get(..size) else {
+            bail!(
+                "value is {size} bytes, but only {} are available",
+                value.len()
+            )
+        };
+        Ok(Qualified {
+            schema: self,
+            value,
+            provider,
+        })
+    }
+}
+
+impl<'value, 'dwarf, P> Qualified<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// The schema of this value.
+    pub fn schema(&self) -> &crate::schema::Qualified<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// This value, as a value of the unqualified type.
+    pub fn value(&self) -> Result<super::Value<'value, 'dwarf, P>, crate::Error> {
+        let r#type = self
+            .schema
+            .r#type()?
+            .ok_or_else(|| crate::error::missing_attr(crate::gimli::DW_AT_type))?;
+        unsafe { super::Value::with_type(r#type, self.value, self.provider) }
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Debug for Qualified<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::Qualified");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("value", &self.value);
+        debug_struct.finish()
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Display for Qualified<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value().map_err(crate::fmt_err)?.fmt(f)
+    }
+}
@@ -0,0 +1,153 @@
+//! Detection of cyclic and aliased referents, so that value graphs containing
+//! them may be displayed (and traversed) in finite time and space.
+//!
+//! When a value is displayed, the referents reachable from it more than once
+//! are first found (see [`super::visit::shared_referents`]). The first time
+//! such a referent is displayed, it is labelled (e.g., `#1 = Node { .. }`);
+//! thereafter, it is displayed as a back-reference to that label (e.g.,
+//! `&#1`). Referents are only sought within a bounded number of pointers of
+//! the displayed value; beyond these, shared referents are displayed in full,
+//! and cycles are cut short as `..`.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Identifies a value by its address and the DIE of its type.
+pub(crate) type Key = (usize, usize, usize);
+
+impl<'value, 'dwarf, P> super::Value<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// The address and type of this value, if it occupies memory of its own
+    /// and is not zero-sized.
+    pub(crate) fn key(&self) -> Result<Option<Key>, crate::Error> {
+        if let Self::Typedef(_) | Self::Qualified(_) = self {
+            // these share the address of the value they wrap
+            return Ok(None);
+        }
+        let Some((r#type, bytes, _)) = self.parts()? else {
+            return Ok(None);
+        };
+        if r#type.size()? == 0 {
+            return Ok(None);
+        }
+        let (_, unit, offset) = r#type.die();
+        Ok(Some((
+            bytes.as_ptr() as usize,
+            unit as *const _ as usize,
+            offset.0,
+        )))
+    }
+
+    /// The value behind this reference or box, if it is one.
+    pub(crate) fn referent(&self) -> Result<Option<Self>, crate::Error> {
+        match self {
+            Self::Box(v) => v.deref().map(Some),
+            Self::BoxedDyn(v) => v.deref().map(Some),
+            Self::SharedRef(v) => v.deref().map(Some),
+            Self::UniqueRef(v) => v.deref().map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// The referents of the value being displayed on this thread.
+struct Referents {
+    /// Referents reachable more than once from the displayed value.
+    shared: HashSet<Key>,
+    /// The labels of the shared referents displayed so far.
+    labels: HashMap<Key, usize>,
+    /// Referents that are currently being displayed.
+    active: HashSet<Key>,
+}
+
+thread_local! {
+    static REFERENTS: RefCell<Option<Referents>> = const { RefCell::new(None) };
+}
+
+/// Clears the referents of this thread when the outermost display finishes.
+struct Outermost;
+
+impl Drop for Outermost {
+    fn drop(&mut self) {
+        REFERENTS.with(|referents| referents.borrow_mut().take());
+    }
+}
+
+/// Displays `value`, which is the referent of a reference or box if
+/// `is_referent`, labelling it if it is shared, and displaying it as a
+/// back-reference if it has been labelled already.
+pub(crate) fn display<'value, 'dwarf, P>(
+    value: &super::Value<'value, 'dwarf, P>,
+    f: &mut fmt::Formatter<'_>,
+    is_referent: bool,
+) -> fmt::Result
+where
+    P: crate::DebugInfoProvider,
+{
+    let is_outermost = REFERENTS.with(|referents| referents.borrow().is_none());
+    if !is_outermost && !is_referent {
+        return value.display_plain(f);
+    }
+    let _outermost = if is_outermost {
+        let shared = super::visit::shared_referents(value).map_err(crate::fmt_err)?;
+        REFERENTS.with(|referents| {
+            *referents.borrow_mut() = Some(Referents {
+                shared,
+                labels: HashMap::new(),
+                active: HashSet::new(),
+            })
+        });
+        Some(Outermost)
+    } else {
+        None
+    };
+    let Some(key) = value.key().map_err(crate::fmt_err)? else {
+        return value.display_plain(f);
+    };
+    let occurrence = REFERENTS.with(|referents| {
+        let mut referents = referents.borrow_mut();
+        let referents = referents.as_mut().expect("referents are tracked");
+        if let Some(&label) = referents.labels.get(&key) {
+            return Occurrence::Labelled(label);
+        }
+        if !referents.active.insert(key) {
+            return Occurrence::Active;
+        }
+        if !referents.shared.contains(&key) {
+            return Occurrence::First(None);
+        }
+        let label = referents.labels.len() + 1;
+        referents.labels.insert(key, label);
+        Occurrence::First(Some(label))
+    });
+    match occurrence {
+        Occurrence::Labelled(label) => write!(f, "#{label}"),
+        Occurrence::Active => f.write_str(".."),
+        Occurrence::First(label) => {
+            if let Some(label) = label {
+                write!(f, "#{label} = ")?;
+            }
+            let result = value.display_plain(f);
+            REFERENTS.with(|referents| {
+                if let Some(referents) = referents.borrow_mut().as_mut() {
+                    referents.active.remove(&key);
+                }
+            });
+            result
+        }
+    }
+}
+
+/// An occurrence of a referent while displaying a value.
+enum Occurrence {
+    /// The referent has been displayed already, with the given label.
+    Labelled(usize),
+    /// The referent is being displayed, but was not known to be shared.
+    Active,
+    /// The referent is displayed for the first time, with the given label if
+    /// it is shared.
+    First(Option<usize>),
+}
@@ -0,0 +1,541 @@
+//! Serialization of reflected values with [`serde`].
+//!
+//! Values are serialized as `#[derive(Serialize)]` would serialize them:
+//! structs, tuples and enum variants through the [`Serializer`] methods for
+//! their kinds, under the names of their types, variants and fields, arrays
+//! as tuples, and `Option`s as optional values. Slices and vectors are
+//! serialized as sequences, references and boxes as their referents, raw pointers as their
+//! addresses, `String`s and `Vec`s as strings and sequences, and values of
+//! unspecified types as units. Values whose referents are cyclic cannot be
+//! serialized.
+
+use super::serde_names;
+use serde::ser::{
+    Error as _, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+use serde::{Serialize, Serializer};
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+impl<'value, 'dwarf, P> Serialize for super::Value<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::bool(v) => v.serialize(serializer),
+            Self::char(v) => v.serialize(serializer),
+            Self::f32(v) => v.serialize(serializer),
+            Self::f64(v) => v.serialize(serializer),
+            Self::i8(v) => v.serialize(serializer),
+            Self::i16(v) => v.serialize(serializer),
+            Self::i32(v) => v.serialize(serializer),
+            Self::i64(v) => v.serialize(serializer),
+            Self::i128(v) => v.serialize(serializer),
+            Self::isize(v) => v.serialize(serializer),
+            Self::u8(v) => v.serialize(serializer),
+            Self::u16(v) => v.serialize(serializer),
+            Self::u32(v) => v.serialize(serializer),
+            Self::u64(v) => v.serialize(serializer),
+            Self::u128(v) => v.serialize(serializer),
+            Self::usize(v) => v.serialize(serializer),
+            Self::unit(v) => v.serialize(serializer),
+            Self::str(v) => v.serialize(serializer),
+            Self::Array(v) => v.serialize(serializer),
+            Self::Box(v) => v.serialize(serializer),
+            Self::BoxedSlice(v) => v.serialize(serializer),
+            Self::BoxedDyn(v) => v.serialize(serializer),
+            Self::Slice(v) => v.serialize(serializer),
+            Self::Struct(v) => v.serialize(serializer),
+            Self::Enum(v) => v.serialize(serializer),
+            Self::Generator(v) => v.serialize(serializer),
+            Self::Function(v) => v.serialize(serializer),
+            Self::Bitfield(v) => v.serialize(serializer),
+            Self::Typedef(v) => v.serialize(serializer),
+            Self::Qualified(v) => v.serialize(serializer),
+            Self::Unspecified(v) => v.serialize(serializer),
+            Self::UnsizedSlice(v) => v.serialize(serializer),
+            Self::Vector(v) => v.serialize(serializer),
+            Self::SharedRef(v) => v.serialize(serializer),
+            Self::UniqueRef(v) => v.serialize(serializer),
+            Self::ConstPtr(v) => v.serialize(serializer),
+            Self::MutPtr(v) => v.serialize(serializer),
+        }
+    }
+}
+
+macro_rules! serialize_primitive {
+    ($($t:ident,)*) => {
+        $(
+            impl<'value, 'dwarf, P> Serialize for super::$t<'value, 'dwarf, P>
+            where
+                P: crate::DebugInfoProvider,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    self.value().serialize(serializer)
+                }
+            }
+        )*
+    };
+}
+
+serialize_primitive! {
+    bool,
+    char,
+    f32,
+    f64,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    unit,
+    str,
+}
+
+macro_rules! serialize_seq {
+    ($($t:ident,)*) => {
+        $(
+            impl<'value, 'dwarf, P> Serialize for super::$t<'value, 'dwarf, P>
+            where
+                P: crate::DebugInfoProvider,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serialize_iter(self.iter().map_err(S::Error::custom)?, serializer)
+                }
+            }
+        )*
+    };
+}
+
+serialize_seq! {
+    BoxedSlice,
+    Slice,
+    UnsizedSlice,
+    Vector,
+}
+
+/// Arrays are serialized as tuples, as their lengths are part of their types.
+impl<'value, 'dwarf, P> Serialize for super::Array<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let elements = self.iter().map_err(S::Error::custom)?;
+        let mut tuple = serializer.serialize_tuple(elements.len())?;
+        for element in elements {
+            tuple.serialize_element(&element.map_err(S::Error::custom)?)?;
+        }
+        tuple.end()
+    }
+}
+
+macro_rules! serialize_deref {
+    ($($t:ident::$method:ident,)*) => {
+        $(
+            impl<'value, 'dwarf, P> Serialize for super::$t<'value, 'dwarf, P>
+            where
+                P: crate::DebugInfoProvider,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    self.$method().map_err(S::Error::custom)?.serialize(serializer)
+                }
+            }
+        )*
+    };
+}
+
+serialize_deref! {
+    Typedef::value,
+    Qualified::value,
+    Field::value,
+    Generator::as_enum,
+}
+
+macro_rules! serialize_referent {
+    ($($t:ident,)*) => {
+        $(
+            impl<'value, 'dwarf, P> Serialize for super::$t<'value, 'dwarf, P>
+            where
+                P: crate::DebugInfoProvider,
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serialize_referent(&self.deref().map_err(S::Error::custom)?, serializer)
+                }
+            }
+        )*
+    };
+}
+
+serialize_referent! {
+    Box,
+    BoxedDyn,
+}
+
+impl<'value, 'dwarf, K, P> Serialize for super::Pointer<'value, 'dwarf, K, P>
+where
+    K: crate::schema::Reference,
+    P: crate::DebugInfoProvider,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_referent(&self.deref().map_err(S::Error::custom)?, serializer)
+    }
+}
+
+thread_local! {
+    /// The referents that are currently being serialized on this thread.
+    static ACTIVE: RefCell<HashSet<super::referents::Key>> = RefCell::new(HashSet::new());
+}
+
+/// Removes a referent from [`ACTIVE`] once it has been serialized.
+struct Active(super::referents::Key);
+
+impl Drop for Active {
+    fn drop(&mut self) {
+        ACTIVE.with(|active| active.borrow_mut().remove(&self.0));
+    }
+}
+
+/// Serializes `value`, the referent of a reference or box, failing if it is
+/// already being serialized; i.e., if it is reachable from itself.
+fn serialize_referent<P, S>(
+    value: &super::Value<'_, '_, P>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    P: crate::DebugInfoProvider,
+    S: Serializer,
+{
+    let Some(key) = referent_key(value).map_err(S::Error::custom)? else {
+        return value.serialize(serializer);
+    };
+    if !ACTIVE.with(|active| active.borrow_mut().insert(key)) {
+        return Err(S::Error::custom("cannot serialize a cyclic value"));
+    }
+    let _active = Active(key);
+    value.serialize(serializer)
+}
+
+impl<'value, 'dwarf, P> Serialize for super::ConstPtr<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.address().serialize(serializer)
+    }
+}
+
+impl<'value, 'dwarf, P> Serialize for super::MutPtr<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.address().serialize(serializer)
+    }
+}
+
+impl<'value, 'dwarf, P> Serialize for super::Struct<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(string) = self.as_string().map_err(S::Error::custom)? {
+            return serializer.serialize_str(string);
+        }
+        if let Some(elements) = self.as_vec().map_err(S::Error::custom)? {
+            return serialize_iter(elements, serializer);
+        }
+        let name = self.schema().name().map_err(S::Error::custom)?;
+        let name = name.to_string_lossy().map_err(S::Error::custom)?;
+        let container = if serde_names::is_tuple(&name) {
+            Container::Tuple
+        } else {
+            Container::Struct(serde_names::type_name(&name))
+        };
+        serialize_fields(
+            container,
+            self.fields().map_err(S::Error::custom)?,
+            serializer,
+        )
+    }
+}
+
+/// Enums are serialized as externally tagged variants, and `Option`s as
+/// optional values.
+impl<'value, 'dwarf, P> Serialize for super::Enum<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let variant = self.variant().map_err(S::Error::custom)?;
+        let variant_name = variant.schema().name().map_err(S::Error::custom)?;
+        let variant_name = variant_name.to_string_lossy().map_err(S::Error::custom)?;
+        if self.schema().is_option().map_err(S::Error::custom)? {
+            let mut fields = variant.fields().map_err(S::Error::custom)?;
+            let mut fields = fields.iter().map_err(S::Error::custom)?;
+            return match fields.try_next().map_err(S::Error::custom)? {
+                Some(value) => serializer.serialize_some(&value),
+                None => serializer.serialize_none(),
+            };
+        }
+        let mut index = 0u32;
+        let mut variants = self.schema().variants().map_err(S::Error::custom)?;
+        let mut variants = variants.iter().map_err(S::Error::custom)?;
+        while let Some(candidate) = variants.try_next().map_err(S::Error::custom)? {
+            let candidate = candidate.name().map_err(S::Error::custom)?;
+            if *candidate.to_slice().map_err(S::Error::custom)? == *variant_name.as_bytes() {
+                break;
+            }
+            index += 1;
+        }
+        let name = self
+            .schema()
+            .name()
+            .to_string_lossy()
+            .map_err(S::Error::custom)?;
+        let container = Container::Variant {
+            name: serde_names::type_name(&name),
+            index,
+            variant: serde_names::intern(&variant_name),
+        };
+        serialize_fields(
+            container,
+            variant.fields().map_err(S::Error::custom)?,
+            serializer,
+        )
+    }
+}
+
+/// Variants are serialized like structs named after them; see
+/// [`Enum`][super::Enum] for how they are serialized within their enums.
+impl<'value, 'dwarf, P> Serialize for super::Variant<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let name = self.schema().name().map_err(S::Error::custom)?;
+        let name = name.to_string_lossy().map_err(S::Error::custom)?;
+        let container = Container::Struct(serde_names::intern(&name));
+        serialize_fields(
+            container,
+            self.fields().map_err(S::Error::custom)?,
+            serializer,
+        )
+    }
+}
+
+impl<'value, 'dwarf, P> Serialize for super::Function<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.symbol().map_err(S::Error::custom)? {
+            Some(symbol) => serializer.collect_str(&symbol),
+            None => self.address().serialize(serializer),
+        }
+    }
+}
+
+impl<'value, 'dwarf, P> Serialize for super::Bitfield<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.schema().is_signed().map_err(S::Error::custom)? {
+            serializer.serialize_i64(self.as_i64())
+        } else {
+            serializer.serialize_u64(self.as_u64())
+        }
+    }
+}
+
+/// The bytes of values of unspecified types may be uninitialized (e.g., if they
+/// are padding), so these values are serialized as opaque units.
+impl<'value, 'dwarf, P> Serialize for super::Unspecified<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_unit()
+    }
+}
+
+/// What a list of fields belongs to.
+#[derive(Clone, Copy)]
+enum Container {
+    /// A tuple, which is unnamed.
+    Tuple,
+    /// A struct of the given name.
+    Struct(&'static str),
+    /// A variant of an enum.
+    Variant {
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+    },
+}
+
+/// Serializes `elements` as a sequence.
+fn serialize_iter<'value, 'dwarf, P, S>(
+    elements: super::Iter<'value, 'dwarf, P>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    P: crate::DebugInfoProvider,
+    S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(elements.len()))?;
+    for element in elements {
+        seq.serialize_element(&element.map_err(S::Error::custom)?)?;
+    }
+    seq.end()
+}
+
+/// Serializes `fields`, the fields of `container`, as a unit, if there are
+/// none; as the only field, if they are the fields of a newtype; as a tuple,
+/// if they are the fields of a tuple, tuple struct or tuple variant; and
+/// otherwise as a struct.
+fn serialize_fields<'value, 'dwarf, P, S>(
+    container: Container,
+    mut fields: super::Fields<'value, 'dwarf, P>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    P: crate::DebugInfoProvider,
+    S: Serializer,
+{
+    let mut names = vec![];
+    let mut values = vec![];
+    let mut fields = fields.iter().map_err(S::Error::custom)?;
+    while let Some(field) = fields.try_next().map_err(S::Error::custom)? {
+        let name = field.schema().name().map_err(S::Error::custom)?;
+        let name = name
+            .to_string_lossy()
+            .map_err(S::Error::custom)?
+            .into_owned();
+        names.push(name);
+        values.push(field);
+    }
+    let len = values.len();
+    let is_positional = serde_names::are_positional(&names);
+    match container {
+        Container::Tuple => {
+            let mut tuple = serializer.serialize_tuple(len)?;
+            for value in &values {
+                tuple.serialize_element(value)?;
+            }
+            tuple.end()
+        }
+        Container::Struct(name) => match &values[..] {
+            [] => serializer.serialize_unit_struct(name),
+            [value] if is_positional => serializer.serialize_newtype_struct(name, value),
+            _ if is_positional => {
+                let mut tuple = serializer.serialize_tuple_struct(name, len)?;
+                for value in &values {
+                    tuple.serialize_field(value)?;
+                }
+                tuple.end()
+            }
+            _ => {
+                let mut r#struct = serializer.serialize_struct(name, len)?;
+                for (field, value) in names.iter().zip(&values) {
+                    r#struct.serialize_field(serde_names::intern(field), value)?;
+                }
+                r#struct.end()
+            }
+        },
+        Container::Variant {
+            name,
+            index,
+            variant,
+        } => match &values[..] {
+            [] => serializer.serialize_unit_variant(name, index, variant),
+            [value] if is_positional => {
+                serializer.serialize_newtype_variant(name, index, variant, value)
+            }
+            _ if is_positional => {
+                let mut tuple = serializer.serialize_tuple_variant(name, index, variant, len)?;
+                for value in &values {
+                    tuple.serialize_field(value)?;
+                }
+                tuple.end()
+            }
+            _ => {
+                let mut r#struct =
+                    serializer.serialize_struct_variant(name, index, variant, len)?;
+                for (field, value) in names.iter().zip(&values) {
+                    r#struct.serialize_field(serde_names::intern(field), value)?;
+                }
+                r#struct.end()
+            }
+        },
+    }
+}
+
+/// The key of `value`, or of the value that it wraps, if it is a typedef or
+/// qualified type, which share the address of the value they wrap.
+fn referent_key<P>(
+    value: &super::Value<'_, '_, P>,
+) -> Result<Option<super::referents::Key>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    match value {
+        super::Value::Typedef(v) => referent_key(&v.value()?),
+        super::Value::Qualified(v) => referent_key(&v.value()?),
+        value => value.key(),
+    }
+}
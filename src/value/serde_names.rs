@@ -0,0 +1,48 @@
+//! The `&'static str` names that [`serde`] expects of types, variants and
+//! fields.
+//!
+//! Reflected names are borrowed from debug info, so each distinct name (and
+//! list of names) is leaked once, and reused thereafter.
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+
+/// `name`, as a `&'static str`.
+pub(crate) fn intern(name: &str) -> &'static str {
+    static NAMES: Lazy<DashMap<String, &'static str>> = Lazy::new(DashMap::new);
+    if let Some(interned) = NAMES.get(name) {
+        return *interned;
+    }
+    *NAMES
+        .entry(name.to_owned())
+        .or_insert_with(|| Box::leak(name.to_owned().into_boxed_str()))
+}
+
+/// `names`, as a `&'static [&'static str]`.
+pub(crate) fn intern_all(names: &[String]) -> &'static [&'static str] {
+    static LISTS: Lazy<DashMap<Vec<String>, &'static [&'static str]>> = Lazy::new(DashMap::new);
+    if let Some(interned) = LISTS.get(names) {
+        return *interned;
+    }
+    *LISTS.entry(names.to_vec()).or_insert_with(|| {
+        let interned: Vec<_> = names.iter().map(|name| intern(name)).collect();
+        Box::leak(interned.into_boxed_slice())
+    })
+}
+
+/// The name `#[derive(Serialize)]` gives to the type named `name`; i.e., its
+/// name without generic arguments.
+pub(crate) fn type_name(name: &str) -> &'static str {
+    intern(name.split('<').next().unwrap_or(name))
+}
+
+/// Whether the type named `name` is a tuple, which `serde` does not name.
+pub(crate) fn is_tuple(name: &str) -> bool {
+    name.starts_with('(')
+}
+
+/// Whether fields named `names` are those of a tuple struct or variant, which
+/// rustc names `__0`, `__1`, etc.
+pub(crate) fn are_positional(names: &[String]) -> bool {
+    names.iter().all(|name| name.starts_with("__"))
+}
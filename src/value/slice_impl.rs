@@ -39,12 +39,29 @@ where
         &self.schema
     }
 
+    /// The bytes of this value.
+    pub(crate) fn bytes(&self) -> crate::Bytes<'value> {
+        self.value
+    }
+
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
+
     /// The value of the `data_ptr` field of this slice.
     pub fn data_ptr(&self) -> Result<crate::Bytes<'value>, crate::Error> {
         let field =
             unsafe { super::Field::new(self.schema.data_ptr().clone(), self.value, self.provider) };
         let value = field.value()?;
         let value: super::Pointer<crate::schema::Mut, _> = value.try_into()?;
+        let elt_type = self.schema.elt()?;
+        let elt_size = usize::try_from(elt_type.size()?)?;
+        let size = elt_size
+            .checked_mul(self.length()?)
+            .ok_or_else(crate::error::arithmetic_overflow)?;
+        let align = super::align_of(&elt_type).map_or(Ok(1), usize::try_from)?;
+        crate::safety::check(value.address(), size, align)?;
         let ptr = value.deref_raw()?;
         Ok(ptr)
     }
@@ -92,9 +109,13 @@ where
     P: crate::DebugInfoProvider,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elts = match self.iter() {
+            Ok(elts) => elts,
+            Err(err) => return crate::safety::display_invalid(err, f),
+        };
         f.write_str("&")?;
         let mut debug_list = f.debug_list();
-        for maybe_elt in self.iter().map_err(crate::fmt_err)? {
+        for maybe_elt in elts {
             let elt = maybe_elt.map_err(crate::fmt_err)?;
             debug_list.entry(&crate::DebugDisplay(elt));
         }
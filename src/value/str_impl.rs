@@ -26,11 +26,12 @@ where
         let data_ptr = unsafe { super::Field::new(self.data_ptr().clone(), value, provider) };
         let data_ptr = data_ptr.value()?;
         let data_ptr: super::Pointer<crate::schema::Mut, _> = data_ptr.try_into()?;
-        let data = data_ptr.deref_raw()?.as_ptr();
+        let data = data_ptr.address() as *const crate::Byte;
 
         let length = unsafe { super::Field::new(self.length().clone(), value, provider) };
         let length = length.value()?;
         let length = length.try_into()?;
+        crate::safety::check(data as usize, length, 1)?;
 
         let value = std::ptr::slice_from_raw_parts(data, length);
         let value = unsafe { &*(value as *const std::primitive::str) };
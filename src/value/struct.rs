@@ -7,6 +7,7 @@ where
 {
     schema: crate::schema::Struct<'dwarf, P::Reader>,
     value: crate::Bytes<'value>,
+    metadata: Option<super::Metadata>,
     provider: &'dwarf P,
 }
 
@@ -25,6 +26,26 @@ where
         Ok(Struct {
             schema: self,
             value,
+            metadata: None,
+            provider,
+        })
+    }
+
+    /// Safety: `value` must be a valid struct of this type, reached through a
+    /// wide pointer with metadata `metadata`.
+    pub(crate) unsafe fn with_metadata<'value, P>(
+        self,
+        provider: &'dwarf P,
+        value: crate::Bytes<'value>,
+        metadata: super::Metadata,
+    ) -> Result<Struct<'value, 'dwarf, P>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        Ok(Struct {
+            schema: self,
+            value,
+            metadata: Some(metadata),
             provider,
         })
     }
@@ -39,8 +60,79 @@ where
         &self.schema
     }
 
+    /// The bytes of this value.
+    pub(crate) fn bytes(&self) -> crate::Bytes<'value> {
+        self.value
+    }
+
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
+
+    /// The metadata of the wide pointer through which this dynamically sized
+    /// struct was reached, if any.
+    pub fn metadata(&self) -> Option<super::Metadata> {
+        self.metadata
+    }
+
+    /// The size of this struct, in bytes, including its unsized tail (if
+    /// any).
+    pub fn size(&self) -> Result<u64, crate::Error> {
+        match self.metadata {
+            Some(metadata) => Ok(super::wide::layout(&self.schema, metadata)?.size),
+            None => self.schema.size(),
+        }
+    }
+
+    /// This value's type's own [`Debug`][fmt::Debug] impl, if the program
+    /// contains a vtable for it.
+    ///
+    /// ## Safety
+    /// No other type of the program may share the path of this value's type
+    /// (as may, e.g., the types of semver-incompatible versions of a crate);
+    /// vtables are found by that path when the program has none for this
+    /// value's own DIE.
+    pub unsafe fn as_debug(
+        &self,
+    ) -> Result<Option<super::Impl<'value, dyn fmt::Debug>>, crate::Error> {
+        if self.metadata.is_some() {
+            // the impls of dynamically sized structs take wide pointers
+            return Ok(None);
+        }
+        let r#type = self.schema.clone().into();
+        let vtable = super::vtable::impl_vtable(self.provider, &r#type, "core::fmt::Debug")?;
+        vtable
+            .map(|vtable| unsafe { super::vtable::fmt_impl(self.provider, self.value, vtable) })
+            .transpose()
+    }
+
+    /// This value's type's own [`Display`][fmt::Display] impl, if the program
+    /// contains a vtable for it.
+    ///
+    /// ## Safety
+    /// No other type of the program may share the path of this value's type
+    /// (as may, e.g., the types of semver-incompatible versions of a crate);
+    /// vtables are found by that path when the program has none for this
+    /// value's own DIE.
+    pub unsafe fn as_display(
+        &self,
+    ) -> Result<Option<super::Impl<'value, dyn fmt::Display>>, crate::Error> {
+        if self.metadata.is_some() {
+            return Ok(None);
+        }
+        let r#type = self.schema.clone().into();
+        let vtable = super::vtable::impl_vtable(self.provider, &r#type, "core::fmt::Display")?;
+        vtable
+            .map(|vtable| unsafe { super::vtable::fmt_impl(self.provider, self.value, vtable) })
+            .transpose()
+    }
+
     /// Get a field of this struct by name.
-    pub fn field<N>(&self, field_name: N) -> Result<Option<super::Field<'value, 'dwarf, P>>, crate::Error>
+    pub fn field<N>(
+        &self,
+        field_name: N,
+    ) -> Result<Option<super::Field<'value, 'dwarf, P>>, crate::Error>
     where
         N: AsRef<[u8]>,
     {
@@ -60,7 +152,110 @@ where
     /// The fields of this struct.
     pub fn fields(&self) -> Result<super::Fields<'value, 'dwarf, P>, crate::Error> {
         let fields = self.schema.fields()?;
-        Ok(super::Fields::new(fields, self.value, self.provider))
+        Ok(super::Fields::new(fields, self.value, self.provider).with_metadata(self.metadata))
+    }
+
+    /// The static members of this (C++) struct or class.
+    pub fn static_fields(&self) -> Result<Vec<super::Field<'value, 'dwarf, P>>, crate::Error> {
+        let statics = self.schema.static_fields()?;
+        Ok(statics
+            .into_iter()
+            .map(|schema| unsafe { super::Field::new(schema, self.value, self.provider) })
+            .collect())
+    }
+
+    /// This struct as a `&str`, if it is an `alloc::string::String`.
+    pub(crate) fn as_string(&self) -> Result<Option<&'value str>, crate::Error> {
+        // check the name first, as computing the path is comparatively costly
+        let Some(name) = self.schema.name_opt()? else {
+            return Ok(None);
+        };
+        if &*name.to_slice()? != b"String" || self.schema.path()? != "alloc::string::String" {
+            return Ok(None);
+        }
+        let Some(super::Value::Struct(vec)) =
+            self.field("vec")?.map(|vec| vec.value()).transpose()?
+        else {
+            return Ok(None);
+        };
+        let Some((address, len)) = vec.vec_parts()? else {
+            return Ok(None);
+        };
+        crate::safety::check(address, len, 1)?;
+        let bytes = unsafe { std::slice::from_raw_parts(address as *const u8, len) };
+        Ok(Some(std::str::from_utf8(bytes)?))
+    }
+
+    /// The elements of this struct, if it is an `alloc::vec::Vec<T>`.
+    pub(crate) fn as_vec(&self) -> Result<Option<super::Iter<'value, 'dwarf, P>>, crate::Error> {
+        // check the name first, as computing the path is comparatively costly
+        let Some(name) = self.schema.name_opt()? else {
+            return Ok(None);
+        };
+        if !name.to_slice()?.starts_with(b"Vec<")
+            || !self.schema.path()?.starts_with("alloc::vec::Vec<")
+        {
+            return Ok(None);
+        }
+        let generics = self.schema.generics()?;
+        let mut types = generics.iter().filter_map(|generic| generic.as_type());
+        let Some(elt_type) = types.next().cloned() else {
+            return Ok(None);
+        };
+        let Some((address, len)) = self.vec_parts()? else {
+            return Ok(None);
+        };
+        let elt_size: usize = elt_type.size()?.try_into()?;
+        let bytes = elt_size
+            .checked_mul(len)
+            .ok_or_else(crate::error::arithmetic_overflow)?;
+        let align = super::align_of(&elt_type).map_or(Ok(1), usize::try_from)?;
+        crate::safety::check(address, bytes, align)?;
+        let value = std::ptr::slice_from_raw_parts(address as *const crate::Byte, bytes);
+        let value = unsafe { &*value };
+        Ok(Some(unsafe {
+            super::Iter::new(value, elt_size, elt_type, len, self.provider)
+        }))
+    }
+
+    /// The address of the buffer and the length of this `alloc::vec::Vec`,
+    /// read from its `buf` and `len` fields.
+    fn vec_parts(&self) -> Result<Option<(usize, usize)>, crate::Error> {
+        let Some(super::Value::usize(len)) =
+            self.field("len")?.map(|len| len.value()).transpose()?
+        else {
+            return Ok(None);
+        };
+        let Some(buf) = self.field("buf")? else {
+            return Ok(None);
+        };
+        let Some(address) = first_address(&buf.value()?)? else {
+            return Ok(None);
+        };
+        Ok(Some((address, *len.value())))
+    }
+}
+
+/// The address of the first raw pointer within `value`, searching its fields
+/// depth-first; e.g., that of the `NonNull` within a `RawVec`.
+fn first_address<P>(value: &super::Value<'_, '_, P>) -> Result<Option<usize>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    match value {
+        super::Value::ConstPtr(pointer) => Ok(Some(pointer.address())),
+        super::Value::MutPtr(pointer) => Ok(Some(pointer.address())),
+        super::Value::Struct(value) => {
+            let mut fields = value.fields()?;
+            let mut fields = fields.iter()?;
+            while let Some(field) = fields.try_next()? {
+                if let Some(address) = first_address(&field.value()?)? {
+                    return Ok(Some(address));
+                }
+            }
+            Ok(None)
+        }
+        _ => Ok(None),
     }
 }
 
@@ -72,6 +267,7 @@ where
         let mut debug_struct = f.debug_struct("deflect::value::Struct");
         debug_struct.field("schema", &self.schema);
         debug_struct.field("value", &self.value);
+        debug_struct.field("metadata", &self.metadata);
         debug_struct.finish()
     }
 }
@@ -81,17 +277,28 @@ where
     P: crate::DebugInfoProvider,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if super::prefers_impls() {
+            // SAFETY: values are only displayed with impls by `WithImpls`,
+            // whose constructor upholds the invariant of `as_debug`.
+            if let Ok(Some(debug)) = unsafe { self.as_debug() } {
+                return fmt::Debug::fmt(&debug, f);
+            }
+        }
         let schema = self.schema();
-        let type_name = schema.name().map_err(crate::fmt_err)?;
-        let type_name = type_name.to_string_lossy().map_err(crate::fmt_err)?;
+        let type_name = schema.display_name().map_err(crate::fmt_err)?;
         let mut debug_struct = f.debug_struct(&type_name);
         let mut fields = self.fields().map_err(crate::fmt_err)?;
         let mut fields = fields.iter().map_err(crate::fmt_err)?;
         while let Some(field) = fields.try_next().map_err(crate::fmt_err)? {
             let field_name = field.schema().name().map_err(crate::fmt_err)?;
             let field_name = field_name.to_string_lossy().map_err(crate::fmt_err)?;
-            let field_value = field.value().map_err(crate::fmt_err)?;
-            debug_struct.field(&field_name, &crate::DebugDisplay(field_value));
+            match field.value() {
+                Ok(value) => debug_struct.field(&field_name, &crate::DebugDisplay(value)),
+                Err(err) => {
+                    let invalid = crate::safety::Invalid::from_err(err)?;
+                    debug_struct.field(&field_name, &crate::DebugDisplay(invalid))
+                }
+            };
         }
         debug_struct.finish()
     }
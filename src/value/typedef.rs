@@ -0,0 +1,75 @@
+use std::fmt;
+
+/// A value of a type alias.
+pub struct Typedef<'value, 'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: crate::schema::Typedef<'dwarf, P::Reader>,
+    value: crate::Bytes<'value>,
+    provider: &'dwarf P,
+}
+
+impl<'dwarf, R> crate::schema::Typedef<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = std::primitive::usize>,
+{
+    pub(crate) unsafe fn with_bytes<'value, P>(
+        self,
+        provider: &'dwarf P,
+        value: crate::Bytes<'value>,
+    ) -> Result<Typedef<'value, 'dwarf, P>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        let size = self.size()?.try_into()?;
+        let Some(value) = value.get(..size) else {
+            bail!(
+                "value is {size} bytes, but only {} are available",
+                value.len()
+            )
+        };
+        Ok(Typedef {
+            schema: self,
+            value,
+            provider,
+        })
+    }
+}
+
+impl<'value, 'dwarf, P> Typedef<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// The schema of this value.
+    pub fn schema(&self) -> &crate::schema::Typedef<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// This value, as a value of the aliased type.
+    pub fn value(&self) -> Result<super::Value<'value, 'dwarf, P>, crate::Error> {
+        let r#type = self.schema.r#type()?;
+        unsafe { super::Value::with_type(r#type, self.value, self.provider) }
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Debug for Typedef<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::Typedef");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("value", &self.value);
+        debug_struct.finish()
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Display for Typedef<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value().map_err(crate::fmt_err)?.fmt(f)
+    }
+}
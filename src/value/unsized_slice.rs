@@ -0,0 +1,109 @@
+use std::fmt;
+
+/// A reflected unsized `[T]` tail of a dynamically sized struct.
+pub struct UnsizedSlice<'value, 'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: crate::schema::UnsizedSlice<'dwarf, P::Reader>,
+    value: crate::Bytes<'value>,
+    len: usize,
+    provider: &'dwarf P,
+}
+
+impl<'dwarf, R> crate::schema::UnsizedSlice<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = std::primitive::usize>,
+{
+    pub(crate) unsafe fn with_bytes<'value, P>(
+        self,
+        _provider: &'dwarf P,
+        _value: crate::Bytes<'value>,
+    ) -> Result<UnsizedSlice<'value, 'dwarf, P>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        bail!("the length of `{self}` is only known from the metadata of a wide pointer")
+    }
+
+    /// Safety: `value` must hold `len` elements of this slice's element type.
+    pub(crate) unsafe fn with_len<'value, P>(
+        self,
+        provider: &'dwarf P,
+        value: crate::Bytes<'value>,
+        len: usize,
+    ) -> Result<UnsizedSlice<'value, 'dwarf, P>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        let elt_size: usize = self.elt_type()?.size()?.try_into()?;
+        let size = elt_size
+            .checked_mul(len)
+            .ok_or_else(crate::error::arithmetic_overflow)?;
+        let Some(value) = value.get(..size) else {
+            bail!(
+                "slice spans {size} bytes, but only {} are available",
+                value.len()
+            )
+        };
+        Ok(UnsizedSlice {
+            schema: self,
+            value,
+            len,
+            provider,
+        })
+    }
+}
+
+impl<'value, 'dwarf, P> UnsizedSlice<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// The schema of this value.
+    pub fn schema(&self) -> &crate::schema::UnsizedSlice<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// The number of elements in this slice.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether this slice has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// An iterator over values of this slice.
+    pub fn iter(&self) -> Result<super::Iter<'value, 'dwarf, P>, crate::Error> {
+        let elt_type = self.schema.elt_type()?;
+        let elt_size = elt_type.size()?.try_into()?;
+        Ok(unsafe { super::Iter::new(self.value, elt_size, elt_type, self.len, self.provider) })
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Debug for UnsizedSlice<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::UnsizedSlice");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("value", &self.value);
+        debug_struct.finish()
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Display for UnsizedSlice<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_list = f.debug_list();
+        for maybe_elt in self.iter().map_err(crate::fmt_err)? {
+            let elt = maybe_elt.map_err(crate::fmt_err)?;
+            debug_list.entry(&crate::DebugDisplay(elt));
+        }
+        debug_list.finish()
+    }
+}
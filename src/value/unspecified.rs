@@ -0,0 +1,79 @@
+use std::fmt;
+
+/// A value of a type whose layout is not described by the debuginfo.
+pub struct Unspecified<'value, 'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: crate::schema::Unspecified<'dwarf, P::Reader>,
+    value: crate::Bytes<'value>,
+    provider: &'dwarf P,
+}
+
+impl<'dwarf, R> crate::schema::Unspecified<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = std::primitive::usize>,
+{
+    pub(crate) unsafe fn with_bytes<'value, P>(
+        self,
+        provider: &'dwarf P,
+        value: crate::Bytes<'value>,
+    ) -> Result<Unspecified<'value, 'dwarf, P>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        let size = self.size()?.unwrap_or(0).try_into()?;
+        let Some(value) = value.get(..size) else {
+            bail!(
+                "value is {size} bytes, but only {} are available",
+                value.len()
+            )
+        };
+        Ok(Unspecified {
+            schema: self,
+            value,
+            provider,
+        })
+    }
+}
+
+impl<'value, 'dwarf, P> Unspecified<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// The schema of this value.
+    pub fn schema(&self) -> &crate::schema::Unspecified<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
+
+    /// The raw bytes of this value.
+    pub fn bytes(&self) -> crate::Bytes<'value> {
+        self.value
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Debug for Unspecified<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::Unspecified");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("value", &self.value);
+        debug_struct.finish()
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Display for Unspecified<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}>", self.schema)
+    }
+}
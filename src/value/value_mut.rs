@@ -0,0 +1,712 @@
+use crate::schema::Type;
+use std::fmt;
+
+/// A mutably-borrowed reflected value.
+///
+/// Produced by [`reflect_mut`][crate::Reflect#method.reflect_mut] or
+/// [`field_mut`][Self::field_mut]. A `ValueMut` can overwrite its value with
+/// another of the same type ([`set`][Self::set]), copy in a reflected value of
+/// the same plain type ([`assign`][Self::assign]), or, if it is an enum, switch
+/// to another of its variants ([`set_variant`][Self::set_variant]).
+///
+/// Reflection disregards privacy, so these writes may break the invariants
+/// that a type's safe API upholds (e.g., that a `NonZeroU32` is non-zero, or
+/// that a `Vec`'s length does not exceed its capacity); hence, all but
+/// `assign` are `unsafe`.
+pub struct ValueMut<'value, 'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: Type<'dwarf, P::Reader>,
+    value: &'value mut [crate::Byte],
+    provider: &'dwarf P,
+}
+
+impl<'value, 'dwarf, P> ValueMut<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// Safety: `value` absolutely must be a valid value of `schema`.
+    pub(crate) unsafe fn new(
+        schema: Type<'dwarf, P::Reader>,
+        value: &'value mut [crate::Byte],
+        provider: &'dwarf P,
+    ) -> Result<Self, crate::Error> {
+        let size: usize = schema.size()?.try_into()?;
+        let available = value.len();
+        let Some(value) = value.get_mut(..size) else {
+            bail!(
+                "`{}` spans {size} bytes, but only {available} are available",
+                short_name(&schema)?
+            )
+        };
+        Ok(Self {
+            schema,
+            value,
+            provider,
+        })
+    }
+
+    /// The schema of this value.
+    pub fn schema(&self) -> &Type<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// This value, reborrowed immutably.
+    pub fn as_value(&self) -> Result<super::Value<'_, 'dwarf, P>, crate::Error> {
+        unsafe { super::Value::with_type(self.schema.clone(), self.value, self.provider) }
+    }
+
+    /// The field named `name` of this struct, or of the current variant of
+    /// this enum, if any.
+    ///
+    /// ## Safety
+    /// The field may be private. Writes through the returned `ValueMut` must
+    /// uphold every invariant that this value's type places on that field.
+    pub unsafe fn field_mut<N>(
+        &mut self,
+        field_name: N,
+    ) -> Result<Option<ValueMut<'_, 'dwarf, P>>, crate::Error>
+    where
+        N: AsRef<[u8]>,
+    {
+        let target_name = field_name.as_ref();
+        let mut fields = match &self.schema {
+            Type::Struct(schema) => schema.fields()?,
+            Type::Enum(schema) => {
+                let value = unsafe { schema.clone().with_bytes(self.provider, self.value)? };
+                value.variant()?.schema().fields()?
+            }
+            schema => bail!("`{}` does not have fields", short_name(schema)?),
+        };
+        let mut fields = fields.iter()?;
+        while let Some(field) = fields.try_next()? {
+            let name = field.name()?;
+            if name.to_slice()?.as_ref() != target_name {
+                continue;
+            }
+            if field.bit_size()?.is_some() || field.is_unsized()? {
+                bail!("field `{name}` cannot be borrowed mutably");
+            }
+            let (start, end) = field_range(&field)?;
+            let Some(value) = self.value.get_mut(start..end) else {
+                bail!("field `{name}` spans bytes {start}..{end}, which are out of bounds")
+            };
+            return Ok(Some(ValueMut {
+                schema: field.r#type()?,
+                value,
+                provider: self.provider,
+            }));
+        }
+        Ok(None)
+    }
+
+    /// Replaces this value with `value`, dropping the previous value.
+    ///
+    /// Fails if this value's type does not share the path, size and structure
+    /// of `T`'s.
+    ///
+    /// ## Safety
+    /// This value must be of type `T`, and no shorter-lived than `T`; e.g.,
+    /// it must not be a `&'static str` if `T` is a `&'a str`. Since `T` must be
+    /// `'static`, this holds if no other type of the program shares the path of
+    /// `T`.
+    pub unsafe fn set<T>(&mut self, value: T) -> Result<(), crate::Error>
+    where
+        T: crate::Reflect + 'static,
+    {
+        let r#type = (&value as &dyn crate::Reflect).reflect_type(self.provider)?;
+        if !same_path(&self.schema, &r#type)? || self.value.len() != std::mem::size_of::<T>() {
+            bail!(
+                "cannot set a `{}` to a `{}`",
+                short_name(&self.schema)?,
+                short_name(&r#type)?
+            );
+        }
+        let pointer = self.value.as_mut_ptr() as *mut T;
+        // this value is a `T`, but might not be aligned like one (e.g., if it
+        // is a field of a packed struct)
+        let previous = unsafe { pointer.read_unaligned() };
+        unsafe { pointer.write_unaligned(value) };
+        drop(previous);
+        Ok(())
+    }
+
+    /// Overwrites this value with a copy of `value`.
+    ///
+    /// Only values that may be soundly duplicated byte-for-byte can be
+    /// assigned: primitives, SIMD vectors, field-less enums, and arrays
+    /// thereof. Fails if `value` is not of this value's type; see
+    /// [`same_type`].
+    pub fn assign(&mut self, value: &super::Value<'_, 'dwarf, P>) -> Result<(), crate::Error> {
+        let Some((r#type, bytes)) = plain_bytes(value) else {
+            bail!("reflected values of this kind cannot be assigned; use `set` instead")
+        };
+        if !is_plain(&r#type)? {
+            bail!(
+                "`{}` cannot be soundly copied; use `set` instead",
+                short_name(&r#type)?
+            );
+        }
+        if !same_type(&self.schema, &r#type)? || bytes.len() != self.value.len() {
+            bail!(
+                "cannot assign a `{}` to a `{}`",
+                short_name(&r#type)?,
+                short_name(&self.schema)?
+            );
+        }
+        self.value.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Switches this enum to its variant named `name`.
+    ///
+    /// The `payload` holds the bytes of each of the variant's fields, in
+    /// declaration order and without padding between them. These bytes are
+    /// validated against the types of their fields; if they are invalid, this
+    /// value is left unchanged.
+    ///
+    /// The fields of the previous variant are not dropped, and so are leaked;
+    /// e.g., switching an `Option<String>` from `Some` to `None` leaks the
+    /// `String`'s buffer.
+    ///
+    /// ## Safety
+    /// The fields of the variant may be of types whose invariants cannot be
+    /// checked from their bytes alone (e.g., a `NonZeroU32` within a struct).
+    /// The `payload` must uphold the invariants of each of those types.
+    pub unsafe fn set_variant(&mut self, name: &str, payload: &[u8]) -> Result<(), crate::Error> {
+        let Type::Enum(schema) = &self.schema else {
+            bail!("`{}` is not an enum", short_name(&self.schema)?)
+        };
+        let mut variants = schema.variants()?;
+        let mut variants = variants.iter()?;
+        let variant = loop {
+            let Some(variant) = variants.try_next()? else {
+                bail!("`{}` has no variant named `{name}`", schema.name())
+            };
+            if variant.name()?.to_slice()? == name.as_bytes() {
+                break variant;
+            }
+        };
+
+        let mut ranges = vec![];
+        let mut fields = variant.fields()?;
+        let mut fields = fields.iter()?;
+        while let Some(field) = fields.try_next()? {
+            if field.bit_size()?.is_some() || field.is_unsized()? {
+                bail!("the fields of variant `{name}` cannot be written");
+            }
+            ranges.push(field_range(&field)?);
+        }
+        let expected: usize = ranges.iter().map(|(start, end)| end - start).sum();
+        if payload.len() != expected {
+            bail!(
+                "the fields of variant `{name}` span {expected} bytes, but the payload has {}",
+                payload.len()
+            );
+        }
+
+        let previous = self.value.to_vec();
+        let result = self.write_variant(schema.clone(), &variant, &ranges, payload);
+        if result.is_err() {
+            self.value.copy_from_slice(&previous);
+        }
+        result
+    }
+
+    /// Writes `payload` into the fields at `ranges`, then the discriminant of
+    /// `variant`, and checks that the result is a valid `variant`.
+    fn write_variant(
+        &mut self,
+        schema: crate::schema::Enum<'dwarf, P::Reader>,
+        variant: &crate::schema::Variant<'dwarf, P::Reader>,
+        ranges: &[(usize, usize)],
+        mut payload: &[u8],
+    ) -> Result<(), crate::Error> {
+        for &(start, end) in ranges {
+            let (bytes, rest) = payload.split_at(end - start);
+            write(self.value, start, bytes)?;
+            payload = rest;
+        }
+
+        write_discriminant(self.value, &schema, variant)?;
+
+        // niche-encoded enums can only be checked by reading them back
+        let value = unsafe { schema.with_bytes(self.provider, self.value)? };
+        let written = value.variant()?;
+        let written = written.schema();
+        if written.name()?.to_slice()? != variant.name()?.to_slice()? {
+            bail!(
+                "the payload encodes variant `{}`, not `{}`",
+                written.name()?,
+                variant.name()?
+            );
+        }
+        validate_fields(written.fields()?, self.value, self.provider)
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Debug for ValueMut<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::ValueMut");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("value", &self.value);
+        debug_struct.finish()
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Display for ValueMut<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_value().map_err(crate::fmt_err)?.fmt(f)
+    }
+}
+
+/// Whether `a` and `b` are the same type: either they are described by the
+/// same DIE, or they are the same primitive, or they are structurally
+/// identical; i.e., they share their kind, path and size, and their fields
+/// (or variants, elements or referents) share their names, offsets and types.
+///
+/// Types are described once per compilation unit that uses them, so
+/// descriptions of one type from different units are identified by their
+/// structure.
+pub(crate) fn same_type<R>(a: &Type<'_, R>, b: &Type<'_, R>) -> Result<bool, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    same_structure(a, b, &mut vec![])
+}
+
+/// A DIE, identified by its unit and its offset therein.
+type DieId = (usize, crate::gimli::UnitOffset);
+
+/// Whether `a` and `b` are structurally identical, assuming that the pairs
+/// of types in `assumed` are; as types may be recursive (e.g., through a
+/// `Box`), pairs are assumed identical while they are being compared.
+fn same_structure<R>(
+    a: &Type<'_, R>,
+    b: &Type<'_, R>,
+    assumed: &mut Vec<(DieId, DieId)>,
+) -> Result<bool, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    if std::mem::discriminant(a) != std::mem::discriminant(b) {
+        return Ok(false);
+    }
+    let (_, a_unit, a_offset) = a.die();
+    let (_, b_unit, b_offset) = b.die();
+    if (std::ptr::eq(a_unit, b_unit) && a_offset == b_offset) || is_primitive(a) {
+        return Ok(true);
+    }
+    let pair = (
+        (a_unit as *const _ as usize, a_offset),
+        (b_unit as *const _ as usize, b_offset),
+    );
+    if assumed.contains(&pair) {
+        return Ok(true);
+    }
+    if a.size()? != b.size()? || a.path()? != b.path()? {
+        return Ok(false);
+    }
+    assumed.push(pair);
+    // each comparison is a conjunction, so a failed comparison fails the
+    // whole, and its assumptions need not be retracted
+    match (a, b) {
+        (Type::Array(a), Type::Array(b)) => {
+            Ok(a.len()? == b.len()? && same_structure(&a.elt_type()?, &b.elt_type()?, assumed)?)
+        }
+        (Type::Struct(a), Type::Struct(b)) => same_fields(a.fields()?, b.fields()?, assumed),
+        (Type::Enum(a), Type::Enum(b)) => {
+            let a_location = a.discriminant_location().clone().address(0)?;
+            let b_location = b.discriminant_location().clone().address(0)?;
+            if a_location != b_location
+                || !same_structure(&a.discriminant_type()?, &b.discriminant_type()?, assumed)?
+            {
+                return Ok(false);
+            }
+            let mut a_variants = a.variants()?;
+            let mut b_variants = b.variants()?;
+            let mut a_variants = a_variants.iter()?;
+            let mut b_variants = b_variants.iter()?;
+            loop {
+                let (a, b) = match (a_variants.try_next()?, b_variants.try_next()?) {
+                    (None, None) => return Ok(true),
+                    (Some(a), Some(b)) => (a, b),
+                    _ => return Ok(false),
+                };
+                if a.name()?.to_slice()? != b.name()?.to_slice()?
+                    || a.discriminant_value() != b.discriminant_value()
+                    || !same_fields(a.fields()?, b.fields()?, assumed)?
+                {
+                    return Ok(false);
+                }
+            }
+        }
+        (Type::SharedRef(a), Type::SharedRef(b)) => {
+            same_structure(&a.r#type()?, &b.r#type()?, assumed)
+        }
+        (Type::UniqueRef(a), Type::UniqueRef(b)) => {
+            same_structure(&a.r#type()?, &b.r#type()?, assumed)
+        }
+        (Type::Box(a), Type::Box(b)) => same_structure(&a.r#type()?, &b.r#type()?, assumed),
+        // the remaining kinds are described by their path (i.e., name) and
+        // size alone
+        _ => Ok(true),
+    }
+}
+
+/// Whether `a` and `b` share the names, offsets and types of their fields.
+fn same_fields<R>(
+    mut a: crate::schema::Fields<'_, R>,
+    mut b: crate::schema::Fields<'_, R>,
+    assumed: &mut Vec<(DieId, DieId)>,
+) -> Result<bool, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let mut a = a.iter()?;
+    let mut b = b.iter()?;
+    loop {
+        let (a, b) = match (a.try_next()?, b.try_next()?) {
+            (None, None) => return Ok(true),
+            (Some(a), Some(b)) => (a, b),
+            _ => return Ok(false),
+        };
+        let a_offset = crate::schema::Offset::from_die(a.unit(), a.entry())?.bit_address(0)?;
+        let b_offset = crate::schema::Offset::from_die(b.unit(), b.entry())?.bit_address(0)?;
+        if a.name()?.to_slice()? != b.name()?.to_slice()?
+            || a_offset != b_offset
+            || a.bit_size()? != b.bit_size()?
+            || !same_structure(&a.r#type()?, &b.r#type()?, assumed)?
+        {
+            return Ok(false);
+        }
+    }
+}
+
+/// Whether `a` and `b` share their kind, size, path and structure.
+///
+/// Unlike [`same_type`], fields are not compared, so distinct types that share
+/// a path (e.g., those of two versions of one crate) may be confused.
+///
+/// ## Safety
+/// A `true` result may only be relied upon if no other type of the program
+/// shares the path of `a` or `b`.
+pub(crate) unsafe fn same_path<R>(a: &Type<'_, R>, b: &Type<'_, R>) -> Result<bool, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    if same_type(a, b)? {
+        return Ok(true);
+    }
+    if std::mem::discriminant(a) != std::mem::discriminant(b) || a.size()? != b.size()? {
+        return Ok(false);
+    }
+    let (a_dwarf, a_unit, a_offset) = a.die();
+    let (b_dwarf, b_unit, b_offset) = b.die();
+    let a_path = crate::get_qualified_name(a_dwarf, a_unit, a_offset);
+    let b_path = crate::get_qualified_name(b_dwarf, b_unit, b_offset);
+    if let (Ok(a_path), Ok(b_path)) = (a_path, b_path) {
+        if a_path != b_path {
+            return Ok(false);
+        }
+    }
+    Ok(a.to_string() == b.to_string())
+}
+
+/// Whether `r#type` is a primitive, which is fully described by its kind.
+fn is_primitive<R>(r#type: &Type<'_, R>) -> bool
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    matches!(
+        r#type,
+        Type::bool(_)
+            | Type::char(_)
+            | Type::f32(_)
+            | Type::f64(_)
+            | Type::i8(_)
+            | Type::i16(_)
+            | Type::i32(_)
+            | Type::i64(_)
+            | Type::i128(_)
+            | Type::isize(_)
+            | Type::u8(_)
+            | Type::u16(_)
+            | Type::u32(_)
+            | Type::u64(_)
+            | Type::u128(_)
+            | Type::usize(_)
+            | Type::unit(_)
+    )
+}
+
+/// The name of `r#type`, for error messages; unlike its `Display`, structs and
+/// enums are named without their fields or variants.
+pub(crate) fn short_name<R>(r#type: &Type<'_, R>) -> Result<String, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    match r#type {
+        Type::Struct(schema) => schema.display_name(),
+        Type::Enum(schema) => Ok(schema.name().to_string_lossy()?.into_owned()),
+        r#type => Ok(r#type.to_string()),
+    }
+}
+
+/// Whether values of `r#type` may be soundly duplicated byte-for-byte.
+fn is_plain<R>(r#type: &Type<'_, R>) -> Result<bool, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    match r#type {
+        Type::bool(_)
+        | Type::char(_)
+        | Type::f32(_)
+        | Type::f64(_)
+        | Type::i8(_)
+        | Type::i16(_)
+        | Type::i32(_)
+        | Type::i64(_)
+        | Type::i128(_)
+        | Type::isize(_)
+        | Type::u8(_)
+        | Type::u16(_)
+        | Type::u32(_)
+        | Type::u64(_)
+        | Type::u128(_)
+        | Type::usize(_)
+        | Type::unit(_)
+        | Type::Vector(_) => Ok(true),
+        Type::Array(array) => is_plain(&array.elt_type()?),
+        Type::Enum(schema) => {
+            let mut variants = schema.variants()?;
+            let mut variants = variants.iter()?;
+            while let Some(variant) = variants.try_next()? {
+                let mut fields = variant.fields()?;
+                if fields.iter()?.try_next()?.is_some() {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// The type and bytes of `value`, if it is of a kind that could be plain.
+fn plain_bytes<'value, 'dwarf, P>(
+    value: &super::Value<'value, 'dwarf, P>,
+) -> Option<(Type<'dwarf, P::Reader>, crate::Bytes<'value>)>
+where
+    P: crate::DebugInfoProvider,
+{
+    macro_rules! primitive {
+        ($value:expr) => {{
+            let schema = Type::from($value.schema().clone());
+            let size = std::mem::size_of_val($value.value());
+            let pointer = $value.value() as *const _ as *const crate::Byte;
+            (schema, unsafe { std::slice::from_raw_parts(pointer, size) })
+        }};
+    }
+    Some(match value {
+        super::Value::bool(v) => primitive!(v),
+        super::Value::char(v) => primitive!(v),
+        super::Value::f32(v) => primitive!(v),
+        super::Value::f64(v) => primitive!(v),
+        super::Value::i8(v) => primitive!(v),
+        super::Value::i16(v) => primitive!(v),
+        super::Value::i32(v) => primitive!(v),
+        super::Value::i64(v) => primitive!(v),
+        super::Value::i128(v) => primitive!(v),
+        super::Value::isize(v) => primitive!(v),
+        super::Value::u8(v) => primitive!(v),
+        super::Value::u16(v) => primitive!(v),
+        super::Value::u32(v) => primitive!(v),
+        super::Value::u64(v) => primitive!(v),
+        super::Value::u128(v) => primitive!(v),
+        super::Value::usize(v) => primitive!(v),
+        super::Value::unit(v) => primitive!(v),
+        super::Value::Array(v) => (v.schema().clone().into(), v.bytes()),
+        super::Value::Enum(v) => (v.schema().clone().into(), v.bytes()),
+        super::Value::Vector(v) => (v.schema().clone().into(), v.bytes()),
+        _ => return None,
+    })
+}
+
+/// The range of bytes that `field` spans within its struct or enum.
+pub(crate) fn field_range<R>(
+    field: &crate::schema::Field<'_, R>,
+) -> Result<(usize, usize), crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let start: usize = crate::schema::Offset::from_die(field.unit(), field.entry())?
+        .address(0)?
+        .try_into()?;
+    let size: usize = field.r#type()?.size()?.try_into()?;
+    let end = start
+        .checked_add(size)
+        .ok_or_else(crate::error::arithmetic_overflow)?;
+    Ok((start, end))
+}
+
+/// Writes `bytes` into `value`, beginning at `offset`.
+pub(crate) fn write(
+    value: &mut [crate::Byte],
+    offset: usize,
+    bytes: &[u8],
+) -> Result<(), crate::Error> {
+    let end = offset
+        .checked_add(bytes.len())
+        .ok_or_else(crate::error::arithmetic_overflow)?;
+    let Some(value) = value.get_mut(offset..end) else {
+        bail!("bytes {offset}..{end} are out of bounds")
+    };
+    for (dst, src) in value.iter_mut().zip(bytes) {
+        *dst = std::mem::MaybeUninit::new(*src);
+    }
+    Ok(())
+}
+
+/// Writes the discriminant of `variant` into the enum `value`, if the variant
+/// has one.
+pub(crate) fn write_discriminant<R>(
+    value: &mut [crate::Byte],
+    schema: &crate::schema::Enum<'_, R>,
+    variant: &crate::schema::Variant<'_, R>,
+) -> Result<(), crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    use crate::schema::Data;
+    let Some(discriminant) = variant.discriminant_value() else {
+        return Ok(());
+    };
+    let offset = schema.discriminant_location().clone().address(0)?;
+    let bytes: &[u8] = match discriminant {
+        Data::u8(v) => &v.to_ne_bytes(),
+        Data::u16(v) => &v.to_ne_bytes(),
+        Data::u32(v) => &v.to_ne_bytes(),
+        Data::u64(v) => &v.to_ne_bytes(),
+    };
+    write(value, offset.try_into()?, bytes)
+}
+
+/// Checks that `value` holds valid values of each of `fields`.
+fn validate_fields<P>(
+    mut fields: crate::schema::Fields<'_, P::Reader>,
+    value: crate::Bytes<'_>,
+    provider: &P,
+) -> Result<(), crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    let mut fields = fields.iter()?;
+    while let Some(field) = fields.try_next()? {
+        if field.bit_size()?.is_some() {
+            continue;
+        }
+        let (start, end) = field_range(&field)?;
+        let Some(bytes) = value.get(start..end) else {
+            bail!(
+                "field `{}` spans bytes {start}..{end}, which are out of bounds",
+                field.name()?
+            )
+        };
+        validate(&field.r#type()?, bytes, provider)?;
+    }
+    Ok(())
+}
+
+/// Checks that `value` is a valid value of `r#type`.
+///
+/// Types whose validity cannot be determined from their bytes alone (e.g.,
+/// references) are rejected.
+fn validate<P>(
+    r#type: &Type<'_, P::Reader>,
+    value: crate::Bytes<'_>,
+    provider: &P,
+) -> Result<(), crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    // only the bytes of scalars are read; scalars have no padding, and the
+    // bytes of those within `value` are initialized by its writer
+    let read = |len: usize| -> Result<*const u8, crate::Error> {
+        let Some(bytes) = value.get(..len) else {
+            bail!(
+                "`{type}` spans {len} bytes, but only {} are available",
+                value.len()
+            )
+        };
+        Ok(bytes.as_ptr() as *const u8)
+    };
+    match r#type {
+        Type::bool(_) => {
+            let byte = unsafe { read(1)?.read() };
+            if byte > 1 {
+                bail!("{byte} is not a valid `bool`");
+            }
+        }
+        Type::char(_) => {
+            let scalar = unsafe { (read(4)? as *const u32).read_unaligned() };
+            if char::from_u32(scalar).is_none() {
+                bail!("{scalar:#x} is not a valid `char`");
+            }
+        }
+        Type::f32(_)
+        | Type::f64(_)
+        | Type::i8(_)
+        | Type::i16(_)
+        | Type::i32(_)
+        | Type::i64(_)
+        | Type::i128(_)
+        | Type::isize(_)
+        | Type::u8(_)
+        | Type::u16(_)
+        | Type::u32(_)
+        | Type::u64(_)
+        | Type::u128(_)
+        | Type::usize(_)
+        | Type::unit(_)
+        | Type::Vector(_)
+        | Type::ConstPtr(_)
+        | Type::MutPtr(_) => {}
+        Type::Array(array) => {
+            let elt_type = array.elt_type()?;
+            let elt_size: usize = elt_type.size()?.try_into()?;
+            let len: usize = array.len()?.try_into()?;
+            for index in 0..len {
+                let start = index * elt_size;
+                let Some(elt) = value.get(start..start + elt_size) else {
+                    bail!("element {index} of `{type}` is out of bounds")
+                };
+                validate(&elt_type, elt, provider)?;
+            }
+        }
+        Type::Struct(schema) => validate_fields(schema.fields()?, value, provider)?,
+        Type::Enum(schema) => {
+            // enums are read through aligned pointers, so are copied first;
+            // their padding and inactive fields may be uninitialized, so only
+            // their discriminant and the fields of their variant are read
+            let mut buffer = vec![std::mem::MaybeUninit::<u64>::uninit(); (value.len() + 7) / 8];
+            let aligned = unsafe {
+                let pointer = buffer.as_mut_ptr() as *mut crate::Byte;
+                std::ptr::copy_nonoverlapping(value.as_ptr(), pointer, value.len());
+                std::slice::from_raw_parts(pointer as *const crate::Byte, value.len())
+            };
+            let value = unsafe { schema.clone().with_bytes(provider, aligned)? };
+            let variant = value.variant()?;
+            validate_fields(variant.schema().fields()?, aligned, provider)?;
+        }
+        r#type => bail!("cannot validate the bytes of a `{type}`"),
+    }
+    Ok(())
+}
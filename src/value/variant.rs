@@ -51,8 +51,13 @@ where
         while let Some(field) = fields.try_next().map_err(crate::fmt_err)? {
             let field_name = field.schema().name().map_err(crate::fmt_err)?;
             let field_name = field_name.to_string_lossy().map_err(crate::fmt_err)?;
-            let field_value = field.value().map_err(crate::fmt_err)?;
-            debug_struct.field(&field_name, &crate::DebugDisplay(field_value));
+            match field.value() {
+                Ok(value) => debug_struct.field(&field_name, &crate::DebugDisplay(value)),
+                Err(err) => {
+                    let invalid = crate::safety::Invalid::from_err(err)?;
+                    debug_struct.field(&field_name, &crate::DebugDisplay(invalid))
+                }
+            };
         }
         debug_struct.finish()
     }
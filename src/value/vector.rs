@@ -0,0 +1,104 @@
+use std::fmt;
+
+/// A reflected SIMD vector value.
+pub struct Vector<'value, 'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: crate::schema::Vector<'dwarf, P::Reader>,
+    value: crate::Bytes<'value>,
+    provider: &'dwarf P,
+}
+
+impl<'dwarf, R> crate::schema::Vector<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = std::primitive::usize>,
+{
+    pub(crate) unsafe fn with_bytes<'value, P>(
+        self,
+        provider: &'dwarf P,
+        value: crate::Bytes<'value>,
+    ) -> Result<Vector<'value, 'dwarf, P>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        let size = self.size()?.try_into()?;
+        let Some(value) = value.get(..size) else {
+            bail!(
+                "vector spans {size} bytes, but only {} are available",
+                value.len()
+            )
+        };
+        Ok(Vector {
+            schema: self,
+            value,
+            provider,
+        })
+    }
+}
+
+impl<'value, 'dwarf, P> Vector<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// The schema of this value.
+    pub fn schema(&self) -> &crate::schema::Vector<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// The debug info provider of this value.
+    pub(crate) fn provider(&self) -> &'dwarf P {
+        self.provider
+    }
+
+    /// The bytes of this value.
+    pub(crate) fn bytes(&self) -> crate::Bytes<'value> {
+        self.value
+    }
+
+    /// The lane at `index`, if any.
+    pub fn lane(
+        &self,
+        index: usize,
+    ) -> Result<Option<super::Value<'value, 'dwarf, P>>, crate::Error> {
+        self.iter()?.nth(index).transpose()
+    }
+
+    /// An iterator over the lanes of this vector.
+    pub fn iter(&self) -> Result<super::Iter<'value, 'dwarf, P>, crate::Error> {
+        let lane_type = self.schema.lane_type()?;
+        let lane_size = lane_type.size()?.try_into()?;
+        let lanes = self.schema.lanes()?.try_into()?;
+        Ok(unsafe { super::Iter::new(self.value, lane_size, lane_type, lanes, self.provider) })
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Debug for Vector<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::Vector");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("value", &self.value);
+        debug_struct.finish()
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Display for Vector<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_list = f.debug_list();
+        for maybe_lane in self.iter().map_err(crate::fmt_err)? {
+            // float lanes are displayed like `1.0`, rather than `1`
+            match maybe_lane.map_err(crate::fmt_err)? {
+                super::Value::f32(lane) => debug_list.entry(lane.value()),
+                super::Value::f64(lane) => debug_list.entry(lane.value()),
+                lane => debug_list.entry(&crate::DebugDisplay(lane)),
+            };
+        }
+        debug_list.finish()
+    }
+}
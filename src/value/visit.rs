@@ -0,0 +1,435 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use super::referents::Key;
+
+/// A visitor of the graph of reflected values reachable from a root value.
+///
+/// Each hook is called with the [`Path`] from the root to the visited value,
+/// and returns a [`Flow`] indicating whether to descend into that value, skip
+/// it, or stop visiting altogether. By default, every hook continues.
+pub trait Visit<'value, 'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// Visits a struct, before its fields (or, if it is a `Vec`, its
+    /// elements).
+    fn visit_struct(
+        &mut self,
+        path: &Path,
+        value: &super::Struct<'value, 'dwarf, P>,
+    ) -> Result<Flow, crate::Error> {
+        let _ = (path, value);
+        Ok(Flow::Continue)
+    }
+
+    /// Visits a field of a struct or variant, before its value.
+    fn visit_field(
+        &mut self,
+        path: &Path,
+        field: &super::Field<'value, 'dwarf, P>,
+    ) -> Result<Flow, crate::Error> {
+        let _ = (path, field);
+        Ok(Flow::Continue)
+    }
+
+    /// Visits the variant of an enum (or the state of a generator), before
+    /// its fields.
+    fn visit_variant(
+        &mut self,
+        path: &Path,
+        variant: &super::Variant<'value, 'dwarf, P>,
+    ) -> Result<Flow, crate::Error> {
+        let _ = (path, variant);
+        Ok(Flow::Continue)
+    }
+
+    /// Visits a reference, box, slice or raw pointer, before its referent.
+    /// Raw pointers are never followed, and the referents of references and
+    /// boxes are descended into at most once.
+    fn visit_pointer(
+        &mut self,
+        path: &Path,
+        value: &super::Value<'value, 'dwarf, P>,
+    ) -> Result<Flow, crate::Error> {
+        let _ = (path, value);
+        Ok(Flow::Continue)
+    }
+
+    /// Visits the element at `index` of an array, slice, SIMD vector or
+    /// `Vec`, before the element itself.
+    fn visit_element(
+        &mut self,
+        path: &Path,
+        index: usize,
+        value: &super::Value<'value, 'dwarf, P>,
+    ) -> Result<Flow, crate::Error> {
+        let _ = (path, index, value);
+        Ok(Flow::Continue)
+    }
+
+    /// Visits a value without (visitable) constituents; i.e., a primitive,
+    /// `str`, `String`, bitfield, function or value of unspecified type.
+    fn visit_primitive(
+        &mut self,
+        path: &Path,
+        value: &super::Value<'value, 'dwarf, P>,
+    ) -> Result<Flow, crate::Error> {
+        let _ = (path, value);
+        Ok(Flow::Continue)
+    }
+}
+
+/// Whether to continue visiting; see [`Visit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Flow {
+    /// Descend into the visited value.
+    Continue,
+    /// Do not descend into the visited value, but continue visiting its
+    /// siblings.
+    Skip,
+    /// Stop visiting.
+    Stop,
+}
+
+/// A path from a root value to one of the values reachable from it; e.g.,
+/// `.mode::Auto.tags[0].*`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Path {
+    segments: Vec<Segment>,
+}
+
+impl Path {
+    /// The segments of this path, from the root.
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// The number of segments of this path.
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Whether this is the path of the root value.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.segments {
+            segment.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A segment of a [`Path`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Segment {
+    /// A field of a struct or variant, displayed as `.name`.
+    Field(String),
+    /// The variant of an enum, displayed as `::Name`.
+    Variant(String),
+    /// An element of an array, slice, SIMD vector or `Vec`, displayed as
+    /// `[index]`.
+    Index(usize),
+    /// The referent of a reference or box, displayed as `.*`.
+    Deref,
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Field(name) => write!(f, ".{name}"),
+            Self::Variant(name) => write!(f, "::{name}"),
+            Self::Index(index) => write!(f, "[{index}]"),
+            Self::Deref => f.write_str(".*"),
+        }
+    }
+}
+
+impl<'value, 'dwarf, P> super::Value<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// Visits this value and the values reachable from it with `visitor`.
+    pub fn visit<V>(&self, visitor: &mut V) -> Result<(), crate::Error>
+    where
+        V: Visit<'value, 'dwarf, P>,
+    {
+        self.visit_with_depth(visitor, usize::MAX)
+    }
+
+    /// Visits this value and the values reachable from it with `visitor`,
+    /// up to `depth` path segments deep.
+    pub fn visit_with_depth<V>(&self, visitor: &mut V, depth: usize) -> Result<(), crate::Error>
+    where
+        V: Visit<'value, 'dwarf, P>,
+    {
+        let mut walker = Walker::new(visitor, self, depth)?;
+        walker.walk(self)?;
+        Ok(())
+    }
+}
+
+/// The most pointers that [`shared_referents`] follows; the referents reachable
+/// only beyond these are not found.
+const MAX_POINTERS: usize = 1024;
+
+/// Produces the referents reachable from `value` more than once, among those
+/// reachable within [`MAX_POINTERS`] pointers. Referents behind invalid
+/// pointers are skipped.
+pub(crate) fn shared_referents<'value, 'dwarf, P>(
+    value: &super::Value<'value, 'dwarf, P>,
+) -> Result<HashSet<Key>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// Stops visiting once its budget of pointers is spent.
+    struct Budget(usize);
+    impl<'value, 'dwarf, P> Visit<'value, 'dwarf, P> for Budget
+    where
+        P: crate::DebugInfoProvider,
+    {
+        fn visit_pointer(
+            &mut self,
+            _: &Path,
+            _: &super::Value<'value, 'dwarf, P>,
+        ) -> Result<Flow, crate::Error> {
+            let Some(budget) = self.0.checked_sub(1) else {
+                return Ok(Flow::Stop);
+            };
+            self.0 = budget;
+            Ok(Flow::Continue)
+        }
+    }
+    let mut budget = Budget(MAX_POINTERS);
+    let mut walker = Walker::new(&mut budget, value, usize::MAX)?;
+    walker.skip_invalid = true;
+    walker.walk(value)?;
+    Ok(walker.shared)
+}
+
+/// Drives a [`Visit`]or over a value graph.
+struct Walker<'a, V> {
+    visitor: &'a mut V,
+    path: Path,
+    depth: usize,
+    /// The values visited, by address and type.
+    visited: HashSet<Key>,
+    /// The referents reached more than once.
+    shared: HashSet<Key>,
+    /// Whether to skip the referents of invalid pointers, rather than fail.
+    skip_invalid: bool,
+}
+
+impl<'a, V> Walker<'a, V> {
+    fn new<'value, 'dwarf, P>(
+        visitor: &'a mut V,
+        root: &super::Value<'value, 'dwarf, P>,
+        depth: usize,
+    ) -> Result<Self, crate::Error>
+    where
+        P: crate::DebugInfoProvider,
+    {
+        Ok(Self {
+            visitor,
+            path: Path::default(),
+            depth,
+            visited: root.key()?.into_iter().collect(),
+            shared: HashSet::new(),
+            skip_invalid: false,
+        })
+    }
+
+    fn walk<'value, 'dwarf, P>(
+        &mut self,
+        value: &super::Value<'value, 'dwarf, P>,
+    ) -> Result<Flow, crate::Error>
+    where
+        P: crate::DebugInfoProvider,
+        V: Visit<'value, 'dwarf, P>,
+    {
+        use super::Value;
+        match value {
+            Value::bool(_)
+            | Value::char(_)
+            | Value::f32(_)
+            | Value::f64(_)
+            | Value::i8(_)
+            | Value::i16(_)
+            | Value::i32(_)
+            | Value::i64(_)
+            | Value::i128(_)
+            | Value::isize(_)
+            | Value::u8(_)
+            | Value::u16(_)
+            | Value::u32(_)
+            | Value::u64(_)
+            | Value::u128(_)
+            | Value::usize(_)
+            | Value::unit(_)
+            | Value::str(_)
+            | Value::Bitfield(_)
+            | Value::Function(_)
+            | Value::Unspecified(_) => self.visitor.visit_primitive(&self.path, value),
+            Value::Typedef(v) => self.walk(&v.value()?),
+            Value::Qualified(v) => self.walk(&v.value()?),
+            Value::Struct(v) => {
+                if v.as_string()?.is_some() {
+                    return self.visitor.visit_primitive(&self.path, value);
+                }
+                match self.visitor.visit_struct(&self.path, v)? {
+                    Flow::Continue => match v.as_vec()? {
+                        Some(elements) => self.walk_elements(elements),
+                        None => self.walk_fields(v.fields()?),
+                    },
+                    flow => Ok(flow),
+                }
+            }
+            Value::Enum(v) => self.walk_enum(v),
+            Value::Generator(v) => self.walk_enum(&v.as_enum()?),
+            Value::Array(v) => self.walk_elements(v.iter()?),
+            Value::Vector(v) => self.walk_elements(v.iter()?),
+            Value::UnsizedSlice(v) => self.walk_elements(v.iter()?),
+            Value::Slice(v) => match self.visitor.visit_pointer(&self.path, value)? {
+                Flow::Continue => match self.valid(v.iter())? {
+                    Some(elements) => self.walk_elements(elements),
+                    None => Ok(Flow::Skip),
+                },
+                flow => Ok(flow),
+            },
+            Value::BoxedSlice(v) => match self.visitor.visit_pointer(&self.path, value)? {
+                Flow::Continue => match self.valid(v.iter())? {
+                    Some(elements) => self.walk_elements(elements),
+                    None => Ok(Flow::Skip),
+                },
+                flow => Ok(flow),
+            },
+            Value::Box(_) | Value::BoxedDyn(_) | Value::SharedRef(_) | Value::UniqueRef(_) => {
+                match self.visitor.visit_pointer(&self.path, value)? {
+                    Flow::Continue => match self.valid(value.referent())? {
+                        Some(Some(referent)) => self.walk_referent(referent),
+                        Some(None) => Ok(Flow::Continue),
+                        None => Ok(Flow::Skip),
+                    },
+                    flow => Ok(flow),
+                }
+            }
+            Value::ConstPtr(_) | Value::MutPtr(_) => self.visitor.visit_pointer(&self.path, value),
+        }
+    }
+
+    /// Visits `referent`, unless it has been visited already.
+    fn walk_referent<'value, 'dwarf, P>(
+        &mut self,
+        referent: super::Value<'value, 'dwarf, P>,
+    ) -> Result<Flow, crate::Error>
+    where
+        P: crate::DebugInfoProvider,
+        V: Visit<'value, 'dwarf, P>,
+    {
+        if let Some(key) = referent.key()? {
+            if !self.visited.insert(key) {
+                self.shared.insert(key);
+                return Ok(Flow::Skip);
+            }
+        }
+        self.enter(Segment::Deref, |this| this.walk(&referent))
+    }
+
+    fn walk_enum<'value, 'dwarf, P>(
+        &mut self,
+        value: &super::Enum<'value, 'dwarf, P>,
+    ) -> Result<Flow, crate::Error>
+    where
+        P: crate::DebugInfoProvider,
+        V: Visit<'value, 'dwarf, P>,
+    {
+        let variant = value.variant()?;
+        let name = variant.schema().name()?.to_string_lossy()?.into_owned();
+        self.enter(Segment::Variant(name), |this| {
+            match this.visitor.visit_variant(&this.path, &variant)? {
+                Flow::Continue => this.walk_fields(variant.fields()?),
+                flow => Ok(flow),
+            }
+        })
+    }
+
+    fn walk_fields<'value, 'dwarf, P>(
+        &mut self,
+        mut fields: super::Fields<'value, 'dwarf, P>,
+    ) -> Result<Flow, crate::Error>
+    where
+        P: crate::DebugInfoProvider,
+        V: Visit<'value, 'dwarf, P>,
+    {
+        let mut fields = fields.iter()?;
+        while let Some(field) = fields.try_next()? {
+            let name = field.schema().name()?.to_string_lossy()?.into_owned();
+            let flow = self.enter(Segment::Field(name), |this| {
+                match this.visitor.visit_field(&this.path, &field)? {
+                    Flow::Continue => match this.valid(field.value())? {
+                        Some(value) => this.walk(&value),
+                        None => Ok(Flow::Skip),
+                    },
+                    flow => Ok(flow),
+                }
+            })?;
+            if flow == Flow::Stop {
+                return Ok(Flow::Stop);
+            }
+        }
+        Ok(Flow::Continue)
+    }
+
+    fn walk_elements<'value, 'dwarf, P>(
+        &mut self,
+        elements: super::Iter<'value, 'dwarf, P>,
+    ) -> Result<Flow, crate::Error>
+    where
+        P: crate::DebugInfoProvider,
+        V: Visit<'value, 'dwarf, P>,
+    {
+        for (index, element) in elements.enumerate() {
+            let element = element?;
+            let flow = self.enter(Segment::Index(index), |this| {
+                match this.visitor.visit_element(&this.path, index, &element)? {
+                    Flow::Continue => this.walk(&element),
+                    flow => Ok(flow),
+                }
+            })?;
+            if flow == Flow::Stop {
+                return Ok(Flow::Stop);
+            }
+        }
+        Ok(Flow::Continue)
+    }
+
+    /// The value of `result`, or `None` if it failed on an invalid pointer and
+    /// invalid pointers are skipped.
+    fn valid<T>(&self, result: Result<T, crate::Error>) -> Result<Option<T>, crate::Error> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if self.skip_invalid && err.is::<crate::error::InvalidPointer>() => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Visits the value at `segment` (with `visit`), unless doing so would
+    /// exceed the depth limit.
+    fn enter<F>(&mut self, segment: Segment, visit: F) -> Result<Flow, crate::Error>
+    where
+        F: FnOnce(&mut Self) -> Result<Flow, crate::Error>,
+    {
+        if self.path.len() >= self.depth {
+            return Ok(Flow::Skip);
+        }
+        self.path.segments.push(segment);
+        let flow = visit(self);
+        self.path.segments.pop();
+        flow
+    }
+}
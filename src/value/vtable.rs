@@ -0,0 +1,278 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A reflected vtable of a trait object.
+pub struct Vtable<'value, 'dwarf, P = crate::DefaultProvider>
+where
+    P: crate::DebugInfoProvider,
+{
+    schema: crate::schema::Vtable<'dwarf, P::Reader>,
+    value: crate::Bytes<'value>,
+    provider: &'dwarf P,
+}
+
+impl<'dwarf, R> crate::schema::Vtable<'dwarf, R>
+where
+    R: crate::gimli::Reader<Offset = std::primitive::usize>,
+{
+    pub(crate) unsafe fn with_bytes<'value, P>(
+        self,
+        provider: &'dwarf P,
+        value: crate::Bytes<'value>,
+    ) -> Result<Vtable<'value, 'dwarf, P>, crate::Error>
+    where
+        P: crate::DebugInfoProvider<Reader = R>,
+    {
+        let size = self.size()?.try_into()?;
+        let value = &value[..size];
+        Ok(Vtable {
+            schema: self,
+            value,
+            provider,
+        })
+    }
+}
+
+impl<'value, 'dwarf, P> Vtable<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    /// The schema of this value.
+    pub fn schema(&self) -> &crate::schema::Vtable<'dwarf, P::Reader> {
+        &self.schema
+    }
+
+    /// The (dynamic) address of this vtable.
+    pub fn address(&self) -> u64 {
+        self.value.as_ptr() as u64
+    }
+
+    /// The size of the type this vtable is for, in bytes.
+    pub fn size(&self) -> Result<usize, crate::Error> {
+        self.slot("size")
+    }
+
+    /// The alignment of the type this vtable is for, in bytes.
+    pub fn align(&self) -> Result<usize, crate::Error> {
+        self.slot("align")
+    }
+
+    /// The symbol of the drop glue of the type this vtable is for, or `None` if
+    /// that type has no drop glue.
+    pub fn drop_glue(&self) -> Result<Option<crate::Symbol>, crate::Error> {
+        self.resolve(self.slot("drop_in_place")?)
+    }
+
+    /// The symbols of the methods of this vtable, in slot order.
+    ///
+    /// Slots of methods that cannot be called on trait objects (e.g., those
+    /// with a `Self: Sized` bound) are `None`.
+    pub fn methods(&self) -> Result<Vec<Option<crate::Symbol>>, crate::Error> {
+        self.method_addresses()?
+            .into_iter()
+            .map(|address| self.resolve(address))
+            .collect()
+    }
+
+    /// The code addresses of the methods of this vtable, in slot order.
+    ///
+    /// Slots of methods that cannot be called on trait objects are null.
+    fn method_addresses(&self) -> Result<Vec<usize>, crate::Error> {
+        let mut methods = vec![];
+        let mut fields = self.schema.as_struct().fields()?;
+        let mut fields = fields.iter()?;
+        while let Some(field) = fields.try_next()? {
+            let name = field.name()?;
+            if matches!(&*name.to_slice()?, b"drop_in_place" | b"size" | b"align") {
+                continue;
+            }
+            let offset = field.offset()?.address(0)?.try_into()?;
+            methods.push(self.read(offset)?);
+        }
+        Ok(methods)
+    }
+
+    /// Reads the `usize` in the slot named `name`.
+    fn slot(&self, name: &str) -> Result<usize, crate::Error> {
+        let mut fields = self.schema.as_struct().fields()?;
+        let mut fields = fields.iter()?;
+        while let Some(field) = fields.try_next()? {
+            if &*field.name()?.to_slice()? == name.as_bytes() {
+                let offset = field.offset()?.address(0)?.try_into()?;
+                return self.read(offset);
+            }
+        }
+        Err(crate::error::missing_child(crate::gimli::DW_TAG_member))
+    }
+
+    /// Reads the `usize` at `offset`.
+    fn read(&self, offset: usize) -> Result<usize, crate::Error> {
+        let end = offset
+            .checked_add(std::mem::size_of::<usize>())
+            .ok_or_else(crate::error::arithmetic_overflow)?;
+        let Some(bytes) = self.value.get(offset..end) else {
+            bail!("vtable slot at offset {offset} is out of bounds")
+        };
+        Ok(unsafe { (bytes.as_ptr() as *const usize).read_unaligned() })
+    }
+
+    /// Resolves the code address `address`, if it is not null.
+    fn resolve(&self, address: usize) -> Result<Option<crate::Symbol>, crate::Error> {
+        if address == 0 {
+            return Ok(None);
+        }
+        crate::Symbol::resolve(self.provider, address as u64).map(Some)
+    }
+}
+
+/// Reflects the vtable at `address`.
+///
+/// Safety: `address` must be the address of a vtable.
+pub(crate) unsafe fn vtable_at<'value, 'dwarf, P>(
+    provider: &'dwarf P,
+    address: u64,
+) -> Result<Vtable<'value, 'dwarf, P>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    let crate::DebugInfo {
+        context,
+        unit,
+        entry,
+    } = provider.vtable_info(address)?;
+    let entry = unit.entry(entry)?;
+    let schema = crate::schema::Vtable::from_dw_tag_variable(context.dwarf(), unit, entry)?;
+    let size = schema.size()?.try_into()?;
+    let value = &*std::ptr::slice_from_raw_parts(address as *const crate::Byte, size);
+    schema.with_bytes(provider, value)
+}
+
+/// Produces the address of the vtable of `r#trait` (e.g., `core::fmt::Debug`)
+/// for `r#type`, if the program contains one.
+///
+/// Vtables whose `{vtable_type}` refers to the very DIE of `r#type` are
+/// preferred. As rustc emits a DIE for a type in each codegen unit that uses
+/// it, the vtables of other types with the same path are considered, too.
+///
+/// Safety: no other type of the program may share the path of `r#type` (as
+/// may, e.g., the types of semver-incompatible versions of a crate).
+pub(crate) unsafe fn impl_vtable<P>(
+    provider: &P,
+    r#type: &crate::schema::Type<'_, P::Reader>,
+    r#trait: &str,
+) -> Result<Option<u64>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    let find = |vtables: Vec<(String, u64)>| {
+        vtables
+            .into_iter()
+            .find_map(|(candidate, vtable)| (candidate == r#trait).then_some(vtable))
+    };
+    let (dwarf, unit, offset) = r#type.die();
+    if let Some(vtable) = find(provider.vtables_of(dwarf, unit, offset)?) {
+        return Ok(Some(vtable));
+    }
+    let Some(path) = r#type.path()? else {
+        return Ok(None);
+    };
+    Ok(find(provider.vtables_named(dwarf, &path)?))
+}
+
+/// A [`Debug`][fmt::Debug] or [`Display`][fmt::Display] impl of a reflected
+/// value, dispatched through the `fmt` method of that impl's vtable.
+///
+/// `T` is `dyn Debug` or `dyn Display`, and determines which of the two this
+/// implements.
+pub struct Impl<'value, T: ?Sized> {
+    data: crate::Bytes<'value>,
+    method: Fmt,
+    r#trait: PhantomData<fn() -> *const T>,
+}
+
+/// The signature of [`fmt::Debug::fmt`] and [`fmt::Display::fmt`], with a
+/// thin pointer in place of `&self`, as they are called through vtables.
+type Fmt = unsafe fn(*const crate::Byte, &mut fmt::Formatter<'_>) -> fmt::Result;
+
+/// Prepares to call the `fmt` method of the vtable at `vtable` on `data`.
+///
+/// Trait object pointers are never assembled, as their layout is unspecified;
+/// instead, the method is called through its pointer in the vtable, whose
+/// layout is described by DWARF. As with any trait object, the method receives
+/// a thin pointer to `data` in place of `&self`, with which it is
+/// ABI-compatible.
+///
+/// Safety: `vtable` must be a vtable of `T` for the type of `data`, where `T`
+/// is `dyn Debug` or `dyn Display`.
+pub(crate) unsafe fn fmt_impl<'value, P, T>(
+    provider: &P,
+    data: crate::Bytes<'value>,
+    vtable: u64,
+) -> Result<Impl<'value, T>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+    T: ?Sized,
+{
+    let vtable = vtable_at(provider, vtable)?;
+    let methods = vtable.method_addresses()?;
+    let &[fmt] = &methods[..] else {
+        bail!("expected a vtable with 1 method, found {}", methods.len());
+    };
+    if fmt == 0 {
+        bail!("the `fmt` method of a vtable was null");
+    }
+    Ok(Impl {
+        data,
+        method: std::mem::transmute::<usize, Fmt>(fmt),
+        r#trait: PhantomData,
+    })
+}
+
+impl<'value> fmt::Debug for Impl<'value, dyn fmt::Debug> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `fmt` is the `Debug::fmt` of the type of `data`
+        unsafe { (self.method)(self.data.as_ptr(), f) }
+    }
+}
+
+impl<'value> fmt::Display for Impl<'value, dyn fmt::Display> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `fmt` is the `Display::fmt` of the type of `data`
+        unsafe { (self.method)(self.data.as_ptr(), f) }
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Debug for Vtable<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug_struct = f.debug_struct("deflect::value::Vtable");
+        debug_struct.field("schema", &self.schema);
+        debug_struct.field("value", &self.value);
+        debug_struct.finish()
+    }
+}
+
+impl<'value, 'dwarf, P> fmt::Display for Vtable<'value, 'dwarf, P>
+where
+    P: crate::DebugInfoProvider,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.schema.name().map_err(crate::fmt_err)?;
+        let name = name.to_string_lossy().map_err(crate::fmt_err)?;
+        let mut debug_struct = f.debug_struct(&name);
+        debug_struct.field("size", &self.size().map_err(crate::fmt_err)?);
+        debug_struct.field("align", &self.align().map_err(crate::fmt_err)?);
+        for (slot, method) in self.methods().map_err(crate::fmt_err)?.iter().enumerate() {
+            let Some(symbol) = method else {
+                debug_struct.field(&format!("[{slot}]"), &format_args!("<vacant>"));
+                continue;
+            };
+            let method = symbol.name().and_then(|name| name.rsplit("::").next());
+            let method = method.map_or_else(|| format!("[{slot}]"), str::to_owned);
+            debug_struct.field(&method, &crate::DebugDisplay(symbol));
+        }
+        debug_struct.finish()
+    }
+}
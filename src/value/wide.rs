@@ -0,0 +1,184 @@
+//! Dynamically sized structs, reached through wide pointers.
+
+/// The metadata of a wide pointer to a dynamically sized struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metadata {
+    /// The number of elements of the struct's `[T]` tail.
+    Length(usize),
+    /// The address of the vtable of the struct's `dyn Trait` tail.
+    Vtable(usize),
+}
+
+/// The dynamic layout of a dynamically sized struct.
+pub(crate) struct Layout {
+    /// The size of the struct, in bytes.
+    pub(crate) size: u64,
+    /// The alignment of the struct, in bytes.
+    align: u64,
+}
+
+/// Reads the data pointer and metadata of the wide pointer `value`.
+pub(crate) fn read(
+    value: crate::Bytes<'_>,
+    wide: crate::schema::Wide,
+) -> Result<(*const crate::Byte, Metadata), crate::Error> {
+    let pointer = read_usize(value, wide.pointer)?;
+    let metadata = read_usize(value, wide.metadata)?;
+    let metadata = if wide.is_dyn {
+        Metadata::Vtable(metadata)
+    } else {
+        Metadata::Length(metadata)
+    };
+    Ok((pointer as *const crate::Byte, metadata))
+}
+
+/// Dereferences the wide pointer `value` to a struct of type `r#type`.
+///
+/// Safety: `value` must be a wide pointer to a valid value of `r#type`.
+pub(crate) unsafe fn deref<'value, 'dwarf, P>(
+    provider: &'dwarf P,
+    value: crate::Bytes<'value>,
+    wide: crate::schema::Wide,
+    r#type: crate::schema::Type<'dwarf, P::Reader>,
+) -> Result<super::Value<'value, 'dwarf, P>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    let (pointer, metadata) = read(value, wide)?;
+    let schema: crate::schema::Struct<'dwarf, P::Reader> = r#type.try_into()?;
+    let layout = layout(&schema, metadata)?;
+    let size = layout.size.try_into()?;
+    crate::safety::check(pointer as usize, size, layout.align.try_into()?)?;
+    let value = &*std::ptr::slice_from_raw_parts(pointer, size);
+    schema
+        .with_metadata(provider, value, metadata)
+        .map(super::Value::Struct)
+}
+
+/// Computes the layout of the dynamically sized struct `schema`, given the
+/// `metadata` of a pointer to it.
+pub(crate) fn layout<R>(
+    schema: &crate::schema::Struct<'_, R>,
+    metadata: Metadata,
+) -> Result<Layout, crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    let Some(tail) = schema.unsized_tail()? else {
+        bail!("`{}` is not dynamically sized", schema.display_name()?)
+    };
+    let offset = crate::schema::Offset::from_die(tail.unit(), tail.entry())?.address(0)?;
+    let (tail_size, tail_align) = tail_size_align(&tail.r#type()?, metadata)?;
+    let tail_offset = round_up(offset, tail_align)?;
+    let align = schema.align()?.unwrap_or(1).max(tail_align);
+    let size = tail_offset
+        .checked_add(tail_size)
+        .ok_or_else(crate::error::arithmetic_overflow)?;
+    Ok(Layout {
+        size: round_up(size, align)?,
+        align,
+    })
+}
+
+/// Computes the size and alignment of the unsized tail `r#type`, given the
+/// `metadata` of a pointer to its struct.
+fn tail_size_align<R>(
+    r#type: &crate::schema::Type<'_, R>,
+    metadata: Metadata,
+) -> Result<(u64, u64), crate::Error>
+where
+    R: crate::gimli::Reader<Offset = usize>,
+{
+    match (r#type, metadata) {
+        (crate::schema::Type::UnsizedSlice(slice), Metadata::Length(len)) => {
+            // the offset of the tail already accounts for the alignment of
+            // its elements
+            let size = slice
+                .elt_type()?
+                .size()?
+                .checked_mul(len as u64)
+                .ok_or_else(crate::error::arithmetic_overflow)?;
+            Ok((size, 1))
+        }
+        (crate::schema::Type::Struct(schema), Metadata::Vtable(vtable)) if schema.is_dyn()? => {
+            // rustc's vtables begin with `drop_in_place`, `size` and `align`
+            let word = std::mem::size_of::<usize>();
+            crate::safety::check(vtable, 3 * word, word)?;
+            let vtable = vtable as *const usize;
+            let (size, align) = unsafe { (vtable.add(1).read(), vtable.add(2).read()) };
+            Ok((size as u64, align as u64))
+        }
+        (crate::schema::Type::Struct(schema), metadata) => {
+            let layout = layout(schema, metadata)?;
+            Ok((layout.size, layout.align))
+        }
+        (r#type, metadata) => bail!("`{type}` is not an unsized tail with {metadata:?}"),
+    }
+}
+
+/// Produces the value of the unsized tail `field` of the struct `value`, given
+/// the `metadata` of a pointer to that struct.
+///
+/// Safety: `value` must be a valid struct with the unsized tail `field` and
+/// pointer metadata `metadata`.
+pub(crate) unsafe fn tail<'value, 'dwarf, P>(
+    provider: &'dwarf P,
+    field: &crate::schema::Field<'dwarf, P::Reader>,
+    value: crate::Bytes<'value>,
+    metadata: Metadata,
+) -> Result<super::Value<'value, 'dwarf, P>, crate::Error>
+where
+    P: crate::DebugInfoProvider,
+{
+    let offset = crate::schema::Offset::from_die(field.unit(), field.entry())?.address(0)?;
+    let r#type = field.r#type()?;
+    let (size, align) = tail_size_align(&r#type, metadata)?;
+    let start = round_up(offset, align)?.try_into()?;
+    let end = start + usize::try_from(size)?;
+    let Some(value) = value.get(start..end) else {
+        bail!(
+            "unsized tail spans bytes {start}..{end}, but only {} are available",
+            value.len()
+        )
+    };
+    match (r#type, metadata) {
+        (crate::schema::Type::UnsizedSlice(slice), Metadata::Length(len)) => slice
+            .with_len(provider, value, len)
+            .map(super::Value::UnsizedSlice),
+        (crate::schema::Type::Struct(schema), Metadata::Vtable(vtable)) if schema.is_dyn()? => {
+            let vtable = super::vtable::vtable_at(provider, vtable as u64)?;
+            let Some(r#type) = vtable.schema().r#type()? else {
+                bail!(
+                    "could not determine the concrete type of `{}`",
+                    schema.display_name()?
+                )
+            };
+            super::Value::with_type(r#type, value, provider)
+        }
+        (crate::schema::Type::Struct(schema), metadata) => schema
+            .with_metadata(provider, value, metadata)
+            .map(super::Value::Struct),
+        (r#type, metadata) => bail!("`{type}` is not an unsized tail with {metadata:?}"),
+    }
+}
+
+/// Reads the `usize` at `offset` of `value`.
+fn read_usize(value: crate::Bytes<'_>, offset: u64) -> Result<usize, crate::Error> {
+    let offset: usize = offset.try_into()?;
+    let end = offset
+        .checked_add(std::mem::size_of::<usize>())
+        .ok_or_else(crate::error::arithmetic_overflow)?;
+    let Some(bytes) = value.get(offset..end) else {
+        bail!("wide pointer field at offset {offset} is out of bounds")
+    };
+    Ok(unsafe { (bytes.as_ptr() as *const usize).read_unaligned() })
+}
+
+/// Rounds `offset` up to a multiple of `align`.
+fn round_up(offset: u64, align: u64) -> Result<u64, crate::Error> {
+    let align = align.max(1);
+    offset
+        .checked_add(align - 1)
+        .map(|offset| offset / align * align)
+        .ok_or_else(crate::error::arithmetic_overflow)
+}
@@ -0,0 +1,39 @@
+//! Compiles the C fixtures, with full debug info, into a static library.
+
+/// The fixtures, and the flags to compile each with.
+const FIXTURES: &[(&str, &[&str])] = &[
+    ("c/bitfields.c", &["-gdwarf-5"]),
+    // DWARF 4 describes bitfields with `DW_AT_bit_offset`
+    ("c/bitfields_dwarf4.c", &["-gdwarf-4", "-gstrict-dwarf"]),
+    ("c/typedefs.c", &[]),
+    ("c/ffi.c", &[]),
+    ("c/nullptr.cpp", &["-fno-exceptions", "-fno-rtti"]),
+    ("c/classes.cpp", &["-fno-exceptions", "-fno-rtti"]),
+    ("c/arrays.c", &[]),
+    // distinct types of the same name
+    ("c/shapes_a.c", &[]),
+    ("c/shapes_b.c", &[]),
+    // hand-written debug info, for bounds that C compilers no longer emit
+    ("c/bounds.s", &[]),
+    // hand-written debug info, for calling conventions that GCC does not emit
+    ("c/callbacks.s", &[]),
+];
+
+fn main() {
+    let mut build = cc::Build::new();
+    build.debug(true).opt_level(0);
+    for (path, flags) in FIXTURES {
+        println!("cargo:rerun-if-changed={path}");
+        let mut build = build.clone();
+        build.file(path);
+        if path.ends_with(".cpp") {
+            // the C++ fixtures do not use the C++ standard library at runtime
+            build.cpp(true).cpp_link_stdlib(None);
+        }
+        for flag in *flags {
+            build.flag(flag);
+        }
+        let name = path.trim_start_matches("c/").replace('.', "_");
+        build.compile(&name);
+    }
+}
@@ -0,0 +1,10 @@
+/* Multi-dimensional, zero-length and flexible arrays. */
+
+struct Arrays {
+    short cube[2][2][2];
+    int empty[0];
+    int len;
+    int flexible[];
+};
+
+struct Arrays arrays = { { { { 1, 2 }, { 3, 4 } }, { { 5, 6 }, { 7, 8 } } }, {}, 2 };
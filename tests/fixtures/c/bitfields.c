@@ -0,0 +1,13 @@
+/* Bitfields, described with `DW_AT_data_bit_offset`. */
+
+#include <stdint.h>
+
+struct Bitfields {
+    uint32_t unsigned_field : 3;
+    int32_t signed_field : 5;
+    unsigned int wide_field : 12;
+    signed char char_field : 4;
+    _Bool flag : 1;
+};
+
+struct Bitfields bitfields = { 5, -3, 1000, -2, 1 };
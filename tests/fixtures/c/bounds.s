@@ -0,0 +1,114 @@
+# Arrays with bounds that C compilers no longer emit: an explicit
+# `DW_AT_lower_bound`, and the `-1` upper bound with which older GCCs
+# described flexible array members.
+#
+# struct Bounds {
+#     int from_one[1..3];
+#     int count;
+#     int flexible[0..-1];
+# };
+
+    .data
+    .globl bounds
+    .type bounds, @object
+    .size bounds, 16
+    .p2align 2
+bounds:
+    .long 1, 2, 3, 3
+
+    .section .debug_abbrev,"",@progbits
+.Labbrev:
+    # DW_TAG_compile_unit, with children
+    .uleb128 1, 0x11
+    .byte 1
+    .uleb128 0x03, 0x08             # DW_AT_name, DW_FORM_string
+    .uleb128 0x13, 0x05             # DW_AT_language, DW_FORM_data2
+    .byte 0, 0
+    # DW_TAG_base_type
+    .uleb128 2, 0x24
+    .byte 0
+    .uleb128 0x03, 0x08             # DW_AT_name, DW_FORM_string
+    .uleb128 0x0b, 0x0b             # DW_AT_byte_size, DW_FORM_data1
+    .uleb128 0x3e, 0x0b             # DW_AT_encoding, DW_FORM_data1
+    .byte 0, 0
+    # DW_TAG_structure_type, with children
+    .uleb128 3, 0x13
+    .byte 1
+    .uleb128 0x03, 0x08             # DW_AT_name, DW_FORM_string
+    .uleb128 0x0b, 0x0b             # DW_AT_byte_size, DW_FORM_data1
+    .byte 0, 0
+    # DW_TAG_member
+    .uleb128 4, 0x0d
+    .byte 0
+    .uleb128 0x03, 0x08             # DW_AT_name, DW_FORM_string
+    .uleb128 0x49, 0x13             # DW_AT_type, DW_FORM_ref4
+    .uleb128 0x38, 0x0b             # DW_AT_data_member_location, DW_FORM_data1
+    .byte 0, 0
+    # DW_TAG_array_type, with children
+    .uleb128 5, 0x01
+    .byte 1
+    .uleb128 0x49, 0x13             # DW_AT_type, DW_FORM_ref4
+    .byte 0, 0
+    # DW_TAG_subrange_type, with lower and upper bounds
+    .uleb128 6, 0x21
+    .byte 0
+    .uleb128 0x49, 0x13             # DW_AT_type, DW_FORM_ref4
+    .uleb128 0x22, 0x0b             # DW_AT_lower_bound, DW_FORM_data1
+    .uleb128 0x2f, 0x0b             # DW_AT_upper_bound, DW_FORM_data1
+    .byte 0, 0
+    # DW_TAG_subrange_type, with a four-byte upper bound
+    .uleb128 7, 0x21
+    .byte 0
+    .uleb128 0x49, 0x13             # DW_AT_type, DW_FORM_ref4
+    .uleb128 0x2f, 0x06             # DW_AT_upper_bound, DW_FORM_data4
+    .byte 0, 0
+    .byte 0
+
+    .section .debug_info,"",@progbits
+.Linfo:
+    .long .Linfo_end - .Linfo_version
+.Linfo_version:
+    .value 4                        # DWARF version
+    .long .Labbrev
+    .byte 8                         # address size
+    .uleb128 1
+    .string "bounds.s"
+    .value 0x0c                     # DW_LANG_C99
+.Lint:
+    .uleb128 2
+    .string "int"
+    .byte 4, 0x05                   # DW_ATE_signed
+    .uleb128 3
+    .string "Bounds"
+    .byte 16
+    .uleb128 4
+    .string "from_one"
+    .long .Lfrom_one - .Linfo
+    .byte 0
+    .uleb128 4
+    .string "count"
+    .long .Lint - .Linfo
+    .byte 12
+    .uleb128 4
+    .string "flexible"
+    .long .Lflexible - .Linfo
+    .byte 16
+    .byte 0
+.Lfrom_one:
+    .uleb128 5
+    .long .Lint - .Linfo
+    .uleb128 6
+    .long .Lint - .Linfo
+    .byte 1, 3
+    .byte 0
+.Lflexible:
+    .uleb128 5
+    .long .Lint - .Linfo
+    .uleb128 7
+    .long .Lint - .Linfo
+    .long 0xffffffff
+    .byte 0
+    .byte 0
+.Linfo_end:
+
+    .section .note.GNU-stack,"",@progbits
@@ -0,0 +1,141 @@
+# Function pointers with hand-written debug info, since GCC does not emit
+# `DW_AT_calling_convention` for the non-default calling conventions.
+#
+# struct Callbacks {
+#     int (*plain)(int);
+#     int (*variadic)(int, ...);
+#     int (__stdcall *stdcall)(int);
+# };
+
+    .data
+    .globl callbacks
+    .type callbacks, @object
+    .size callbacks, 24
+    .p2align 3
+callbacks:
+    .quad 0, 0, 0
+
+    .section .debug_abbrev,"",@progbits
+.Labbrev:
+    # DW_TAG_compile_unit, with children
+    .uleb128 1, 0x11
+    .byte 1
+    .uleb128 0x03, 0x08             # DW_AT_name, DW_FORM_string
+    .uleb128 0x13, 0x05             # DW_AT_language, DW_FORM_data2
+    .byte 0, 0
+    # DW_TAG_base_type
+    .uleb128 2, 0x24
+    .byte 0
+    .uleb128 0x03, 0x08             # DW_AT_name, DW_FORM_string
+    .uleb128 0x0b, 0x0b             # DW_AT_byte_size, DW_FORM_data1
+    .uleb128 0x3e, 0x0b             # DW_AT_encoding, DW_FORM_data1
+    .byte 0, 0
+    # DW_TAG_structure_type, with children
+    .uleb128 3, 0x13
+    .byte 1
+    .uleb128 0x03, 0x08             # DW_AT_name, DW_FORM_string
+    .uleb128 0x0b, 0x0b             # DW_AT_byte_size, DW_FORM_data1
+    .byte 0, 0
+    # DW_TAG_member
+    .uleb128 4, 0x0d
+    .byte 0
+    .uleb128 0x03, 0x08             # DW_AT_name, DW_FORM_string
+    .uleb128 0x49, 0x13             # DW_AT_type, DW_FORM_ref4
+    .uleb128 0x38, 0x0b             # DW_AT_data_member_location, DW_FORM_data1
+    .byte 0, 0
+    # DW_TAG_pointer_type
+    .uleb128 5, 0x0f
+    .byte 0
+    .uleb128 0x0b, 0x0b             # DW_AT_byte_size, DW_FORM_data1
+    .uleb128 0x49, 0x13             # DW_AT_type, DW_FORM_ref4
+    .byte 0, 0
+    # DW_TAG_subroutine_type, with children
+    .uleb128 6, 0x15
+    .byte 1
+    .uleb128 0x27, 0x19             # DW_AT_prototyped, DW_FORM_flag_present
+    .uleb128 0x49, 0x13             # DW_AT_type, DW_FORM_ref4
+    .byte 0, 0
+    # DW_TAG_subroutine_type, with a calling convention and children
+    .uleb128 7, 0x15
+    .byte 1
+    .uleb128 0x27, 0x19             # DW_AT_prototyped, DW_FORM_flag_present
+    .uleb128 0x49, 0x13             # DW_AT_type, DW_FORM_ref4
+    .uleb128 0x36, 0x0b             # DW_AT_calling_convention, DW_FORM_data1
+    .byte 0, 0
+    # DW_TAG_formal_parameter
+    .uleb128 8, 0x05
+    .byte 0
+    .uleb128 0x49, 0x13             # DW_AT_type, DW_FORM_ref4
+    .byte 0, 0
+    # DW_TAG_unspecified_parameters
+    .uleb128 9, 0x18
+    .byte 0
+    .byte 0, 0
+    .byte 0
+
+    .section .debug_info,"",@progbits
+.Linfo:
+    .long .Linfo_end - .Linfo_version
+.Linfo_version:
+    .value 4                        # DWARF version
+    .long .Labbrev
+    .byte 8                         # address size
+    .uleb128 1
+    .string "callbacks.s"
+    .value 0x0c                     # DW_LANG_C99
+.Lint:
+    .uleb128 2
+    .string "int"
+    .byte 4, 0x05                   # DW_ATE_signed
+    .uleb128 3
+    .string "Callbacks"
+    .byte 24
+    .uleb128 4
+    .string "plain"
+    .long .Lplain - .Linfo
+    .byte 0
+    .uleb128 4
+    .string "variadic"
+    .long .Lvariadic - .Linfo
+    .byte 8
+    .uleb128 4
+    .string "stdcall"
+    .long .Lstdcall - .Linfo
+    .byte 16
+    .byte 0
+.Lplain:
+    .uleb128 5
+    .byte 8
+    .long .Lplain_fn - .Linfo
+.Lplain_fn:
+    .uleb128 6
+    .long .Lint - .Linfo
+    .uleb128 8
+    .long .Lint - .Linfo
+    .byte 0
+.Lvariadic:
+    .uleb128 5
+    .byte 8
+    .long .Lvariadic_fn - .Linfo
+.Lvariadic_fn:
+    .uleb128 6
+    .long .Lint - .Linfo
+    .uleb128 8
+    .long .Lint - .Linfo
+    .uleb128 9
+    .byte 0
+.Lstdcall:
+    .uleb128 5
+    .byte 8
+    .long .Lstdcall_fn - .Linfo
+.Lstdcall_fn:
+    .uleb128 7
+    .long .Lint - .Linfo
+    .byte 0xb1                      # DW_CC_LLVM_X86StdCall
+    .uleb128 8
+    .long .Lint - .Linfo
+    .byte 0
+    .byte 0
+.Linfo_end:
+
+    .section .note.GNU-stack,"",@progbits
@@ -0,0 +1,37 @@
+// C++ classes, with a base class, a vtable pointer, static members and
+// references.
+
+struct Base {
+    int base_field;
+
+    constexpr Base(int base_field) : base_field(base_field) {}
+};
+
+class Derived : public Base {
+  public:
+    static int instances;
+    static const int limit = 8;
+
+    int derived_field;
+    int &lvalue;
+    int &&rvalue;
+
+    constexpr Derived(int base_field, int derived_field, int &lvalue, int &&rvalue)
+        : Base(base_field), derived_field(derived_field), lvalue(lvalue),
+          rvalue(static_cast<int &&>(rvalue)) {}
+
+    virtual int area() const;
+};
+
+int Derived::area() const {
+    return base_field * derived_field;
+}
+
+int Derived::instances = 3;
+
+static int lvalue_target = 10;
+static int rvalue_target = 20;
+
+extern "C" {
+Derived derived(1, 2, lvalue_target, static_cast<int &&>(rvalue_target));
+}
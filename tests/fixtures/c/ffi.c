@@ -0,0 +1,37 @@
+/* C base types, enums, anonymous structs, arrays and pointers. */
+
+enum Color { RED, GREEN = 5, BLUE };
+
+struct Primitives {
+    char c;
+    signed char sc;
+    unsigned char uc;
+    short s;
+    unsigned short us;
+    int i;
+    unsigned int ui;
+    long l;
+    unsigned long ul;
+    long long ll;
+    unsigned long long ull;
+    float f;
+    double d;
+    _Bool b;
+    enum Color color;
+};
+
+struct Ffi {
+    struct Primitives primitives;
+    struct { int x; int y; } point;
+    void *opaque;
+    const char *name;
+    int grid[2][3];
+};
+
+struct Ffi ffi = {
+    { 'a', -2, 200, -300, 60000, -70000, 4000000000u, -5, 6, -7, 8, 1.5f, -2.25, 1, GREEN },
+    { 3, -4 },
+    (void *)&ffi,
+    "ffi",
+    { { 1, 2, 3 }, { 4, 5, 6 } },
+};
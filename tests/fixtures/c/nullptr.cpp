@@ -0,0 +1,12 @@
+// A C++ `decltype(nullptr)`, described with `DW_TAG_unspecified_type`.
+
+#include <cstddef>
+
+struct Nullable {
+    std::nullptr_t null;
+    int tag;
+};
+
+extern "C" {
+Nullable nullable = { nullptr, 9 };
+}
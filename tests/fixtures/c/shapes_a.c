@@ -0,0 +1,11 @@
+/* A `struct Shape` that is distinct from that of `shapes_b.c`, and a
+   `struct Extent` that is identical to it. */
+
+struct Extent { int width; int height; };
+
+struct Shape {
+    int sides;
+    struct Extent extent;
+};
+
+struct Shape shape_a = { 4, { 2, 3 } };
@@ -0,0 +1,11 @@
+/* A `struct Shape` that is distinct from that of `shapes_a.c`, and a
+   `struct Extent` that is identical to it. */
+
+struct Extent { int width; int height; };
+
+struct Shape {
+    double radius;
+    struct Extent extent;
+};
+
+struct Shape shape_b = { 1.5, { 3, 3 } };
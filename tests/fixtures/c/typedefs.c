@@ -0,0 +1,19 @@
+/* Typedefs and type qualifiers. */
+
+#include <stdint.h>
+
+typedef uint32_t Id;
+typedef struct { Id id; uint8_t level; } Account;
+
+struct Qualified {
+    const int32_t constant;
+    volatile int16_t volatile_field;
+    const volatile uint8_t both;
+    Id id;
+    const Account account;
+    int32_t *restrict restricted;
+};
+
+int32_t qualified_target = 42;
+
+struct Qualified qualified = { -1, 7, 255, 1000, { 3, 4 }, &qualified_target };
@@ -0,0 +1,93 @@
+//! C fixtures for the tests of deflect.
+//!
+//! Each fixture is compiled with full debug info, and exposes values of its
+//! types as statics.
+
+use std::ffi::c_void;
+
+extern "C" {
+    #[link_name = "bitfields"]
+    static BITFIELDS: u8;
+    #[link_name = "legacy_bitfields"]
+    static LEGACY_BITFIELDS: u8;
+    #[link_name = "qualified"]
+    static QUALIFIED: u8;
+    #[link_name = "nullable"]
+    static NULLABLE: u8;
+    #[link_name = "ffi"]
+    static FFI: u8;
+    #[link_name = "derived"]
+    static DERIVED: u8;
+    #[link_name = "_ZN7Derived9instancesE"]
+    static DERIVED_INSTANCES: i32;
+    #[link_name = "arrays"]
+    static ARRAYS: u8;
+    #[link_name = "bounds"]
+    static BOUNDS: u8;
+    #[link_name = "callbacks"]
+    static CALLBACKS: u8;
+    #[link_name = "shape_a"]
+    static SHAPE_A: u8;
+    #[link_name = "shape_b"]
+    static SHAPE_B: u8;
+}
+
+/// The `struct Bitfields` of `c/bitfields.c`.
+pub fn bitfields() -> *const c_void {
+    unsafe { &BITFIELDS as *const u8 as _ }
+}
+
+/// The `struct LegacyBitfields` of `c/bitfields_dwarf4.c`.
+pub fn legacy_bitfields() -> *const c_void {
+    unsafe { &LEGACY_BITFIELDS as *const u8 as _ }
+}
+
+/// The `struct Qualified` of `c/typedefs.c`.
+pub fn qualified() -> *const c_void {
+    unsafe { &QUALIFIED as *const u8 as _ }
+}
+
+/// The `struct Nullable` of `c/nullptr.cpp`.
+pub fn nullable() -> *const c_void {
+    unsafe { &NULLABLE as *const u8 as _ }
+}
+
+/// The `struct Ffi` of `c/ffi.c`.
+pub fn ffi() -> *const c_void {
+    unsafe { &FFI as *const u8 as _ }
+}
+
+/// The `class Derived` of `c/classes.cpp`.
+pub fn derived() -> *const c_void {
+    unsafe { &DERIVED as *const u8 as _ }
+}
+
+/// The static member `Derived::instances` of `c/classes.cpp`.
+pub fn derived_instances() -> i32 {
+    unsafe { DERIVED_INSTANCES }
+}
+
+/// The `struct Arrays` of `c/arrays.c`.
+pub fn arrays() -> *const c_void {
+    unsafe { &ARRAYS as *const u8 as _ }
+}
+
+/// The `struct Bounds` of `c/bounds.s`.
+pub fn bounds() -> *const c_void {
+    unsafe { &BOUNDS as *const u8 as _ }
+}
+
+/// The `struct Callbacks` of `c/callbacks.s`.
+pub fn callbacks() -> *const c_void {
+    unsafe { &CALLBACKS as *const u8 as _ }
+}
+
+/// The `struct Shape` of `c/shapes_a.c`.
+pub fn shape_a() -> *const c_void {
+    unsafe { &SHAPE_A as *const u8 as _ }
+}
+
+/// The `struct Shape` of `c/shapes_b.c`.
+pub fn shape_b() -> *const c_void {
+    unsafe { &SHAPE_B as *const u8 as _ }
+}
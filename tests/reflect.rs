@@ -1,4 +1,5 @@
 use std::fmt;
+#[allow(dead_code)]
 struct DisplayDebug<T>(T);
 
 impl<T> fmt::Display for DisplayDebug<T>
@@ -33,7 +34,7 @@ fn unit_struct() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn tuple_struct() -> Result<(), Box<dyn std::error::Error>> {
-    struct TupleStruct(u8);
+    struct TupleStruct(#[allow(dead_code)] u8);
     let erased: &dyn deflect::Reflect = &TupleStruct(42);
     let context = deflect::default_provider()?;
     let value = erased.reflect(&context)?;
@@ -54,6 +55,768 @@ fn braced_struct() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn fieldless_enum() -> Result<(), Box<dyn std::error::Error>> {
+    #[allow(dead_code)]
+    enum Level {
+        Low = 1,
+        Mid = 5,
+        High = 9,
+    }
+    #[allow(dead_code)]
+    #[repr(i16)]
+    enum Signed {
+        Neg = -3,
+        Pos = 4,
+    }
+    let context = deflect::default_provider()?;
+    let erased: &dyn deflect::Reflect = &Level::Mid;
+    assert_eq!(erased.reflect(&context)?.to_string(), "Level::Mid");
+    let erased: &dyn deflect::Reflect = &Signed::Neg;
+    assert_eq!(erased.reflect(&context)?.to_string(), "Signed::Neg");
+    Ok(())
+}
+
+#[test]
+fn nested_arrays() -> Result<(), Box<dyn std::error::Error>> {
+    let erased: &dyn deflect::Reflect = &[[1u8, 2], [3, 4], [5, 6]];
+    let context = deflect::default_provider()?;
+    let value: deflect::value::Array = erased.reflect(&context)?.try_into()?;
+    assert_eq!(value.schema().dimensions()?, [Some(3)]);
+    assert!(!value.schema().is_unbounded()?);
+    let rows: Vec<_> = value.iter()?.map(|row| row.unwrap().to_string()).collect();
+    assert_eq!(rows, ["[1, 2]", "[3, 4]", "[5, 6]"]);
+    Ok(())
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn simd_vectors() -> Result<(), Box<dyn std::error::Error>> {
+    use std::arch::x86_64::{__m128, _mm_set_ps};
+    let vector: __m128 = unsafe { _mm_set_ps(4.0, 3.0, 2.0, 1.0) };
+    let erased: &dyn deflect::Reflect = &vector;
+    let context = deflect::default_provider()?;
+    let value: deflect::value::Vector = erased.reflect(&context)?.try_into()?;
+    assert_eq!(value.schema().lanes()?, 4);
+    assert_eq!(value.schema().align()?, 16);
+    assert!(matches!(
+        value.schema().lane_type()?,
+        deflect::schema::Type::f32(_)
+    ));
+    assert_eq!(value.to_string(), "[1.0, 2.0, 3.0, 4.0]");
+    let lane: f32 = value.lane(2)?.unwrap().try_into()?;
+    assert_eq!(lane, 3.0);
+    assert!(value.lane(4)?.is_none());
+
+    // over-aligned arrays are not vectors
+    #[repr(align(16))]
+    struct Aligned(#[allow(dead_code)] [u32; 4]);
+    let erased: &dyn deflect::Reflect = &Aligned([1, 2, 3, 4]);
+    let value: deflect::value::Struct = erased.reflect(&context)?.try_into()?;
+    assert_eq!(value.to_string(), "Aligned { __0: [1, 2, 3, 4] }");
+    Ok(())
+}
+
+#[test]
+fn value_mut() -> Result<(), Box<dyn std::error::Error>> {
+    #[derive(Debug, PartialEq)]
+    enum Mode {
+        Off,
+        Fixed(u16),
+        Auto {
+            min: u8,
+            max: u8,
+        },
+        #[allow(dead_code)]
+        Flag(bool),
+    }
+    struct Config {
+        level: u32,
+        name: String,
+        mode: Mode,
+    }
+    let context = deflect::default_provider()?;
+    let mut config = Config {
+        level: 1,
+        name: String::from("a"),
+        mode: Mode::Off,
+    };
+    let other = Config {
+        level: 7,
+        name: String::new(),
+        mode: Mode::Fixed(3),
+    };
+
+    let erased: &mut dyn deflect::Reflect = &mut config;
+    let mut value = erased.reflect_mut(&context)?;
+    // SAFETY: `Config` has no invariants, and its fields are of the types set
+    unsafe {
+        value.field_mut("name")?.unwrap().set(String::from("b"))?;
+        assert!(value.field_mut("level")?.unwrap().set(9u64).is_err());
+        assert!(value.field_mut("missing")?.is_none());
+    }
+
+    let other: deflect::value::Struct = (&other as &dyn deflect::Reflect)
+        .reflect(&context)?
+        .try_into()?;
+    let level = other.field("level")?.unwrap().value()?;
+    let name = other.field("name")?.unwrap().value()?;
+    // SAFETY: `Config` has no invariants
+    unsafe {
+        value.field_mut("level")?.unwrap().assign(&level)?;
+        assert!(value.field_mut("name")?.unwrap().assign(&name).is_err());
+    }
+
+    // SAFETY: the fields of `Mode` are primitives, whose validity is checked
+    let mut mode = unsafe { value.field_mut("mode")?.unwrap() };
+    unsafe {
+        mode.set_variant("Auto", &[2, 5])?;
+        assert!(mode.set_variant("Fixed", &[0]).is_err());
+        assert!(mode.set_variant("Flag", &[2]).is_err());
+        assert!(mode.set_variant("Unknown", &[]).is_err());
+        mode.field_mut("max")?.unwrap().set(6u8)?;
+    }
+
+    // the bytes of a `u32` may be assigned from another `u32`, safely
+    let mut level = 1u32;
+    let mut value = (&mut level as &mut dyn deflect::Reflect).reflect_mut(&context)?;
+    value.assign(&(&5u32 as &dyn deflect::Reflect).reflect(&context)?)?;
+    assert!(value
+        .assign(&(&5u64 as &dyn deflect::Reflect).reflect(&context)?)
+        .is_err());
+    assert_eq!(level, 5);
+
+    assert_eq!(config.level, 7);
+    assert_eq!(config.name, "b");
+    assert_eq!(config.mode, Mode::Auto { min: 2, max: 6 });
+    Ok(())
+}
+
+#[test]
+fn builder() -> Result<(), Box<dyn std::error::Error>> {
+    #[derive(Debug, PartialEq)]
+    enum Mode {
+        Off,
+        Auto { min: u8, max: u8 },
+    }
+    #[derive(Debug, PartialEq)]
+    struct Config {
+        levels: [u32; 2],
+        name: String,
+        mode: Mode,
+    }
+    let context = deflect::default_provider()?;
+    let template = Config {
+        levels: [0, 0],
+        name: String::new(),
+        mode: Mode::Off,
+    };
+    let template: deflect::value::Struct = (&template as &dyn deflect::Reflect)
+        .reflect(&context)?
+        .try_into()?;
+
+    // SAFETY: `Config` has no invariants, and no other type shares its path
+    let mut builder =
+        unsafe { deflect::value::Builder::new(template.schema().clone().into(), &context)? };
+    builder.field("name")?.set(String::from("built"))?;
+    builder.field("levels")?.element(0)?.set(1u32)?;
+    assert!(builder.field("levels")?.element(2).is_err());
+    assert!(builder.field("name")?.set(1u32).is_err());
+    builder
+        .field("mode")?
+        .variant("Auto")?
+        .field("min")?
+        .set(2u8)?;
+
+    let err = builder.check().unwrap_err();
+    assert_eq!(err.to_string(), "`Config.levels[1]` is uninitialized");
+    builder.field("levels")?.element(1)?.set(3u32)?;
+    let err = builder.check().unwrap_err();
+    assert_eq!(err.to_string(), "`Config.mode::Auto.max` is uninitialized");
+    builder.field("mode")?.field("max")?.set(4u8)?;
+
+    let built = builder.finish()?;
+    assert!(!built.is::<Mode>()?);
+    let config = unsafe { built.into_box::<Config>()? };
+    assert_eq!(
+        *config,
+        Config {
+            levels: [1, 3],
+            name: String::from("built"),
+            mode: Mode::Auto { min: 2, max: 4 },
+        }
+    );
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize() -> Result<(), Box<dyn std::error::Error>> {
+    use bincode::Options;
+    use serde::de::DeserializeSeed;
+    #[derive(Debug, PartialEq)]
+    enum Shape {
+        Empty,
+        Circle(u8),
+        Rect { w: u8, h: u8 },
+    }
+    #[derive(Debug, PartialEq)]
+    struct Point(i16, i16);
+    #[derive(Debug, PartialEq)]
+    struct Fixture {
+        name: String,
+        origin: Point,
+        tags: [char; 2],
+        shapes: [Shape; 3],
+        ratio: f64,
+        limit: Option<u32>,
+    }
+    let context = deflect::default_provider()?;
+    let template = Fixture {
+        name: String::new(),
+        origin: Point(0, 0),
+        tags: ['a', 'b'],
+        shapes: [Shape::Empty, Shape::Circle(0), Shape::Rect { w: 0, h: 0 }],
+        ratio: 0.0,
+        limit: None,
+    };
+    let template: deflect::value::Struct = (&template as &dyn deflect::Reflect)
+        .reflect(&context)?
+        .try_into()?;
+    let schema: deflect::schema::Type<_> = template.schema().clone().into();
+
+    let json = r#"{
+        "name": "fixture",
+        "origin": [3, -4],
+        "tags": ["x", "y"],
+        "shapes": [{ "Rect": { "w": 2, "h": 5 } }, "Empty", { "Circle": 9 }],
+        "ratio": 0.5,
+        "limit": 7
+    }"#;
+    // SAFETY: `Fixture` has no invariants, and no other type shares the path
+    // of `String`
+    let seed = unsafe { deflect::value::Seed::new(schema.clone(), &context) };
+    let fixture = seed.deserialize(&mut serde_json::Deserializer::from_str(json))?;
+    assert_eq!(
+        // SAFETY: no other type shares the path of `Fixture`
+        *unsafe { fixture.into_box::<Fixture>()? },
+        Fixture {
+            name: String::from("fixture"),
+            origin: Point(3, -4),
+            tags: ['x', 'y'],
+            shapes: [Shape::Rect { w: 2, h: 5 }, Shape::Empty, Shape::Circle(9)],
+            ratio: 0.5,
+            limit: Some(7),
+        }
+    );
+
+    // values round-trip through formats that are not self-describing
+    let fixture = Fixture {
+        name: String::from("binary"),
+        origin: Point(-1, 2),
+        tags: ['z', 'w'],
+        shapes: [Shape::Circle(3), Shape::Rect { w: 4, h: 1 }, Shape::Empty],
+        ratio: -0.25,
+        limit: None,
+    };
+    let value = (&fixture as &dyn deflect::Reflect).reflect(&context)?;
+    let bytes = bincode::serialize(&value)?;
+    let seed = unsafe { deflect::value::Seed::new(schema.clone(), &context) };
+    let options = bincode::options()
+        .with_fixint_encoding()
+        .allow_trailing_bytes();
+    let decoded = seed.deserialize(&mut bincode::Deserializer::from_slice(&bytes, options))?;
+    assert_eq!(*unsafe { decoded.into_box::<Fixture>()? }, fixture);
+    let bytes = postcard::to_allocvec(&value)?;
+    let seed = unsafe { deflect::value::Seed::new(schema.clone(), &context) };
+    let decoded = seed.deserialize(&mut postcard::Deserializer::from_bytes(&bytes))?;
+    assert_eq!(*unsafe { decoded.into_box::<Fixture>()? }, fixture);
+
+    let seed = unsafe { deflect::value::Seed::new(schema, &context) };
+    let json = r#"{ "name": "partial", "origin": [0, 0] }"#;
+    let err = seed
+        .deserialize(&mut serde_json::Deserializer::from_str(json))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "`Fixture.tags` is uninitialized");
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize() -> Result<(), Box<dyn std::error::Error>> {
+    #[allow(dead_code)]
+    enum Shape {
+        Empty,
+        Circle(u8),
+        Rect { w: u8, h: u8 },
+    }
+    #[allow(dead_code)]
+    struct Point(i16, i16);
+    #[allow(dead_code)]
+    struct Meters(f32);
+    struct Unit;
+    #[allow(dead_code)]
+    struct State<'a> {
+        name: String,
+        label: &'a str,
+        origin: Point,
+        length: Meters,
+        unit: Unit,
+        shapes: Vec<Shape>,
+        boxed: Box<[u8; 2]>,
+        maybe: Option<u32>,
+        nothing: Option<u32>,
+    }
+    let context = deflect::default_provider()?;
+    let state = State {
+        name: String::from("state"),
+        label: "label",
+        origin: Point(3, -4),
+        length: Meters(1.5),
+        unit: Unit,
+        shapes: vec![Shape::Empty, Shape::Circle(9), Shape::Rect { w: 2, h: 5 }],
+        boxed: Box::new([7, 8]),
+        maybe: Some(1),
+        nothing: None,
+    };
+    let value = (&state as &dyn deflect::Reflect).reflect(&context)?;
+    assert_eq!(
+        serde_json::to_value(&value)?,
+        serde_json::json!({
+            "name": "state",
+            "label": "label",
+            "origin": [3, -4],
+            "length": 1.5,
+            "unit": null,
+            "shapes": ["Empty", { "Circle": 9 }, { "Rect": { "w": 2, "h": 5 } }],
+            "boxed": [7, 8],
+            "maybe": 1,
+            "nothing": null,
+        })
+    );
+
+    // aliased referents are serialized in full, but cyclic ones cannot be
+    #[allow(dead_code)]
+    struct Node<'a> {
+        id: u8,
+        next: Option<&'a std::cell::Cell<Option<&'a Node<'a>>>>,
+    }
+    let next = std::cell::Cell::new(None);
+    let node = Node {
+        id: 1,
+        next: Some(&next),
+    };
+    let pair = (&node.id, &node.id);
+    let value = (&pair as &dyn deflect::Reflect).reflect(&context)?;
+    assert_eq!(serde_json::to_value(&value)?, serde_json::json!([1, 1]));
+    next.set(Some(&node));
+    let value = (&node as &dyn deflect::Reflect).reflect(&context)?;
+    let err = serde_json::to_value(&value).unwrap_err();
+    assert_eq!(err.to_string(), "cannot serialize a cyclic value");
+    Ok(())
+}
+
+#[test]
+fn snapshot() -> Result<(), Box<dyn std::error::Error>> {
+    #[allow(dead_code)]
+    enum Mode {
+        Off,
+        On { level: u8 },
+    }
+    #[allow(dead_code)]
+    struct Node<'a> {
+        name: String,
+        mode: Mode,
+        ids: Vec<u16>,
+        next: Option<&'a Node<'a>>,
+        raw: *const u8,
+    }
+    let context = deflect::default_provider()?;
+    let (snapshot, shallow) = {
+        let tail = Node {
+            name: String::from("tail"),
+            mode: Mode::Off,
+            ids: vec![],
+            next: None,
+            raw: std::ptr::null(),
+        };
+        let head = Node {
+            name: String::from("head"),
+            mode: Mode::On { level: 3 },
+            ids: vec![1, 2],
+            next: Some(&tail),
+            raw: std::ptr::null(),
+        };
+        let value = (&head as &dyn deflect::Reflect).reflect(&context)?;
+        (value.snapshot()?, value.snapshot_with_depth(0)?)
+    };
+    // snapshots outlive the reflected values, and may be sent across threads
+    let snapshot = std::thread::spawn(move || snapshot.to_string())
+        .join()
+        .unwrap();
+    assert_eq!(
+        snapshot,
+        "Node { name: \"head\", mode: Mode::On { level: 3 }, ids: [1, 2], next: \
+         Option<&reflect::snapshot::Node>::Some { __0: &Node { name: \"tail\", mode: \
+         Mode::Off, ids: [], next: Option<&reflect::snapshot::Node>::None, raw: 0x0 as \
+         *const u8 } }, raw: 0x0 as *const u8 }"
+    );
+    let deflect::value::OwnedValue::Struct { fields, .. } = shallow else {
+        panic!("expected a struct");
+    };
+    let (_, next) = &fields[3];
+    assert!(matches!(
+        next,
+        deflect::value::OwnedValue::Enum { fields, .. }
+            if matches!(fields[0].1, deflect::value::OwnedValue::Pointer { pointee: None, .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn downcast_ref() -> Result<(), Box<dyn std::error::Error>> {
+    #[derive(Debug, PartialEq)]
+    struct Config {
+        name: &'static str,
+        tags: Vec<String>,
+        level: u16,
+    }
+    let context = deflect::default_provider()?;
+    let config = Config {
+        name: "config",
+        tags: vec![String::from("a")],
+        level: 3,
+    };
+    let value = (&config as &dyn deflect::Reflect).reflect(&context)?;
+    assert_eq!(value.downcast_ref::<Config>()?, &config);
+    let tags = (&config.tags as &dyn deflect::Reflect).reflect(&context)?;
+    assert_eq!(tags.downcast_ref::<Vec<String>>()?, &config.tags);
+    // types described in other compilation units are identified by structure
+    let value: deflect::value::Struct<_> = value.try_into()?;
+    let tags = value.field("tags")?.unwrap().value()?;
+    assert_eq!(tags.downcast_ref::<Vec<String>>()?, &config.tags);
+    assert!(tags.downcast_ref::<Vec<u8>>().is_err());
+    let level = value.field("level")?.unwrap().value()?;
+    assert_eq!(level.downcast_ref::<u16>()?, &3);
+    assert_eq!(
+        level.downcast_ref::<i16>().unwrap_err().to_string(),
+        "cannot downcast a `u16` to a `i16`"
+    );
+    assert!(tags.downcast_ref::<Vec<u8>>().is_err());
+    Ok(())
+}
+
+#[test]
+fn reflect_raw() -> Result<(), Box<dyn std::error::Error>> {
+    use deflect::DebugInfoProvider;
+    #[allow(dead_code)]
+    struct Packet {
+        id: u32,
+        payload: [u8; 3],
+    }
+    let context = deflect::default_provider()?;
+    let packet = Packet {
+        id: 7,
+        payload: [1, 2, 3],
+    };
+    let ptr = &packet as *const Packet as *const std::ffi::c_void;
+    let value = unsafe { context.reflect_raw(ptr, "reflect::reflect_raw::Packet")? };
+    assert_eq!(value.to_string(), "Packet { id: 7, payload: [1, 2, 3] }");
+    let r#type = context.type_named("u32")?.unwrap();
+    let id = unsafe { r#type.reflect_at(&packet.id as *const u32 as *const _, &context)? };
+    assert_eq!(u32::try_from(id)?, 7);
+    assert!(context
+        .type_named("reflect::reflect_raw::Missing")?
+        .is_none());
+    Ok(())
+}
+
+#[test]
+fn visit() -> Result<(), Box<dyn std::error::Error>> {
+    use deflect::anyhow::Error;
+    use deflect::value::{Field, Flow, Path, Value, Visit};
+    use deflect::DebugInfoProvider;
+    #[allow(dead_code)]
+    enum Mode {
+        Off,
+        Auto { max: u8 },
+    }
+    #[allow(dead_code)]
+    struct Config<'a> {
+        name: String,
+        mode: Mode,
+        tags: Vec<&'a str>,
+        secret: [u8; 2],
+        parent: Option<&'a Config<'a>>,
+    }
+    /// Records the paths of primitives, skipping `secret`s.
+    struct Primitives(Vec<String>);
+    impl<'value, 'dwarf, P: DebugInfoProvider> Visit<'value, 'dwarf, P> for Primitives {
+        fn visit_field(
+            &mut self,
+            _: &Path,
+            field: &Field<'value, 'dwarf, P>,
+        ) -> Result<Flow, Error> {
+            if field.schema().name()?.to_string_lossy()? == "secret" {
+                return Ok(Flow::Skip);
+            }
+            Ok(Flow::Continue)
+        }
+        fn visit_primitive(
+            &mut self,
+            path: &Path,
+            _: &Value<'value, 'dwarf, P>,
+        ) -> Result<Flow, Error> {
+            self.0.push(path.to_string());
+            Ok(Flow::Continue)
+        }
+    }
+    let context = deflect::default_provider()?;
+    let parent = Config {
+        name: String::from("parent"),
+        mode: Mode::Auto { max: 1 },
+        tags: vec![],
+        secret: [0; 2],
+        parent: None,
+    };
+    let config = Config {
+        name: String::from("child"),
+        mode: Mode::Auto { max: 2 },
+        tags: vec!["a", "b"],
+        secret: [0; 2],
+        parent: Some(&parent),
+    };
+    let value = (&config as &dyn deflect::Reflect).reflect(&context)?;
+    let mut primitives = Primitives(vec![]);
+    value.visit(&mut primitives)?;
+    assert_eq!(
+        primitives.0,
+        [
+            ".name",
+            ".mode::Auto.max",
+            ".tags[0]",
+            ".tags[1]",
+            ".parent::Some.__0.*.name",
+            ".parent::Some.__0.*.mode::Auto.max",
+        ]
+    );
+    let mut primitives = Primitives(vec![]);
+    value.visit_with_depth(&mut primitives, 2)?;
+    assert_eq!(primitives.0, [".name", ".tags[0]", ".tags[1]"]);
+    Ok(())
+}
+
+#[test]
+fn cycles() -> Result<(), Box<dyn std::error::Error>> {
+    use std::cell::Cell;
+    #[allow(dead_code)]
+    struct Node<'a> {
+        id: u8,
+        prev: Cell<Option<&'a Node<'a>>>,
+        next: Cell<Option<&'a Node<'a>>>,
+    }
+    let context = deflect::default_provider()?;
+    let node = |id| Node {
+        id,
+        prev: Cell::new(None),
+        next: Cell::new(None),
+    };
+    let (a, b) = (node(1), node(2));
+    a.next.set(Some(&b));
+    b.prev.set(Some(&a));
+    let value = (&a as &dyn deflect::Reflect).reflect(&context)?;
+    let cell = "Cell<core::option::Option<&reflect::cycles::Node>>";
+    let unsafe_cell = "UnsafeCell<core::option::Option<&reflect::cycles::Node>>";
+    let option = "Option<&reflect::cycles::Node>";
+    let none = format!("{cell} {{ value: {unsafe_cell} {{ value: {option}::None }} }}");
+    let some = |node| {
+        format!("{cell} {{ value: {unsafe_cell} {{ value: {option}::Some {{ __0: {node} }} }} }}")
+    };
+    let b = format!("&Node {{ id: 2, prev: {}, next: {none} }}", some("&#1"));
+    assert_eq!(
+        value.to_string(),
+        format!("#1 = Node {{ id: 1, prev: {none}, next: {} }}", some(&b))
+    );
+
+    struct Ids(Vec<String>);
+    impl<'value, 'dwarf, P> deflect::value::Visit<'value, 'dwarf, P> for Ids
+    where
+        P: deflect::DebugInfoProvider,
+    {
+        fn visit_primitive(
+            &mut self,
+            path: &deflect::value::Path,
+            value: &deflect::value::Value<'value, 'dwarf, P>,
+        ) -> Result<deflect::value::Flow, deflect::anyhow::Error> {
+            self.0.push(format!("{path} = {value}"));
+            Ok(deflect::value::Flow::Continue)
+        }
+    }
+    let mut ids = Ids(vec![]);
+    value.visit(&mut ids)?;
+    assert_eq!(ids.0, [".id = 1", ".next.value.value::Some.__0.*.id = 2"]);
+    Ok(())
+}
+
+#[test]
+fn pointer_validation() -> Result<(), Box<dyn std::error::Error>> {
+    use deflect::DebugInfoProvider;
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct Refs<'a> {
+        misaligned: &'a u64,
+        unmapped: &'a u64,
+        name: &'a str,
+    }
+    let context = deflect::default_provider()?;
+    let (word, other) = (42u64, 43u64);
+    let valid = Refs {
+        misaligned: &word,
+        unmapped: &other,
+        name: "ok",
+    };
+    // Restores the global flag even if an assertion below fails.
+    struct Validation;
+    impl Drop for Validation {
+        fn drop(&mut self) {
+            deflect::set_pointer_validation(false);
+        }
+    }
+    deflect::set_pointer_validation(true);
+    let _validation = Validation;
+    let value = (&valid as &dyn deflect::Reflect).reflect(&context)?;
+    assert_eq!(
+        value.to_string(),
+        r#"Refs { misaligned: &42, unmapped: &43, name: "ok" }"#
+    );
+    let misaligned = &word as *const u64 as usize + 1;
+    let raw: [usize; 4] = [misaligned, 0x10, 0x10, 3];
+    let ptr = &raw as *const [usize; 4] as *const std::ffi::c_void;
+    let value = unsafe { context.reflect_raw(ptr, "reflect::pointer_validation::Refs")? };
+    assert_eq!(
+        value.to_string(),
+        format!(
+            "Refs {{ misaligned: <invalid pointer 0x{misaligned:x}>, \
+             unmapped: <invalid pointer 0x10>, name: <invalid pointer 0x10> }}"
+        )
+    );
+    let misaligned: deflect::value::Struct = value.try_into()?;
+    let misaligned: deflect::value::SharedRef = misaligned
+        .field("misaligned")?
+        .unwrap()
+        .value()?
+        .try_into()?;
+    let err = misaligned.deref().unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<deflect::InvalidPointer>()
+            .map(|err| err.address()),
+        Some(misaligned.address())
+    );
+    Ok(())
+}
+
+#[test]
+fn unsized_structs() -> Result<(), Box<dyn std::error::Error>> {
+    #[allow(dead_code)]
+    struct Holder<T: ?Sized> {
+        tag: u8,
+        inner: T,
+    }
+    let context = deflect::default_provider()?;
+
+    let slice: &Holder<[u16]> = &Holder {
+        tag: 1,
+        inner: [1, 2, 3],
+    };
+    let erased: &dyn deflect::Reflect = &slice;
+    let value: deflect::value::SharedRef = erased.reflect(&context)?.try_into()?;
+    assert!(value.schema().is_wide());
+    assert_eq!(value.metadata()?, Some(deflect::value::Metadata::Length(3)));
+    let value: deflect::value::Struct = value.deref()?.try_into()?;
+    assert_eq!(value.size()?, std::mem::size_of_val(slice) as u64);
+    assert_eq!(
+        value.to_string(),
+        "Holder<[u16]> { tag: 1, inner: [1, 2, 3] }"
+    );
+    assert_eq!(
+        value.schema().to_string(),
+        "Holder<[u16]> { tag: u8, inner: [u16] }"
+    );
+
+    let object: &Holder<dyn fmt::Debug> = &Holder {
+        tag: 2,
+        inner: 5u64,
+    };
+    let erased: &dyn deflect::Reflect = &object;
+    let value: deflect::value::SharedRef = erased.reflect(&context)?.try_into()?;
+    let value: deflect::value::Struct = value.deref()?.try_into()?;
+    assert_eq!(value.size()?, std::mem::size_of_val(object) as u64);
+    assert_eq!(
+        value.to_string(),
+        "Holder<dyn core::fmt::Debug> { tag: 2, inner: 5 }"
+    );
+
+    let nested: Box<Holder<Holder<[u32]>>> = Box::new(Holder {
+        tag: 3,
+        inner: Holder {
+            tag: 4,
+            inner: [6, 7],
+        },
+    });
+    let erased: &dyn deflect::Reflect = &nested;
+    let value: deflect::value::Box = erased.reflect(&context)?.try_into()?;
+    let value: deflect::value::Struct = value.deref()?.try_into()?;
+    assert_eq!(value.size()?, std::mem::size_of_val(&*nested) as u64);
+    assert!(value
+        .to_string()
+        .ends_with("{ tag: 3, inner: Holder<[u32]> { tag: 4, inner: [6, 7] } }"));
+    Ok(())
+}
+
+#[test]
+fn generics() -> Result<(), Box<dyn std::error::Error>> {
+    #[allow(dead_code)]
+    struct Buf<T, const N: usize, const B: bool> {
+        items: [T; N],
+    }
+    let context = deflect::default_provider()?;
+
+    let erased: &dyn deflect::Reflect = &Vec::<u32>::new();
+    let value: deflect::value::Struct = erased.reflect(&context)?.try_into()?;
+    let generics = value.schema().generics()?;
+    let generics: Vec<_> = generics.iter().map(ToString::to_string).collect();
+    assert_eq!(generics, ["T = u32", "A = Global"]);
+
+    let erased: &dyn deflect::Reflect = &Buf::<u8, 2, true> { items: [1, 2] };
+    let value: deflect::value::Struct = erased.reflect(&context)?.try_into()?;
+    let generics = value.schema().generics()?;
+    assert!(matches!(
+        generics[0].as_type(),
+        Some(deflect::schema::Type::u8(_))
+    ));
+    assert_eq!(
+        generics[1].as_const(),
+        Some(&deflect::schema::ConstValue::UInt(2))
+    );
+    assert_eq!(
+        generics[2].as_const(),
+        Some(&deflect::schema::ConstValue::Bool(true))
+    );
+
+    let erased: &dyn deflect::Reflect = &Some('a');
+    let value: deflect::value::Enum = erased.reflect(&context)?.try_into()?;
+    let generics = value.schema().generics()?;
+    assert!(matches!(
+        generics[..],
+        [deflect::schema::Generic::Type { .. }]
+    ));
+    assert!(matches!(
+        generics[0].as_type(),
+        Some(deflect::schema::Type::char(_))
+    ));
+    Ok(())
+}
+
 mod r#ref {
     #[test]
     fn unit_struct() -> Result<(), Box<dyn std::error::Error>> {
@@ -148,6 +911,166 @@ fn boxed_dyn() -> Result<(), Box<dyn std::error::Error>> {
 
     assert_eq!(value.to_string(), "Foo");
 
+    // addresses that are not vtables are reported as such, so that `deref`
+    // may fall back to drop glue
+    use deflect::DebugInfoProvider;
+    static NOT_A_VTABLE: u64 = 0;
+    let Err(err) = context.vtable_info(&NOT_A_VTABLE as *const u64 as u64) else {
+        panic!("found a vtable at `NOT_A_VTABLE`");
+    };
+    assert!(err.is::<deflect::VtableNotFound>());
+
+    Ok(())
+}
+
+#[test]
+fn boxed_dyn_without_drop_glue() -> Result<(), Box<dyn std::error::Error>> {
+    struct Small(#[allow(dead_code)] u8);
+    struct Large(#[allow(dead_code)] u64, #[allow(dead_code)] u64);
+    struct Owning(#[allow(dead_code)] Box<u8>);
+
+    trait Trait {}
+
+    impl Trait for Small {}
+    impl Trait for Large {}
+    impl Trait for Owning {}
+
+    let context = deflect::default_provider()?;
+
+    let data: [Box<dyn Trait>; 3] = [
+        Box::new(Small(1)),
+        Box::new(Large(2, 3)),
+        Box::new(Owning(Box::new(4))),
+    ];
+    let expected = [
+        "box Small { __0: 1 }",
+        "box Large { __0: 2, __1: 3 }",
+        "box Owning { __0: box 4 }",
+    ];
+    for (data, expected) in data.iter().zip(expected) {
+        let erased: &dyn deflect::Reflect = data;
+        let value: deflect::value::BoxedDyn = erased.reflect(&context)?.try_into()?;
+        assert_eq!(value.to_string(), expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn vtable() -> Result<(), Box<dyn std::error::Error>> {
+    struct Square(#[allow(dead_code)] u64);
+
+    trait Shape {
+        fn area(&self) -> u64;
+        fn name(&self) -> &'static str {
+            "shape"
+        }
+    }
+
+    impl Shape for Square {
+        fn area(&self) -> u64 {
+            self.0 * self.0
+        }
+    }
+
+    let context = deflect::default_provider()?;
+
+    let data: Box<dyn Shape> = Box::new(Square(2));
+    assert_eq!(data.area() + data.name().len() as u64, 9);
+    let erased: &dyn deflect::Reflect = &data;
+
+    let value: deflect::value::BoxedDyn = erased.reflect(&context)?.try_into()?;
+    let vtable = value.vtable()?;
+
+    assert_eq!(
+        vtable.schema().trait_name()?.as_deref(),
+        Some("reflect::vtable::Shape")
+    );
+    assert_eq!(
+        vtable.schema().self_type_name()?.as_deref(),
+        Some("reflect::vtable::Square")
+    );
+    assert_eq!(vtable.size()?, 8);
+    assert_eq!(vtable.align()?, 8);
+    assert!(vtable.drop_glue()?.is_none());
+
+    let methods = vtable.methods()?;
+    let methods: Vec<_> = methods
+        .iter()
+        .map(|method| method.as_ref().and_then(|symbol| symbol.name()))
+        .collect();
+    assert_eq!(
+        methods,
+        [
+            Some("<reflect::vtable::Square as reflect::vtable::Shape>::area"),
+            Some("reflect::vtable::Shape::name"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn impls() -> Result<(), Box<dyn std::error::Error>> {
+    struct Secret(#[allow(dead_code)] u32);
+
+    impl fmt::Debug for Secret {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("Secret(<redacted>)")
+        }
+    }
+
+    impl fmt::Display for Secret {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("***")
+        }
+    }
+
+    struct Credentials {
+        #[allow(dead_code)]
+        user: u8,
+        #[allow(dead_code)]
+        secret: Secret,
+    }
+
+    // ensure the program contains `Debug` and `Display` vtables for `Secret`
+    let secret = Secret(7);
+    std::hint::black_box::<&dyn fmt::Debug>(&secret);
+    std::hint::black_box::<&dyn fmt::Display>(&secret);
+
+    let context = deflect::default_provider()?;
+    let erased: &dyn deflect::Reflect = &Credentials { user: 1, secret };
+    let value = erased.reflect(&context)?;
+
+    assert_eq!(
+        value.to_string(),
+        "Credentials { user: 1, secret: Secret { __0: 7 } }"
+    );
+    // SAFETY: no other types of this program share the paths of these.
+    unsafe {
+        assert_eq!(
+            value.with_impls().to_string(),
+            "Credentials { user: 1, secret: Secret(<redacted>) }"
+        );
+        assert!(value.as_debug()?.is_none());
+    }
+
+    let value: deflect::value::Struct = value.try_into()?;
+    let secret = value.field("secret")?.unwrap().value()?;
+    unsafe {
+        assert_eq!(
+            format!("{:?}", secret.as_debug()?.unwrap()),
+            "Secret(<redacted>)"
+        );
+        assert_eq!(secret.as_display()?.unwrap().to_string(), "***");
+    }
+
+    let secret: deflect::value::Struct = secret.try_into()?;
+    let traits =
+        deflect::schema::Type::from(secret.schema().clone()).implemented_traits(&context)?;
+    assert!(traits.contains(&"core::fmt::Debug".to_owned()));
+    assert!(traits.contains(&"core::fmt::Display".to_owned()));
+
     Ok(())
 }
 
@@ -162,6 +1085,95 @@ fn boxed_slice() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn fn_pointer() -> Result<(), Box<dyn std::error::Error>> {
+    fn callee(val: u8) -> u32 {
+        val as _
+    }
+    let line = line!() - 3;
+    struct Callback(#[allow(dead_code)] fn(u8) -> u32);
+    let erased: &dyn deflect::Reflect = &Callback(callee);
+    let context = deflect::default_provider()?;
+    let value: deflect::value::Struct = erased.reflect(&context)?.try_into()?;
+    let field = value.field("__0")?.unwrap().value()?;
+    let value: deflect::value::Function = field.try_into()?;
+    assert_eq!(value.schema().name()?.to_string()?, "fn(u8) -> u32");
+    assert_eq!(
+        value.address(),
+        Some(callee as fn(u8) -> u32 as usize as u64)
+    );
+
+    let symbol = value.symbol()?.unwrap();
+    assert_eq!(symbol.name(), Some("reflect::fn_pointer::callee"));
+    assert!(symbol.file().unwrap().ends_with("reflect.rs"));
+    assert_eq!(symbol.line(), Some(line));
+    assert!(value
+        .to_string()
+        .starts_with("fn reflect::fn_pointer::callee @ "));
+    Ok(())
+}
+
+#[test]
+fn fn_signature() -> Result<(), Box<dyn std::error::Error>> {
+    extern "C" {
+        fn printf(format: *const u8, ...) -> i32;
+    }
+    extern "C" fn negate(n: i32) -> i32 {
+        -n
+    }
+    fn parse(_: &str, _: usize) -> Option<u8> {
+        None
+    }
+    fn noop() {}
+
+    #[allow(dead_code)]
+    struct Signatures {
+        rust: fn(&str, usize) -> Option<u8>,
+        unit: fn(),
+        c: extern "C" fn(i32) -> i32,
+        variadic: unsafe extern "C" fn(*const u8, ...) -> i32,
+    }
+
+    let erased: &dyn deflect::Reflect = &Signatures {
+        rust: parse,
+        unit: noop,
+        c: negate,
+        variadic: printf,
+    };
+    let context = deflect::default_provider()?;
+    let value: deflect::value::Struct = erased.reflect(&context)?.try_into()?;
+    let schema = |name| -> Result<_, Box<dyn std::error::Error>> {
+        let field = value.field(name)?.unwrap();
+        let value: deflect::value::Function = field.value()?.try_into()?;
+        Ok(value.schema().clone())
+    };
+
+    let rust = schema("rust")?;
+    assert_eq!(rust.to_string(), "fn(&str, usize) -> Option<u8>");
+    assert_eq!(rust.params()?.len(), 2);
+    assert!(rust.return_type()?.is_some());
+    assert_eq!(rust.abi()?, None);
+
+    let unit = schema("unit")?;
+    assert_eq!(unit.to_string(), "fn()");
+    assert!(unit.params()?.is_empty());
+    assert!(unit.return_type()?.is_none());
+
+    let c = schema("c")?;
+    assert_eq!(c.to_string(), "extern \"C\" fn(i32) -> i32");
+    assert_eq!(c.abi()?.as_deref(), Some("C"));
+    assert!(!c.is_variadic()?);
+
+    let variadic = schema("variadic")?;
+    assert_eq!(
+        variadic.to_string(),
+        "unsafe extern \"C\" fn(*const u8, ...) -> i32"
+    );
+    assert!(variadic.is_variadic()?);
+    assert!(variadic.is_unsafe()?);
+    Ok(())
+}
+
 mod primitive {
 
     use std::ptr;
@@ -387,3 +1399,481 @@ mod primitive {
         Ok(())
     }
 }
+
+/// Futures shared by the `generator` and `async_backtrace` tests.
+mod futures {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    pub struct YieldOnce(pub bool);
+
+    impl Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                Poll::Pending
+            }
+        }
+    }
+
+    pub fn noop_waker() -> Waker {
+        const VTABLE: RawWakerVTable = RawWakerVTable::new(
+            |_| RawWaker::new(std::ptr::null(), &VTABLE),
+            |_| {},
+            |_| {},
+            |_| {},
+        );
+        unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    }
+}
+
+mod generator {
+    use super::futures::{noop_waker, YieldOnce};
+    use std::future::Future;
+    use std::task::Context;
+
+    async fn suspends(x: u32) -> u32 {
+        let local = x + 1;
+        let line = line!();
+        YieldOnce(false).await;
+        local + line
+    }
+
+    #[test]
+    fn suspended() -> Result<(), Box<dyn std::error::Error>> {
+        let mut future = Box::pin(suspends(41));
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+
+        let erased: &dyn deflect::Reflect = &*future;
+        let context = deflect::default_provider()?;
+        let value = erased.reflect(&context)?;
+        let value: deflect::value::Generator = value.try_into()?;
+
+        assert_eq!(value.schema().function()?, "reflect::generator::suspends");
+        assert_eq!(value.state()?.schema().name()?.to_string()?, "Suspend0");
+
+        let mut locals = value
+            .locals()?
+            .into_iter()
+            .map(|local| Ok((local.schema().name()?.to_string()?.into_owned(), local)))
+            .collect::<Result<std::collections::HashMap<_, _>, deflect::anyhow::Error>>()?;
+        let local: u32 = locals.remove("local").unwrap().value()?.try_into()?;
+        let line: u32 = locals.remove("line").unwrap().value()?.try_into()?;
+        assert_eq!(local, 42);
+
+        let (file, await_line) = value.location()?.unwrap();
+        assert!(file.ends_with("reflect.rs"));
+        assert_eq!(await_line, line as u64 + 1);
+
+        let awaitee: deflect::value::Struct = value.awaitee()?.unwrap().try_into()?;
+        assert_eq!(awaitee.to_string(), "YieldOnce { __0: true }");
+
+        Ok(())
+    }
+}
+
+mod async_backtrace {
+    use super::futures::{noop_waker, YieldOnce};
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    async fn leaf() {
+        YieldOnce(false).await
+    }
+
+    async fn middle() {
+        Box::pin(leaf()).await
+    }
+
+    async fn root() {
+        middle().await
+    }
+
+    #[test]
+    fn pinned_boxed_dyn() -> Result<(), Box<dyn std::error::Error>> {
+        let mut future: Pin<Box<dyn Future<Output = ()>>> = Box::pin(root());
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+
+        let frames = deflect::async_backtrace(&future)?;
+        let [root] = &frames[..] else {
+            panic!("expected exactly one root frame, got {frames:?}")
+        };
+        assert_eq!(root.function(), "reflect::async_backtrace::root");
+        let [middle] = root.awaiting() else {
+            panic!("expected exactly one awaited frame")
+        };
+        assert_eq!(middle.function(), "reflect::async_backtrace::middle");
+        let [leaf] = middle.awaiting() else {
+            panic!("expected exactly one awaited frame")
+        };
+        assert_eq!(leaf.function(), "reflect::async_backtrace::leaf");
+        assert_eq!(leaf.state(), "Suspend0");
+        assert!(leaf.location().is_some());
+        assert!(leaf.awaiting().is_empty());
+        Ok(())
+    }
+
+    struct Join<A, B>(Pin<Box<A>>, Pin<Box<B>>);
+
+    impl<A: Future<Output = ()>, B: Future<Output = ()>> Future for Join<A, B> {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let a = self.0.as_mut().poll(cx);
+            let b = self.1.as_mut().poll(cx);
+            if a.is_ready() && b.is_ready() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn join() -> Result<(), Box<dyn std::error::Error>> {
+        let mut future = Box::pin(async { Join(Box::pin(leaf()), Box::pin(middle())).await });
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+
+        let context = deflect::default_provider()?;
+        let frames = deflect::async_backtrace_with(&future, &context)?;
+        let [root] = &frames[..] else {
+            panic!("expected exactly one root frame, got {frames:?}")
+        };
+        let functions: Vec<_> = root.awaiting().iter().map(|f| f.function()).collect();
+        assert_eq!(
+            functions,
+            [
+                "reflect::async_backtrace::leaf",
+                "reflect::async_backtrace::middle"
+            ]
+        );
+        Ok(())
+    }
+}
+
+#[test]
+fn c_bitfields() -> Result<(), Box<dyn std::error::Error>> {
+    use deflect::DebugInfoProvider;
+    let context = deflect::default_provider()?;
+    for (ptr, name) in [
+        // described with `DW_AT_data_bit_offset`
+        (deflect_fixtures::bitfields(), "Bitfields"),
+        // described with `DW_AT_bit_offset`
+        (deflect_fixtures::legacy_bitfields(), "LegacyBitfields"),
+    ] {
+        let value = unsafe { context.reflect_raw(ptr, name)? };
+        assert_eq!(
+            value.to_string(),
+            format!(
+                "{name} {{ unsigned_field: 5, signed_field: -3, wide_field: 1000, \
+                 char_field: -2, flag: true }}"
+            )
+        );
+        let value: deflect::value::Struct = value.try_into()?;
+        let signed = value.field("signed_field")?.unwrap().value()?;
+        let signed: deflect::value::Bitfield = signed.try_into()?;
+        assert_eq!(signed.schema().bit_size(), 5);
+        assert_eq!(signed.schema().bit_offset(), 3);
+        assert!(signed.schema().is_signed()?);
+        assert_eq!(signed.as_i64(), -3);
+        assert_eq!(signed.as_u64(), 0b11101);
+    }
+    Ok(())
+}
+
+#[test]
+fn c_typedefs() -> Result<(), Box<dyn std::error::Error>> {
+    use deflect::schema::Qualifier;
+    use deflect::value::Value;
+    use deflect::DebugInfoProvider;
+
+    /// Strips the qualifiers and typedefs of `value`, and collects its
+    /// qualifiers, innermost last.
+    fn unqualify<'value, 'dwarf>(
+        mut value: Value<'value, 'dwarf>,
+    ) -> Result<(Vec<Qualifier>, Value<'value, 'dwarf>), deflect::anyhow::Error> {
+        let mut qualifiers = vec![];
+        loop {
+            value = match value {
+                Value::Qualified(qualified) => {
+                    qualifiers.push(qualified.schema().qualifier());
+                    qualified.value()?
+                }
+                Value::Typedef(typedef) => typedef.value()?,
+                value => return Ok((qualifiers, value)),
+            }
+        }
+    }
+
+    let context = deflect::default_provider()?;
+    let value = unsafe { context.reflect_raw(deflect_fixtures::qualified(), "Qualified")? };
+    assert!(value.to_string().starts_with(
+        "Qualified { constant: -1, volatile_field: 7, both: 255, id: 1000, \
+         account: struct { id: 3, level: 4 }, restricted: "
+    ));
+    let value: deflect::value::Struct = value.try_into()?;
+    let field = |name| value.field(name).map(|field| field.unwrap().value());
+
+    let (qualifiers, constant) = unqualify(field("constant")??)?;
+    assert_eq!(qualifiers, [Qualifier::Const]);
+    assert_eq!(i32::try_from(constant)?, -1);
+
+    let (qualifiers, volatile) = unqualify(field("volatile_field")??)?;
+    assert_eq!(qualifiers, [Qualifier::Volatile]);
+    assert_eq!(i16::try_from(volatile)?, 7);
+
+    let (mut qualifiers, both) = unqualify(field("both")??)?;
+    qualifiers.sort_by_key(|qualifier| *qualifier as u8);
+    assert_eq!(qualifiers, [Qualifier::Const, Qualifier::Volatile]);
+    assert_eq!(u8::try_from(both)?, 255);
+
+    // `Id` aliases `uint32_t`, which (eventually) aliases `unsigned int`
+    let Value::Typedef(id) = field("id")?? else {
+        panic!("`id` is not a typedef");
+    };
+    assert_eq!(id.schema().name()?.to_string_lossy()?, "Id");
+    assert_eq!(id.schema().size()?, 4);
+    let Value::Typedef(uint32_t) = id.value()? else {
+        panic!("`Id` does not alias a typedef");
+    };
+    assert_eq!(uint32_t.schema().name()?.to_string_lossy()?, "uint32_t");
+    assert_eq!(u32::try_from(unqualify(uint32_t.value()?)?.1)?, 1000);
+
+    let Value::Qualified(account) = field("account")?? else {
+        panic!("`account` is not qualified");
+    };
+    assert_eq!(account.schema().qualifier(), Qualifier::Const);
+    let Value::Typedef(account) = account.value()? else {
+        panic!("`account` is not a typedef");
+    };
+    assert_eq!(account.schema().name()?.to_string_lossy()?, "Account");
+    assert_eq!(account.value()?.to_string(), "struct { id: 3, level: 4 }");
+
+    let (qualifiers, restricted) = unqualify(field("restricted")??)?;
+    assert_eq!(qualifiers, [Qualifier::Restrict]);
+    let restricted: deflect::value::MutPtr = restricted.try_into()?;
+    let target = restricted.schema().r#type()?;
+    let target = unsafe { target.reflect_at(restricted.address() as *const _, &context)? };
+    assert_eq!(target.to_string(), "42");
+
+    let value = unsafe { context.reflect_raw(deflect_fixtures::nullable(), "Nullable")? };
+    assert_eq!(
+        value.to_string(),
+        "Nullable { null: <decltype(nullptr)>, tag: 9 }"
+    );
+    let value: deflect::value::Struct = value.try_into()?;
+    // `std::nullptr_t` aliases `decltype(nullptr)`
+    let (_, null) = unqualify(value.field("null")?.unwrap().value()?)?;
+    let Value::Unspecified(null) = null else {
+        panic!("`null` is not of an unspecified type");
+    };
+    assert_eq!(
+        null.schema().name()?.unwrap().to_string_lossy()?,
+        "decltype(nullptr)"
+    );
+    // its size is not recorded, so none of its bytes are reflected
+    assert_eq!(null.schema().size()?, None);
+    assert!(null.bytes().is_empty());
+    Ok(())
+}
+
+#[test]
+fn c_ffi() -> Result<(), Box<dyn std::error::Error>> {
+    use deflect::value::Value;
+    use deflect::DebugInfoProvider;
+    let context = deflect::default_provider()?;
+    let ptr = deflect_fixtures::ffi();
+    let value = unsafe { context.reflect_raw(ptr, "Ffi")? };
+    let value: deflect::value::Struct = value.try_into()?;
+    let field = |name| value.field(name).map(|field| field.unwrap().value());
+
+    // base types are classified by their encoding and size, not their names
+    let primitives: deflect::value::Struct = field("primitives")??.try_into()?;
+    assert_eq!(
+        primitives.to_string(),
+        "Primitives { c: 97, sc: -2, uc: 200, s: -300, us: 60000, i: -70000, \
+         ui: 4000000000, l: -5, ul: 6, ll: -7, ull: 8, f: 1.5, d: -2.25, b: true, \
+         color: Color::GREEN }"
+    );
+    let primitive = |name| primitives.field(name).map(|field| field.unwrap().value());
+    assert_eq!(i8::try_from(primitive("sc")??)?, -2);
+    assert_eq!(u8::try_from(primitive("uc")??)?, 200);
+    assert_eq!(i16::try_from(primitive("s")??)?, -300);
+    assert_eq!(u16::try_from(primitive("us")??)?, 60000);
+    assert_eq!(i32::try_from(primitive("i")??)?, -70000);
+    assert_eq!(u32::try_from(primitive("ui")??)?, 4000000000);
+    assert_eq!(i64::try_from(primitive("ll")??)?, -7);
+    assert_eq!(u64::try_from(primitive("ull")??)?, 8);
+    assert_eq!(f32::try_from(primitive("f")??)?, 1.5);
+    assert_eq!(f64::try_from(primitive("d")??)?, -2.25);
+    assert!(bool::try_from(primitive("b")??)?);
+    let Value::Enum(color) = primitive("color")?? else {
+        panic!("`color` is not an enum");
+    };
+    assert_eq!(color.variant()?.to_string(), "GREEN");
+
+    // anonymous structs are displayed without a name
+    assert_eq!(field("point")??.to_string(), "struct { x: 3, y: -4 }");
+
+    // `void *` is reflected as a pointer to an unknown type
+    let opaque: deflect::value::MutPtr = field("opaque")??.try_into()?;
+    assert!(opaque.schema().is_void());
+    assert_eq!(opaque.address(), ptr as usize);
+
+    let name: deflect::value::MutPtr = field("name")??.try_into()?;
+    let target = name.schema().r#type()?;
+    let target = unsafe { target.reflect_at(name.address() as *const _, &context)? };
+    assert_eq!(target.to_string(), "102");
+
+    // C arrays are described by the `DW_AT_upper_bound` of a subrange per
+    // dimension
+    assert_eq!(field("grid")??.to_string(), "[[1, 2, 3], [4, 5, 6]]");
+    Ok(())
+}
+
+#[test]
+fn cpp_classes() -> Result<(), Box<dyn std::error::Error>> {
+    use deflect::value::Value;
+    use deflect::DebugInfoProvider;
+    let context = deflect::default_provider()?;
+    let value = unsafe { context.reflect_raw(deflect_fixtures::derived(), "Derived")? };
+    let value: deflect::value::Struct = value.try_into()?;
+    assert!(value.schema().is_class());
+
+    // base-class subobjects are fields named after their class
+    let bases = value.schema().bases()?;
+    assert_eq!(bases.len(), 1);
+    assert_eq!(bases[0].name()?.to_string_lossy()?, "Base");
+    let base = value.field("Base")?.unwrap();
+    assert!(base.schema().is_base());
+    assert_eq!(base.value()?.to_string(), "Base { base_field: 1 }");
+
+    let vptr = value.schema().vtable_pointer()?.unwrap();
+    assert_eq!(vptr.name()?.to_string_lossy()?, "_vptr.Derived");
+    let field = |name| value.field(name).map(|field| field.unwrap().value());
+    assert_eq!(i32::try_from(field("derived_field")??)?, 2);
+
+    // `T&` and `T&&` are both reflected as shared references
+    for (name, target, is_rvalue) in [("lvalue", 10, false), ("rvalue", 20, true)] {
+        let Value::SharedRef(reference) = field(name)?? else {
+            panic!("`{name}` is not a reference");
+        };
+        assert_eq!(reference.schema().is_rvalue_reference()?, is_rvalue);
+        assert_eq!(i32::try_from(reference.deref()?)?, target);
+    }
+
+    // static members are not fields, and are stored apart from their class
+    assert!(value.field("instances")?.is_none());
+    let statics = value.static_fields()?;
+    let names = statics
+        .iter()
+        .map(|field| Ok(field.schema().name()?.to_string_lossy()?.into_owned()))
+        .collect::<Result<Vec<_>, deflect::anyhow::Error>>()?;
+    assert_eq!(names, ["instances", "limit"]);
+    assert!(statics[0].schema().offset().is_err());
+    assert_eq!(deflect_fixtures::derived_instances(), 3);
+    assert_eq!(i32::try_from(statics[0].value()?)?, 3);
+    // `limit` is initialized in its class, and has no storage
+    assert!(statics[1].value().is_err());
+    Ok(())
+}
+
+#[test]
+fn c_arrays() -> Result<(), Box<dyn std::error::Error>> {
+    use deflect::DebugInfoProvider;
+    let context = deflect::default_provider()?;
+
+    /// The dimensions of the array field `name` of `value`.
+    fn dimensions(
+        value: &deflect::value::Struct,
+        name: &str,
+    ) -> Result<Vec<Option<u64>>, Box<dyn std::error::Error>> {
+        let array: deflect::value::Array = value.field(name)?.unwrap().value()?.try_into()?;
+        Ok(array.schema().dimensions()?)
+    }
+
+    let value = unsafe { context.reflect_raw(deflect_fixtures::arrays(), "Arrays")? };
+    assert_eq!(
+        value.to_string(),
+        "Arrays { cube: [[[1, 2], [3, 4]], [[5, 6], [7, 8]]], empty: [], len: 2, flexible: [] }"
+    );
+    let value: deflect::value::Struct = value.try_into()?;
+    assert_eq!(dimensions(&value, "cube")?, [Some(2), Some(2), Some(2)]);
+    assert_eq!(dimensions(&value, "empty")?, [Some(0)]);
+    // flexible array members have no upper bound
+    assert_eq!(dimensions(&value, "flexible")?, [None]);
+
+    let value = unsafe { context.reflect_raw(deflect_fixtures::bounds(), "Bounds")? };
+    assert_eq!(
+        value.to_string(),
+        "Bounds { from_one: [1, 2, 3], count: 3, flexible: [] }"
+    );
+    let value: deflect::value::Struct = value.try_into()?;
+    // `from_one` is indexed from 1 to 3
+    assert_eq!(dimensions(&value, "from_one")?, [Some(3)]);
+    // `flexible` has an upper bound of `-1`, encoded as a four-byte `int`
+    assert_eq!(dimensions(&value, "flexible")?, [None]);
+    Ok(())
+}
+
+#[test]
+fn c_function_pointers() -> Result<(), Box<dyn std::error::Error>> {
+    use deflect::DebugInfoProvider;
+    let context = deflect::default_provider()?;
+    let value = unsafe { context.reflect_raw(deflect_fixtures::callbacks(), "Callbacks")? };
+    let value: deflect::value::Struct = value.try_into()?;
+    let schema = |name| -> Result<_, Box<dyn std::error::Error>> {
+        let field = value.field(name)?.unwrap();
+        let value: deflect::value::Function = field.value()?.try_into()?;
+        Ok(value.schema().clone())
+    };
+
+    // C function pointers are unnamed, and use the C ABI by default
+    let plain = schema("plain")?;
+    assert_eq!(plain.abi()?.as_deref(), Some("C"));
+    assert!(!plain.is_variadic()?);
+    assert_eq!(plain.to_string(), "extern \"C\" fn(int) -> int");
+
+    // variadics are described by `DW_TAG_unspecified_parameters`
+    let variadic = schema("variadic")?;
+    assert_eq!(variadic.abi()?.as_deref(), Some("C"));
+    assert!(variadic.is_variadic()?);
+    assert_eq!(variadic.to_string(), "extern \"C\" fn(int, ...) -> int");
+
+    // other ABIs are described by `DW_AT_calling_convention`
+    let stdcall = schema("stdcall")?;
+    assert_eq!(stdcall.abi()?.as_deref(), Some("stdcall"));
+    assert!(!stdcall.is_variadic()?);
+    Ok(())
+}
+
+#[test]
+fn c_ambiguous_types() -> Result<(), Box<dyn std::error::Error>> {
+    use deflect::DebugInfoProvider;
+    let context = deflect::default_provider()?;
+    // `shapes_a.c` and `shapes_b.c` define distinct `struct Shape`s
+    let err = context.type_named("Shape").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`Shape` is ambiguous; it names 2 distinct types"
+    );
+    // ...but identical `struct Extent`s, which are no more ambiguous than the
+    // `int` that each unit defines
+    assert!(context.type_named("int")?.is_some());
+    let extent = unsafe { (deflect_fixtures::shape_b() as *const u8).add(8) };
+    let value = unsafe { context.reflect_raw(extent as _, "Extent")? };
+    assert_eq!(value.to_string(), "Extent { width: 3, height: 3 }");
+    Ok(())
+}